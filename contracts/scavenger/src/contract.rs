@@ -1,8 +1,13 @@
 use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
 
 use crate::events;
+use crate::pool;
 use crate::storage::Storage;
-use crate::types::{GlobalMetrics, Incentive, Material, Participant, Role, WasteTransfer, WasteType};
+use crate::types::{
+    GlobalMetrics, Incentive, IncentiveLimits, IncentiveQuote, IncentiveScope, IncentiveUsage,
+    Material, Participant, PoolContribution, PoolPayout, RetirementCertificate, Role,
+    VestingPolicy, VestingSchedule, WasteCategory, WasteTransfer, WasteType,
+};
 
 #[contract]
 pub struct ScavengerContract;
@@ -67,7 +72,7 @@ impl ScavengerContract {
 
     // ========== Global Metrics (Issue #55) ==========
 
-    /// Retrieve global contract metrics (total wastes and total tokens earned)
+    /// Retrieve global contract metrics (total wastes, tokens earned, weight and CO2e avoided)
     pub fn get_metrics(env: &Env) -> GlobalMetrics {
         // We use the Material counter (MAT_CNT) to determine total waste items logged.
         // This calculates the metric efficiently from storage without iteration.
@@ -76,9 +81,34 @@ impl ScavengerContract {
         GlobalMetrics {
             total_wastes_count: waste_count,
             total_tokens_earned: Storage::get_total_earned(env),
+            total_weight: Storage::get_total_weight(env),
+            total_co2e_avoided: Storage::get_total_co2e(env),
         }
     }
 
+    /// Set the grams of CO2e avoided per kilogram of a waste type (admin only)
+    /// Materials already submitted keep the factor that applied at the time
+    pub fn set_emission_factor(env: &Env, admin: Address, waste_type: WasteType, co2e_per_kg: u64) {
+        Self::require_admin(env, &admin);
+        Self::require_waste_category(env, waste_type);
+        Storage::set_emission_factor(env, waste_type, co2e_per_kg);
+    }
+
+    /// Get the grams of CO2e avoided per kilogram of a waste type
+    pub fn get_emission_factor(env: &Env, waste_type: WasteType) -> u64 {
+        Storage::get_emission_factor(env, waste_type)
+    }
+
+    /// Get the grams of CO2e avoided by a confirmed waste (0 until confirmed)
+    pub fn get_waste_co2e(env: &Env, waste_id: u64) -> u64 {
+        Storage::get_waste_co2e(env, waste_id).unwrap_or(0)
+    }
+
+    /// Get the grams of CO2e avoided by a participant's confirmed submissions
+    pub fn get_participant_co2e(env: &Env, address: Address) -> u64 {
+        Storage::get_participant_co2e(env, &address)
+    }
+
     // ===============================================
 
     /// Update the token address (admin only)
@@ -183,6 +213,54 @@ impl ScavengerContract {
         Storage::is_participant_registered(env, &address)
     }
 
+    /// Add a new waste category to the catalogue (admin only)
+    /// The id and active flag are assigned here; the category id is used as
+    /// the WasteType in every waste and incentive API
+    pub fn add_waste_category(env: &Env, admin: Address, category: WasteCategory) -> WasteCategory {
+        Self::require_admin(env, &admin);
+
+        assert!(category.max_weight > 0, "Max weight must be greater than zero");
+
+        let category = WasteCategory {
+            id: Storage::next_category_id(env),
+            is_active: true,
+            ..category
+        };
+
+        Storage::set_waste_category(env, &category);
+        category
+    }
+
+    /// Replace the metadata of an existing waste category (admin only)
+    pub fn update_waste_category(env: &Env, admin: Address, category: WasteCategory) -> WasteCategory {
+        Self::require_admin(env, &admin);
+
+        assert!(
+            Storage::get_waste_category(env, category.waste_type()).is_some(),
+            "Unknown waste type"
+        );
+        assert!(category.max_weight > 0, "Max weight must be greater than zero");
+
+        Storage::set_waste_category(env, &category);
+        category
+    }
+
+    /// Get a waste category from the catalogue
+    pub fn get_waste_category(env: &Env, waste_type: WasteType) -> Option<WasteCategory> {
+        Storage::get_waste_category(env, waste_type)
+    }
+
+    /// Get every category in the catalogue, ordered by id
+    pub fn get_waste_categories(env: &Env) -> Vec<WasteCategory> {
+        let mut categories = Vec::new(env);
+        for id in 0..Storage::get_category_count(env) {
+            if let Some(category) = Storage::get_waste_category(env, WasteType(id)) {
+                categories.push_back(category);
+            }
+        }
+        categories
+    }
+
     /// Create a new incentive (manufacturers only)
     pub fn create_incentive(
         env: &Env,
@@ -209,6 +287,8 @@ impl ScavengerContract {
             "Only manufacturers can create incentives"
         );
 
+        Self::require_waste_category(env, waste_type);
+
         // Generate incentive ID
         let incentive_id = Storage::next_incentive_id(env);
 
@@ -306,6 +386,10 @@ impl ScavengerContract {
 
         // Check incentive is active
         assert!(incentive.active, "Incentive is not active");
+        assert!(
+            Storage::get_pool_payout(env, incentive_id).is_none(),
+            "Pooled budgets follow contributions"
+        );
 
         // Validate new values
         assert!(new_reward_points > 0, "Reward must be greater than zero");
@@ -326,6 +410,7 @@ impl ScavengerContract {
         } else {
             incentive.remaining_budget = 0;
             incentive.active = false;
+            events::emit_incentive_exhausted(env, incentive_id, new_total_budget);
         }
 
         // Store the updated incentive
@@ -351,6 +436,239 @@ impl ScavengerContract {
         assert!(incentive.rewarder == rewarder, "Only rewarder can deactivate");
         incentive.active = false;
         Storage::set_incentive(env, incentive_id, &incentive);
+        events::emit_incentive_deactivated(env, incentive_id, &rewarder);
+    }
+
+    /// Add escrowed funds to an incentive's budget (rewarder only)
+    /// An incentive that ran out of budget is reactivated by the top-up
+    pub fn top_up_incentive(env: &Env, rewarder: Address, incentive_id: u64, amount: i128) -> Incentive {
+        rewarder.require_auth();
+
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(incentive.rewarder == rewarder, "Only rewarder can top up");
+        assert!(
+            Storage::get_pool_payout(env, incentive_id).is_none(),
+            "Contribute to pooled incentives instead"
+        );
+        assert!(!Storage::is_incentive_closed(env, incentive_id), "Incentive is closed");
+        assert!(amount > 0, "Amount must be greater than zero");
+
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+        let contract = env.current_contract_address();
+        token_client.transfer(&rewarder, &contract, &amount);
+
+        let escrow = Storage::get_incentive_escrow(env, incentive_id) + amount;
+        Storage::set_incentive_escrow(env, incentive_id, escrow);
+
+        let exhausted = !incentive.active && incentive.remaining_budget == 0;
+        incentive.total_budget += amount as u64;
+        incentive.remaining_budget += amount as u64;
+        if exhausted {
+            incentive.active = true;
+        }
+        Storage::set_incentive(env, incentive_id, &incentive);
+
+        events::emit_incentive_topped_up(env, incentive_id, &rewarder, amount, incentive.remaining_budget);
+        incentive
+    }
+
+    /// Close an incentive for good and refund its escrow (rewarder only)
+    /// Returns the amount refunded
+    pub fn close_incentive(env: &Env, rewarder: Address, incentive_id: u64) -> i128 {
+        rewarder.require_auth();
+
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(incentive.rewarder == rewarder, "Only rewarder can close");
+        assert!(
+            Storage::get_pool_payout(env, incentive_id).is_none(),
+            "Use close_incentive_pool for pooled incentives"
+        );
+        assert!(!Storage::is_incentive_closed(env, incentive_id), "Incentive is closed");
+
+        incentive.active = false;
+        incentive.remaining_budget = 0;
+        Storage::set_incentive(env, incentive_id, &incentive);
+        Storage::set_incentive_closed(env, incentive_id);
+
+        let escrow = Storage::get_incentive_escrow(env, incentive_id);
+        if escrow > 0 {
+            Storage::set_incentive_escrow(env, incentive_id, 0);
+
+            let token_address = Storage::get_token_address(env)
+                .expect("Token address not set");
+            let token_client = token::Client::new(env, &token_address);
+            let contract = env.current_contract_address();
+            token_client.transfer(&contract, &rewarder, &escrow);
+        }
+
+        events::emit_incentive_closed(env, incentive_id, &rewarder, escrow);
+        escrow
+    }
+
+    /// Check if an incentive has been closed
+    pub fn is_incentive_closed(env: &Env, incentive_id: u64) -> bool {
+        Storage::is_incentive_closed(env, incentive_id)
+    }
+
+    /// Set per-participant limits on an incentive (rewarder only)
+    /// Limits apply to the submitter of each rewarded material
+    pub fn set_incentive_limits(
+        env: &Env,
+        rewarder: Address,
+        incentive_id: u64,
+        limits: IncentiveLimits,
+    ) {
+        rewarder.require_auth();
+        let incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(incentive.rewarder == rewarder, "Only rewarder can set limits");
+        assert!(
+            limits.max_reward_per_period == 0 || limits.period > 0,
+            "Period cap requires a period"
+        );
+
+        Storage::set_incentive_limits(env, incentive_id, &limits);
+        events::emit_incentive_limits_set(env, incentive_id, &rewarder, &limits);
+    }
+
+    /// Get an incentive's per-participant limits, if any
+    pub fn get_incentive_limits(env: &Env, incentive_id: u64) -> Option<IncentiveLimits> {
+        Storage::get_incentive_limits(env, incentive_id)
+    }
+
+    /// Get how much of an incentive a participant has earned
+    /// The period figures reflect the current cap window
+    pub fn get_incentive_usage(env: &Env, incentive_id: u64, participant: Address) -> IncentiveUsage {
+        Self::current_usage(env, incentive_id, &participant)
+    }
+
+    /// Calculate the reward a material would earn from an incentive right now,
+    /// after the incentive's per-participant limits are applied
+    pub fn calculate_incentive_reward(env: &Env, waste_id: u64, incentive_id: u64) -> i128 {
+        let material = Storage::get_material(env, waste_id)
+            .expect("Material not found");
+        let incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+
+        if !Self::meets_min_account_age(env, incentive_id, &material.submitter) {
+            return 0;
+        }
+
+        let (_, reward) =
+            Self::limited_reward(env, &incentive, Some(&material.submitter), material.weight);
+        reward
+    }
+
+    /// Set when an incentive expires and the region it pays out in (rewarder only)
+    pub fn set_incentive_scope(
+        env: &Env,
+        rewarder: Address,
+        incentive_id: u64,
+        scope: IncentiveScope,
+    ) {
+        rewarder.require_auth();
+        let incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(incentive.rewarder == rewarder, "Only rewarder can set scope");
+        assert!(
+            scope.min_latitude <= scope.max_latitude && scope.min_longitude <= scope.max_longitude,
+            "Invalid region"
+        );
+
+        Storage::set_incentive_scope(env, incentive_id, &scope);
+        events::emit_incentive_scope_set(env, incentive_id, &rewarder, &scope);
+    }
+
+    /// Get an incentive's expiry and region
+    pub fn get_incentive_scope(env: &Env, incentive_id: u64) -> IncentiveScope {
+        Storage::get_incentive_scope(env, incentive_id)
+    }
+
+    /// Rank every manufacturer's incentives for a delivery of `weight` grams
+    /// of a waste type from a location, best effective payout first
+    /// Caps are applied for `participant` when given; incentives that are
+    /// inactive, expired, out of region or short of budget or escrow are left out
+    pub fn get_best_incentives(
+        env: &Env,
+        waste_type: WasteType,
+        weight: u64,
+        latitude: i64,
+        longitude: i64,
+        participant: Option<Address>,
+    ) -> Vec<IncentiveQuote> {
+        let mut quotes: Vec<IncentiveQuote> = Vec::new(env);
+
+        for incentive_id in Storage::get_incentives_by_waste_type(env, waste_type).iter() {
+            let Some(incentive) = Storage::get_incentive(env, incentive_id) else {
+                continue;
+            };
+            if !incentive.active {
+                continue;
+            }
+
+            let scope = Storage::get_incentive_scope(env, incentive_id);
+            if scope.is_expired_at(env.ledger().timestamp()) || !scope.covers(latitude, longitude) {
+                continue;
+            }
+
+            if let Some(participant) = &participant {
+                if !Self::meets_min_account_age(env, incentive_id, participant) {
+                    continue;
+                }
+            }
+
+            let (_, payout) = Self::limited_reward(env, &incentive, participant.as_ref(), weight);
+            if payout <= 0 || payout > incentive.remaining_budget as i128 {
+                continue;
+            }
+            if payout > Storage::get_incentive_escrow(env, incentive_id) {
+                continue;
+            }
+
+            // Keep the list sorted by payout, oldest incentive first on ties
+            let quote = IncentiveQuote {
+                incentive_id,
+                rewarder: incentive.rewarder,
+                reward_points: incentive.reward_points,
+                payout,
+            };
+            let mut position = quotes.len();
+            for (i, existing) in quotes.iter().enumerate() {
+                if payout > existing.payout {
+                    position = i as u32;
+                    break;
+                }
+            }
+            quotes.insert(position, quote);
+        }
+
+        quotes
+    }
+
+    /// Rank every manufacturer's incentives for a submitted material, using
+    /// its type and weight and its submitter's location and caps
+    pub fn get_best_incentives_for_waste(env: &Env, waste_id: u64) -> Vec<IncentiveQuote> {
+        let material = Storage::get_material(env, waste_id)
+            .expect("Material not found");
+        if Storage::is_reward_paid(env, waste_id) {
+            return Vec::new(env);
+        }
+
+        let submitter = Storage::get_participant(env, &material.submitter)
+            .expect("Submitter not registered");
+
+        Self::get_best_incentives(
+            env,
+            material.waste_type,
+            material.weight,
+            submitter.latitude,
+            submitter.longitude,
+            Some(material.submitter),
+        )
     }
 
     /// Submit material for recycling
@@ -367,6 +685,9 @@ impl ScavengerContract {
             "Submitter not registered"
         );
 
+        let category = Self::require_waste_category(env, waste_type);
+        assert!(category.allows_weight(weight), "Weight exceeds category maximum");
+
         let material_id = Storage::next_material_id(env);
         let material = Material::new(
             material_id,
@@ -440,6 +761,9 @@ impl ScavengerContract {
         // Store the updated material
         Storage::set_material(env, waste_id, &material);
 
+        // Emissions are only counted once the waste is confirmed
+        Self::credit_co2e(env, &material);
+
         // Emit confirmation event
         events::emit_waste_confirmed(env, waste_id, &confirmer);
     }
@@ -470,6 +794,9 @@ impl ScavengerContract {
         // Store the updated material
         Storage::set_material(env, waste_id, &material);
 
+        // Withdraw the emissions credited at confirmation
+        Self::reverse_co2e(env, &material);
+
         // Emit reset event
         events::emit_waste_confirmation_reset(env, waste_id, &owner);
     }
@@ -520,6 +847,8 @@ impl ScavengerContract {
     }
 
     /// Distribute token rewards through the supply chain
+    /// The manufacturer pays the reward into the contract in a single transfer
+    /// and each recipient's share is credited to their claimable balance
     pub fn distribute_rewards(
         env: &Env,
         waste_id: u64,
@@ -538,11 +867,545 @@ impl ScavengerContract {
         let incentive = Storage::get_incentive(env, incentive_id)
             .expect("Incentive not found");
 
+        // Pooled incentives pay out of their funders' escrow
+        if Storage::get_pool_payout(env, incentive_id).is_some() {
+            assert!(
+                Self::is_pool_funder(env, incentive_id, &manufacturer),
+                "Only pool funders can distribute rewards"
+            );
+
+            let (total_reward, total_distributed) = Self::settle_incentive(env, &material, incentive);
+            Self::draw_from_pool(env, incentive_id, total_distributed);
+            return total_reward;
+        }
+
         assert!(
             incentive.rewarder == manufacturer,
             "Only incentive creator can distribute rewards"
         );
 
+        let (total_reward, total_distributed) = Self::settle_incentive(env, &material, incentive);
+
+        // Fund every credited share at once
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+        let contract = env.current_contract_address();
+        token_client.transfer(&manufacturer, &contract, &total_distributed);
+
+        total_reward
+    }
+
+    /// Claim an incentive's reward for a delivered waste without waiting for
+    /// the manufacturer (current owner or original submitter only)
+    /// The reward is paid from the incentive's escrow into claimable balances
+    pub fn claim_incentive(env: &Env, waste_id: u64, incentive_id: u64, claimant: Address) -> i128 {
+        claimant.require_auth();
+
+        let material = Storage::get_material(env, waste_id)
+            .expect("Material not found");
+
+        assert!(
+            claimant == material.current_owner || claimant == material.submitter,
+            "Only the owner or submitter can claim"
+        );
+        assert!(material.is_confirmed, "Material must be confirmed");
+
+        let incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+
+        let pooled = Storage::get_pool_payout(env, incentive_id).is_some();
+        assert!(
+            material.current_owner == incentive.rewarder
+                || (pooled && Self::is_pool_funder(env, incentive_id, &material.current_owner)),
+            "Waste not delivered to incentive manufacturer"
+        );
+
+        let (total_reward, total_distributed) = Self::settle_incentive(env, &material, incentive);
+
+        if pooled {
+            Self::draw_from_pool(env, incentive_id, total_distributed);
+            return total_reward;
+        }
+
+        let escrow = Storage::get_incentive_escrow(env, incentive_id);
+        assert!(total_distributed <= escrow, "Insufficient escrowed funds");
+        Storage::set_incentive_escrow(env, incentive_id, escrow - total_distributed);
+
+        total_reward
+    }
+
+    /// Get the tokens escrowed for an incentive
+    pub fn get_incentive_escrow(env: &Env, incentive_id: u64) -> i128 {
+        Storage::get_incentive_escrow(env, incentive_id)
+    }
+
+    /// Create an incentive whose budget is pooled by several manufacturers
+    /// The pool starts empty; its budget grows as funders contribute
+    pub fn create_incentive_pool(
+        env: &Env,
+        rewarder: Address,
+        waste_type: WasteType,
+        reward_points: u64,
+        payout: PoolPayout,
+    ) -> Incentive {
+        let incentive = Self::create_incentive(env, rewarder, waste_type, reward_points, 0);
+        Storage::set_pool_payout(env, incentive.id, payout);
+        incentive
+    }
+
+    /// Contribute tokens to a pooled incentive (registered manufacturers only)
+    /// `share_bps` sets the funder's share of payouts in share-based pools and
+    /// must be zero for pro-rata pools
+    pub fn contribute_to_pool(
+        env: &Env,
+        funder: Address,
+        incentive_id: u64,
+        amount: i128,
+        share_bps: u32,
+    ) -> PoolContribution {
+        funder.require_auth();
+
+        let participant = Storage::get_participant(env, &funder)
+            .expect("Funder not registered");
+        assert!(
+            participant.role.can_manufacture(),
+            "Only manufacturers can fund pools"
+        );
+
+        let payout = Storage::get_pool_payout(env, incentive_id)
+            .expect("Incentive is not pooled");
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(incentive.active, "Incentive not active");
+        assert!(amount > 0, "Amount must be greater than zero");
+
+        let mut contributions = Storage::get_pool_contributions(env, incentive_id);
+        let contribution = pool::contribute(&mut contributions, payout, &funder, amount, share_bps);
+
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+        let contract = env.current_contract_address();
+        token_client.transfer(&funder, &contract, &amount);
+
+        Storage::set_pool_contributions(env, incentive_id, &contributions);
+
+        incentive.total_budget += amount as u64;
+        incentive.remaining_budget += amount as u64;
+        Storage::set_incentive(env, incentive_id, &incentive);
+
+        let escrow = Storage::get_incentive_escrow(env, incentive_id) + amount;
+        Storage::set_incentive_escrow(env, incentive_id, escrow);
+
+        events::emit_pool_contributed(env, incentive_id, &funder, amount);
+        contribution
+    }
+
+    /// Close a pooled incentive and refund each funder's unspent contribution
+    /// (pool creator only)
+    pub fn close_incentive_pool(env: &Env, rewarder: Address, incentive_id: u64) -> Vec<PoolContribution> {
+        rewarder.require_auth();
+
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        assert!(incentive.rewarder == rewarder, "Only rewarder can close");
+        assert!(
+            Storage::get_pool_payout(env, incentive_id).is_some(),
+            "Incentive is not pooled"
+        );
+        assert!(!Storage::is_incentive_closed(env, incentive_id), "Incentive is closed");
+
+        incentive.active = false;
+        incentive.remaining_budget = 0;
+        Storage::set_incentive(env, incentive_id, &incentive);
+        Storage::set_incentive_closed(env, incentive_id);
+
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+        let contract = env.current_contract_address();
+
+        let mut contributions = Storage::get_pool_contributions(env, incentive_id);
+        for i in 0..contributions.len() {
+            let mut contribution = contributions.get(i).unwrap();
+            if contribution.remaining > 0 {
+                token_client.transfer(&contract, &contribution.funder, &contribution.remaining);
+                events::emit_pool_refunded(env, incentive_id, &contribution.funder, contribution.remaining);
+                contribution.remaining = 0;
+                contributions.set(i, contribution);
+            }
+        }
+        Storage::set_pool_contributions(env, incentive_id, &contributions);
+
+        let refunded = Storage::get_incentive_escrow(env, incentive_id);
+        Storage::set_incentive_escrow(env, incentive_id, 0);
+        events::emit_incentive_closed(env, incentive_id, &rewarder, refunded);
+
+        contributions
+    }
+
+    /// Get each funder's contribution to a pooled incentive
+    pub fn get_pool_contributions(env: &Env, incentive_id: u64) -> Vec<PoolContribution> {
+        Storage::get_pool_contributions(env, incentive_id)
+    }
+
+    /// Get how a pooled incentive splits payouts, or None if it is not pooled
+    pub fn get_pool_payout(env: &Env, incentive_id: u64) -> Option<PoolPayout> {
+        Storage::get_pool_payout(env, incentive_id)
+    }
+
+    /// Check if an incentive reward has been paid for a waste
+    pub fn is_reward_paid(env: &Env, waste_id: u64) -> bool {
+        Storage::is_reward_paid(env, waste_id)
+    }
+
+    /// Mint recycling credits from a confirmed material (owner only)
+    /// Credits are denominated in grams of the material's waste type
+    /// and can be minted only once per material
+    pub fn mint_credits(env: &Env, waste_id: u64, owner: Address) -> u64 {
+        owner.require_auth();
+
+        let material = Storage::get_material(env, waste_id)
+            .expect("Material not found");
+
+        assert!(
+            material.current_owner == owner,
+            "Only current owner can mint credits"
+        );
+        assert!(material.is_active, "Waste is not active");
+        assert!(
+            material.is_confirmed || material.verified,
+            "Material must be confirmed"
+        );
+        assert!(
+            !Storage::is_material_credited(env, waste_id),
+            "Credits already minted for material"
+        );
+        assert!(material.weight > 0, "Material has no weight to credit");
+
+        Storage::set_material_credited(env, waste_id);
+        let balance = Storage::get_credit_balance(env, &owner, material.waste_type);
+        Storage::set_credit_balance(env, &owner, material.waste_type, balance + material.weight);
+
+        events::emit_credits_minted(env, waste_id, &owner, material.waste_type, material.weight);
+
+        material.weight
+    }
+
+    /// Check if credits have already been minted for a material
+    pub fn is_material_credited(env: &Env, waste_id: u64) -> bool {
+        Storage::is_material_credited(env, waste_id)
+    }
+
+    /// Get an address's credit balance for a waste type in grams
+    pub fn get_credit_balance(env: &Env, owner: Address, waste_type: WasteType) -> u64 {
+        Storage::get_credit_balance(env, &owner, waste_type)
+    }
+
+    /// Transfer recycling credits to another address
+    pub fn transfer_credits(
+        env: &Env,
+        from: Address,
+        to: Address,
+        waste_type: WasteType,
+        grams: u64,
+    ) {
+        from.require_auth();
+
+        assert!(grams > 0, "Credit amount must be greater than zero");
+        assert!(from != to, "Sender and recipient cannot be the same");
+
+        let from_balance = Storage::get_credit_balance(env, &from, waste_type);
+        assert!(from_balance >= grams, "Insufficient credit balance");
+
+        Storage::set_credit_balance(env, &from, waste_type, from_balance - grams);
+        let to_balance = Storage::get_credit_balance(env, &to, waste_type);
+        Storage::set_credit_balance(env, &to, waste_type, to_balance + grams);
+
+        events::emit_credits_transferred(env, &from, &to, waste_type, grams);
+    }
+
+    /// Permanently retire recycling credits and issue a certificate
+    pub fn retire_credits(
+        env: &Env,
+        owner: Address,
+        waste_type: WasteType,
+        grams: u64,
+    ) -> RetirementCertificate {
+        owner.require_auth();
+
+        assert!(grams > 0, "Credit amount must be greater than zero");
+
+        let balance = Storage::get_credit_balance(env, &owner, waste_type);
+        assert!(balance >= grams, "Insufficient credit balance");
+        Storage::set_credit_balance(env, &owner, waste_type, balance - grams);
+
+        let certificate = RetirementCertificate {
+            id: Storage::next_certificate_id(env),
+            owner: owner.clone(),
+            waste_type,
+            grams,
+            retired_at: env.ledger().timestamp(),
+        };
+        Storage::set_certificate(env, &certificate);
+        Storage::add_to_total_retired(env, waste_type, grams);
+
+        events::emit_credits_retired(env, certificate.id, &owner, waste_type, grams);
+
+        certificate
+    }
+
+    /// Get a retirement certificate by ID
+    pub fn get_retirement_certificate(env: &Env, certificate_id: u64) -> Option<RetirementCertificate> {
+        Storage::get_certificate(env, certificate_id)
+    }
+
+    /// Get the total credits retired for a waste type in grams
+    pub fn get_total_retired(env: &Env, waste_type: WasteType) -> u64 {
+        Storage::get_total_retired(env, waste_type)
+    }
+
+    /// Withdraw accrued rewards to the participant's account
+    /// Returns the balance left to claim
+    pub fn claim_rewards(env: &Env, address: Address, amount: i128) -> i128 {
+        address.require_auth();
+
+        assert!(amount > 0, "Claim amount must be positive");
+
+        let claimable = Storage::get_claimable(env, &address);
+        assert!(amount <= claimable, "Insufficient claimable balance");
+
+        let remaining = claimable - amount;
+        Storage::set_claimable(env, &address, remaining);
+
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &address, &amount);
+
+        events::emit_rewards_claimed(env, &address, amount, remaining);
+        remaining
+    }
+
+    /// Get the rewards a participant has accrued but not yet claimed
+    pub fn get_claimable(env: &Env, address: Address) -> i128 {
+        Storage::get_claimable(env, &address)
+    }
+
+    /// Make reward shares above `threshold` vest instead of becoming claimable
+    /// at once (admin only)
+    /// Shares stay locked for `lock_period` seconds, then vest linearly over
+    /// `vesting_period` seconds
+    pub fn set_vesting_policy(env: &Env, admin: Address, policy: VestingPolicy) {
+        Self::require_admin(env, &admin);
+
+        assert!(policy.threshold >= 0, "Threshold cannot be negative");
+        assert!(
+            policy.lock_period > 0 || policy.vesting_period > 0,
+            "Vesting policy must lock or vest"
+        );
+
+        Storage::set_vesting_policy(env, &policy);
+    }
+
+    /// Stop vesting new reward shares (admin only)
+    /// Existing schedules keep vesting
+    pub fn clear_vesting_policy(env: &Env, admin: Address) {
+        Self::require_admin(env, &admin);
+        Storage::remove_vesting_policy(env);
+    }
+
+    /// Get the vesting policy, if one is set
+    pub fn get_vesting_policy(env: &Env) -> Option<VestingPolicy> {
+        Storage::get_vesting_policy(env)
+    }
+
+    /// Get a vesting schedule by ID
+    pub fn get_vesting_schedule(env: &Env, schedule_id: u64) -> Option<VestingSchedule> {
+        Storage::get_vesting_schedule(env, schedule_id)
+    }
+
+    /// Get all of a participant's vesting schedules
+    pub fn get_vesting_schedules(env: &Env, address: Address) -> Vec<VestingSchedule> {
+        let mut schedules = Vec::new(env);
+        for id in Storage::get_participant_vesting(env, &address).iter() {
+            if let Some(schedule) = Storage::get_vesting_schedule(env, id) {
+                schedules.push_back(schedule);
+            }
+        }
+        schedules
+    }
+
+    /// Get the vested rewards a participant can release right now
+    pub fn get_releasable(env: &Env, address: Address) -> i128 {
+        let now = env.ledger().timestamp();
+        let mut releasable = 0;
+        for schedule in Self::get_vesting_schedules(env, address).iter() {
+            releasable += schedule.releasable_at(now);
+        }
+        releasable
+    }
+
+    /// Move every vested reward of a participant to their claimable balance
+    /// Returns the amount released
+    pub fn release_vested(env: &Env, address: Address) -> i128 {
+        address.require_auth();
+
+        let now = env.ledger().timestamp();
+        let mut released = 0;
+        for mut schedule in Self::get_vesting_schedules(env, address.clone()).iter() {
+            let amount = schedule.releasable_at(now);
+            if amount > 0 {
+                schedule.released += amount;
+                Storage::set_vesting_schedule(env, &schedule);
+                released += amount;
+            }
+        }
+
+        assert!(released > 0, "Nothing vested to release");
+
+        Storage::add_claimable(env, &address, released);
+        events::emit_vesting_released(env, &address, released);
+        released
+    }
+
+    /// Dispute the rewards paid for a waste (paying rewarder or pool funder only)
+    /// Only rewards that are still vesting can be disputed; the admin then
+    /// decides the outcome with `resolve_dispute`
+    pub fn raise_dispute(env: &Env, disputer: Address, waste_id: u64) {
+        disputer.require_auth();
+
+        assert!(Storage::get_material(env, waste_id).is_some(), "Material not found");
+        assert!(Storage::get_open_dispute(env, waste_id).is_none(), "Dispute already open");
+        assert!(!Storage::is_dispute_succeeded(env, waste_id), "Dispute already succeeded");
+
+        let schedules = Storage::get_waste_vesting(env, waste_id);
+        assert!(!schedules.is_empty(), "No vesting rewards to dispute");
+
+        let paid_for = schedules.iter().any(|id| {
+            let schedule = Storage::get_vesting_schedule(env, id)
+                .expect("Vesting schedule not found");
+            let incentive = Storage::get_incentive(env, schedule.incentive_id)
+                .expect("Incentive not found");
+            incentive.rewarder == disputer
+                || Self::is_pool_funder(env, schedule.incentive_id, &disputer)
+        });
+        assert!(paid_for, "Only a paying rewarder can dispute");
+
+        Storage::set_open_dispute(env, waste_id, &disputer);
+        events::emit_dispute_raised(env, waste_id, &disputer);
+    }
+
+    /// Decide an open dispute against a waste's rewards (admin only)
+    /// A successful dispute is what allows `clawback_unvested` for the waste
+    pub fn resolve_dispute(env: &Env, admin: Address, waste_id: u64, succeeded: bool) {
+        Self::require_admin(env, &admin);
+
+        assert!(Storage::get_open_dispute(env, waste_id).is_some(), "No open dispute");
+
+        Storage::remove_open_dispute(env, waste_id);
+        if succeeded {
+            Storage::set_dispute_succeeded(env, waste_id);
+        }
+        events::emit_dispute_resolved(env, waste_id, &admin, succeeded);
+    }
+
+    /// Get who has an open dispute against a waste's rewards, if anyone
+    pub fn get_open_dispute(env: &Env, waste_id: u64) -> Option<Address> {
+        Storage::get_open_dispute(env, waste_id)
+    }
+
+    /// Check if a dispute against a waste's rewards has succeeded
+    pub fn is_dispute_succeeded(env: &Env, waste_id: u64) -> bool {
+        Storage::is_dispute_succeeded(env, waste_id)
+    }
+
+    /// Claw back the unvested rewards earned for a waste once a dispute
+    /// against it has succeeded (admin only)
+    /// Already vested rewards stay with their beneficiaries; clawed-back
+    /// tokens return to the pool that paid them, or to the rewarder of a
+    /// single-funder incentive, and go back on the incentive's budget
+    /// The waste is no longer marked as paid afterwards
+    /// Returns the amount clawed back
+    pub fn clawback_unvested(env: &Env, admin: Address, waste_id: u64) -> i128 {
+        Self::require_admin(env, &admin);
+
+        assert!(Storage::is_dispute_succeeded(env, waste_id), "Dispute not succeeded");
+
+        let now = env.ledger().timestamp();
+        let token_address = Storage::get_token_address(env)
+            .expect("Token address not set");
+        let token_client = token::Client::new(env, &token_address);
+        let contract = env.current_contract_address();
+
+        let mut total_clawed = 0;
+        for id in Storage::get_waste_vesting(env, waste_id).iter() {
+            let mut schedule = Storage::get_vesting_schedule(env, id)
+                .expect("Vesting schedule not found");
+            if schedule.clawed_back {
+                continue;
+            }
+
+            let unvested = schedule.total - schedule.vested_at(now);
+            schedule.total -= unvested;
+            schedule.clawed_back = true;
+            Storage::set_vesting_schedule(env, &schedule);
+
+            if unvested > 0 {
+                Storage::add_earnings(env, &schedule.beneficiary, -unvested);
+                if Storage::get_pool_payout(env, schedule.incentive_id).is_some() {
+                    Self::return_to_pool(env, schedule.incentive_id, unvested);
+                } else {
+                    let mut incentive = Storage::get_incentive(env, schedule.incentive_id)
+                        .expect("Incentive not found");
+                    token_client.transfer(&contract, &incentive.rewarder, &unvested);
+
+                    // The clawed-back reward no longer counts against the budget
+                    if !Storage::is_incentive_closed(env, schedule.incentive_id) {
+                        if !incentive.active && incentive.remaining_budget == 0 {
+                            incentive.active = true;
+                        }
+                        incentive.remaining_budget += unvested as u64;
+                        Storage::set_incentive(env, schedule.incentive_id, &incentive);
+                    }
+                }
+                total_clawed += unvested;
+            }
+
+            events::emit_vesting_clawed_back(env, id, waste_id, unvested);
+        }
+
+        assert!(total_clawed > 0, "Nothing to claw back");
+
+        Storage::remove_reward_paid(env, waste_id);
+        Storage::add_to_total_earned(env, -total_clawed);
+        total_clawed
+    }
+
+    /// Get participant statistics
+    pub fn get_participant_stats(env: &Env, address: Address) -> crate::types::ParticipantStats {
+        Storage::get_stats(env, &address)
+    }
+
+    /// Get supply chain statistics (total wastes, total weight, total tokens earned)
+    pub fn get_supply_chain_stats(env: &Env) -> (u64, u64, i128) {
+        let total_wastes = env.storage().instance()
+            .get(&soroban_sdk::symbol_short!("MAT_CNT"))
+            .unwrap_or(0);
+        let total_weight = Storage::get_total_weight(env);
+        let total_tokens = Storage::get_total_earned(env);
+        
+        (total_wastes, total_weight, total_tokens)
+    }
+
+    // Private helper function to check an incentive applies to a material and
+    // credit its reward to the supply chain; returns (reward, amount credited)
+    fn settle_incentive(env: &Env, material: &Material, incentive: Incentive) -> (i128, i128) {
+        let waste_id = material.id;
+
+        assert!(!Storage::is_reward_paid(env, waste_id), "Reward already paid");
+
         assert!(
             incentive.waste_type == material.waste_type,
             "Waste type mismatch"
@@ -550,9 +1413,27 @@ impl ScavengerContract {
 
         assert!(incentive.active, "Incentive not active");
 
-        // Calculate total reward (incentive * weight in kg)
-        let weight_kg = material.weight / 1000;
-        let total_reward = (incentive.reward_points as i128) * (weight_kg as i128);
+        assert!(
+            Self::meets_min_account_age(env, incentive.id, &material.submitter),
+            "Account too new for incentive"
+        );
+
+        let scope = Storage::get_incentive_scope(env, incentive.id);
+        assert!(!scope.is_expired_at(env.ledger().timestamp()), "Incentive expired");
+        let submitter = Storage::get_participant(env, &material.submitter)
+            .expect("Submitter not registered");
+        assert!(
+            scope.covers(submitter.latitude, submitter.longitude),
+            "Submitter outside incentive region"
+        );
+
+        // Calculate total reward (incentive * weight in kg), within the
+        // submitter's limits
+        let (rewarded_weight, total_reward) =
+            Self::limited_reward(env, &incentive, Some(&material.submitter), material.weight);
+        // Nothing is marked paid or counted against caps for a zero reward
+        assert!(material.weight >= 1000, "Material below one kilogram");
+        assert!(total_reward > 0, "Incentive limit reached");
 
         assert!(
             (total_reward as u64) <= incentive.remaining_budget,
@@ -568,10 +1449,6 @@ impl ScavengerContract {
         let owner_pct = Storage::get_owner_percentage(env)
             .expect("Owner percentage not set");
 
-        let token_address = Storage::get_token_address(env)
-            .expect("Token address not set");
-        let token_client = token::Client::new(env, &token_address);
-
         // Calculate collector shares (5% each from total)
         let collector_share = (total_reward * (collector_pct as i128)) / 100;
         
@@ -585,62 +1462,278 @@ impl ScavengerContract {
             let participant = Storage::get_participant(env, &transfer.to);
             if let Some(p) = participant {
                 if matches!(p.role, Role::Collector) {
-                    // Transfer tokens to collector
-                    token_client.transfer(&manufacturer, &transfer.to, &collector_share);
-                    
-                    // Update participant statistics
-                    Storage::add_earnings(env, &transfer.to, collector_share);
-                    
-                    // Emit TokensRewarded event
-                    events::emit_tokens_rewarded(env, waste_id, &transfer.to, collector_share);
-                    
+                    Self::accrue_reward(env, waste_id, incentive.id, &transfer.to, collector_share);
                     total_distributed += collector_share;
                 }
             }
         }
 
         // Reward the original owner (submitter) with their share
-        token_client.transfer(&manufacturer, &material.submitter, &owner_share);
-        Storage::add_earnings(env, &material.submitter, owner_share);
-        events::emit_tokens_rewarded(env, waste_id, &material.submitter, owner_share);
+        Self::accrue_reward(env, waste_id, incentive.id, &material.submitter, owner_share);
         total_distributed += owner_share;
 
         // Recycler gets remaining amount
         let recycler_amount = total_reward - total_distributed;
         if recycler_amount > 0 {
-            token_client.transfer(&manufacturer, &material.current_owner, &recycler_amount);
-            Storage::add_earnings(env, &material.current_owner, recycler_amount);
-            events::emit_tokens_rewarded(env, waste_id, &material.current_owner, recycler_amount);
+            Self::accrue_reward(env, waste_id, incentive.id, &material.current_owner, recycler_amount);
+            total_distributed += recycler_amount;
         }
 
+        // Record the submitter's usage against the incentive
+        let incentive_id = incentive.id;
+        let mut usage = Self::current_usage(env, incentive_id, &material.submitter);
+        usage.weight += rewarded_weight;
+        usage.reward += total_reward as u64;
+        usage.period_reward += total_reward as u64;
+        Storage::set_incentive_usage(env, incentive_id, &material.submitter, &usage);
+
         // Update incentive budget
         let mut updated_incentive = incentive;
         updated_incentive.remaining_budget -= total_reward as u64;
         if updated_incentive.remaining_budget == 0 {
             updated_incentive.active = false;
+            events::emit_incentive_exhausted(env, incentive_id, updated_incentive.total_budget);
         }
         Storage::set_incentive(env, incentive_id, &updated_incentive);
 
         // Update total earned
         Storage::add_to_total_earned(env, total_reward);
+        Storage::set_reward_paid(env, waste_id);
 
-        total_reward
+        (total_reward, total_distributed)
     }
 
-    /// Get participant statistics
-    pub fn get_participant_stats(env: &Env, address: Address) -> crate::types::ParticipantStats {
-        Storage::get_stats(env, &address)
+    // Private helper function to credit a confirmed material's avoided
+    // emissions to its submitter and the global total, once per confirmation
+    fn credit_co2e(env: &Env, material: &Material) {
+        if Storage::get_waste_co2e(env, material.id).is_some() {
+            return;
+        }
+
+        let co2e = material
+            .weight
+            .checked_mul(Storage::get_emission_factor(env, material.waste_type))
+            .expect("Overflow in CO2e")
+            / 1000;
+
+        Storage::set_waste_co2e(env, material.id, co2e);
+        let participant_co2e = Storage::get_participant_co2e(env, &material.submitter);
+        Storage::set_participant_co2e(env, &material.submitter, participant_co2e + co2e);
+        Storage::add_to_total_co2e(env, co2e);
     }
 
-    /// Get supply chain statistics (total wastes, total weight, total tokens earned)
-    pub fn get_supply_chain_stats(env: &Env) -> (u64, u64, i128) {
-        let total_wastes = env.storage().instance()
-            .get(&soroban_sdk::symbol_short!("MAT_CNT"))
-            .unwrap_or(0);
-        let total_weight = Storage::get_total_weight(env);
-        let total_tokens = Storage::get_total_earned(env);
-        
-        (total_wastes, total_weight, total_tokens)
+    // Private helper function to withdraw the emissions credited for a
+    // material when its confirmation is reset
+    fn reverse_co2e(env: &Env, material: &Material) {
+        let Some(co2e) = Storage::get_waste_co2e(env, material.id) else {
+            return;
+        };
+
+        Storage::remove_waste_co2e(env, material.id);
+        let participant_co2e = Storage::get_participant_co2e(env, &material.submitter);
+        Storage::set_participant_co2e(env, &material.submitter, participant_co2e - co2e);
+        Storage::set_total_co2e(env, Storage::get_total_co2e(env) - co2e);
+    }
+
+    // Private helper function to work out the weight and reward a delivery
+    // earns from an incentive once the participant's limits are applied;
+    // without a participant the limits apply as if nothing had been earned yet
+    fn limited_reward(
+        env: &Env,
+        incentive: &Incentive,
+        participant: Option<&Address>,
+        weight: u64,
+    ) -> (u64, i128) {
+        let Some(limits) = Storage::get_incentive_limits(env, incentive.id) else {
+            let weight_kg = weight / 1000;
+            return (weight, (incentive.reward_points as i128) * (weight_kg as i128));
+        };
+
+        let usage = match participant {
+            Some(participant) => Self::current_usage(env, incentive.id, participant),
+            None => IncentiveUsage::default(),
+        };
+
+        let mut weight = weight;
+        if limits.max_weight_per_participant > 0 {
+            weight = weight.min(limits.max_weight_per_participant.saturating_sub(usage.weight));
+        }
+
+        let mut reward = incentive.reward_points * (weight / 1000);
+        if limits.max_reward_per_participant > 0 {
+            reward = reward.min(limits.max_reward_per_participant.saturating_sub(usage.reward));
+        }
+        if limits.max_reward_per_period > 0 {
+            reward = reward.min(limits.max_reward_per_period.saturating_sub(usage.period_reward));
+        }
+
+        (weight, reward as i128)
+    }
+
+    // Private helper function to load a participant's usage of an incentive,
+    // starting a fresh cap window if the last one has ended
+    fn current_usage(env: &Env, incentive_id: u64, participant: &Address) -> IncentiveUsage {
+        let mut usage = Storage::get_incentive_usage(env, incentive_id, participant);
+        if let Some(limits) = Storage::get_incentive_limits(env, incentive_id) {
+            let period_start = limits.period_start(env.ledger().timestamp());
+            if period_start != usage.period_start {
+                usage.period_start = period_start;
+                usage.period_reward = 0;
+            }
+        }
+        usage
+    }
+
+    // Private helper function to check a participant has been registered long
+    // enough to earn from an incentive
+    fn meets_min_account_age(env: &Env, incentive_id: u64, participant: &Address) -> bool {
+        let Some(limits) = Storage::get_incentive_limits(env, incentive_id) else {
+            return true;
+        };
+        if limits.min_account_age == 0 {
+            return true;
+        }
+
+        let Some(registered) = Storage::get_participant(env, participant) else {
+            return false;
+        };
+        env.ledger().timestamp().saturating_sub(registered.registered_at) >= limits.min_account_age
+    }
+
+    // Private helper function to check whether an address has funded a pool
+    fn is_pool_funder(env: &Env, incentive_id: u64, address: &Address) -> bool {
+        Storage::get_pool_contributions(env, incentive_id)
+            .iter()
+            .any(|contribution| contribution.funder == *address)
+    }
+
+    // Private helper function to pay an amount out of a pool's escrow, split
+    // between funders by the pool's payout rule
+    fn draw_from_pool(env: &Env, incentive_id: u64, amount: i128) {
+        let payout = Storage::get_pool_payout(env, incentive_id)
+            .expect("Incentive is not pooled");
+        let mut contributions = Storage::get_pool_contributions(env, incentive_id);
+
+        let escrow = Storage::get_incentive_escrow(env, incentive_id);
+        assert!(amount <= escrow, "Insufficient escrowed funds");
+
+        pool::draw(&mut contributions, payout, amount);
+        Storage::set_pool_contributions(env, incentive_id, &contributions);
+        Storage::set_incentive_escrow(env, incentive_id, escrow - amount);
+    }
+
+    // Private helper function to give clawed-back tokens back to a pool's
+    // funders in proportion to what each has paid out; an open pool gets them
+    // back in its escrow and budget, a closed pool refunds them at once
+    fn return_to_pool(env: &Env, incentive_id: u64, amount: i128) {
+        let mut contributions = Storage::get_pool_contributions(env, incentive_id);
+        let closed = Storage::is_incentive_closed(env, incentive_id);
+
+        let mut total_paid: i128 = 0;
+        for contribution in contributions.iter() {
+            total_paid += contribution.contributed - contribution.remaining;
+        }
+
+        let mut portions: Vec<i128> = Vec::new(env);
+        let mut left = amount;
+        for contribution in contributions.iter() {
+            let paid = contribution.contributed - contribution.remaining;
+            let portion = if total_paid > 0 {
+                (amount * paid / total_paid).min(paid)
+            } else {
+                0
+            };
+            portions.push_back(portion);
+            left -= portion;
+        }
+
+        // Rounding dust goes to whoever still has room for it
+        for i in 0..contributions.len() {
+            if left == 0 {
+                break;
+            }
+            let contribution = contributions.get(i).unwrap();
+            let room = contribution.contributed - contribution.remaining - portions.get(i).unwrap();
+            let portion = left.min(room);
+            portions.set(i, portions.get(i).unwrap() + portion);
+            left -= portion;
+        }
+        assert!(left == 0, "Clawback exceeds pool payouts");
+
+        if closed {
+            let token_address = Storage::get_token_address(env)
+                .expect("Token address not set");
+            let token_client = token::Client::new(env, &token_address);
+            let contract = env.current_contract_address();
+            for (i, contribution) in contributions.iter().enumerate() {
+                let portion = portions.get(i as u32).unwrap();
+                if portion > 0 {
+                    token_client.transfer(&contract, &contribution.funder, &portion);
+                    events::emit_pool_refunded(env, incentive_id, &contribution.funder, portion);
+                }
+            }
+            return;
+        }
+
+        for i in 0..contributions.len() {
+            let mut contribution = contributions.get(i).unwrap();
+            contribution.remaining += portions.get(i).unwrap();
+            contributions.set(i, contribution);
+        }
+        Storage::set_pool_contributions(env, incentive_id, &contributions);
+
+        let escrow = Storage::get_incentive_escrow(env, incentive_id);
+        Storage::set_incentive_escrow(env, incentive_id, escrow + amount);
+
+        let mut incentive = Storage::get_incentive(env, incentive_id)
+            .expect("Incentive not found");
+        if incentive.remaining_budget == 0 {
+            incentive.active = true;
+        }
+        incentive.remaining_budget += amount as u64;
+        Storage::set_incentive(env, incentive_id, &incentive);
+    }
+
+    // Private helper function to credit a reward share to a claimable balance,
+    // or to a vesting schedule when it exceeds the vesting threshold
+    fn accrue_reward(
+        env: &Env,
+        waste_id: u64,
+        incentive_id: u64,
+        recipient: &Address,
+        amount: i128,
+    ) {
+        Storage::add_earnings(env, recipient, amount);
+
+        if let Some(policy) = Storage::get_vesting_policy(env) {
+            if amount > policy.threshold {
+                let schedule = VestingSchedule {
+                    id: Storage::next_vesting_id(env),
+                    beneficiary: recipient.clone(),
+                    waste_id,
+                    incentive_id,
+                    total: amount,
+                    released: 0,
+                    start: env.ledger().timestamp(),
+                    lock_period: policy.lock_period,
+                    vesting_period: policy.vesting_period,
+                    clawed_back: false,
+                };
+                Storage::add_vesting_schedule(env, &schedule);
+                events::emit_vesting_started(env, &schedule);
+                return;
+            }
+        }
+
+        Storage::add_claimable(env, recipient, amount);
+        events::emit_rewards_accrued(env, waste_id, recipient, amount);
+    }
+
+    // Private helper function to look up an active catalogue category
+    fn require_waste_category(env: &Env, waste_type: WasteType) -> WasteCategory {
+        let category = Storage::get_waste_category(env, waste_type).expect("Unknown waste type");
+        assert!(category.is_active, "Waste type is not active");
+        category
     }
 
     // Private helper function to require admin authentication
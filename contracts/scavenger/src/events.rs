@@ -1,14 +1,31 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
-use crate::types::{Role, WasteType};
+use crate::types::{IncentiveLimits, IncentiveScope, Role, VestingSchedule, WasteType};
 
 const PARTICIPANT_REGISTERED: Symbol = symbol_short!("reg");
 const INCENTIVE_SET: Symbol = symbol_short!("inc_set");
 const INCENTIVE_UPDATED: Symbol = symbol_short!("inc_upd");
-const TOKENS_REWARDED: Symbol = symbol_short!("rewarded");
+const INCENTIVE_TOPPED_UP: Symbol = symbol_short!("inc_topup");
+const INCENTIVE_DEACTIVATED: Symbol = symbol_short!("inc_deact");
+const INCENTIVE_CLOSED: Symbol = symbol_short!("inc_close");
+const INCENTIVE_EXHAUSTED: Symbol = symbol_short!("inc_exhst");
+const INCENTIVE_LIMITED: Symbol = symbol_short!("inc_limit");
+const INCENTIVE_SCOPED: Symbol = symbol_short!("inc_scope");
+const POOL_CONTRIBUTED: Symbol = symbol_short!("pool_add");
+const POOL_REFUNDED: Symbol = symbol_short!("pool_rfnd");
+const REWARDS_ACCRUED: Symbol = symbol_short!("accrued");
+const REWARDS_CLAIMED: Symbol = symbol_short!("claimed");
+const VESTING_STARTED: Symbol = symbol_short!("vest_new");
+const VESTING_RELEASED: Symbol = symbol_short!("vest_rel");
+const VESTING_CLAWED_BACK: Symbol = symbol_short!("vest_claw");
+const DISPUTE_RAISED: Symbol = symbol_short!("disp_new");
+const DISPUTE_RESOLVED: Symbol = symbol_short!("disp_res");
 const WASTE_DEACTIVATED: Symbol = symbol_short!("wst_deact");
 const WASTE_CONFIRMED: Symbol = symbol_short!("wst_conf");
 const WASTE_CONFIRMATION_RESET: Symbol = symbol_short!("wst_rst");
+const CREDITS_MINTED: Symbol = symbol_short!("cr_mint");
+const CREDITS_TRANSFERRED: Symbol = symbol_short!("cr_xfer");
+const CREDITS_RETIRED: Symbol = symbol_short!("cr_retire");
 
 /// Emit event when a participant registers
 pub fn emit_participant_registered(
@@ -54,19 +71,108 @@ pub fn emit_incentive_updated(
     );
 }
 
-/// Emit event when tokens are rewarded
-pub fn emit_tokens_rewarded(
+/// Emit event when an incentive's budget is topped up with escrowed funds
+pub fn emit_incentive_topped_up(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    amount: i128,
+    remaining_budget: u64,
+) {
+    env.events().publish(
+        (INCENTIVE_TOPPED_UP, incentive_id),
+        (rewarder, amount, remaining_budget),
+    );
+}
+
+/// Emit event when an incentive is deactivated by its rewarder
+pub fn emit_incentive_deactivated(env: &Env, incentive_id: u64, rewarder: &Address) {
+    env.events()
+        .publish((INCENTIVE_DEACTIVATED, incentive_id), rewarder);
+}
+
+/// Emit event when an incentive is closed and its escrow refunded
+pub fn emit_incentive_closed(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    refunded: i128,
+) {
+    env.events().publish(
+        (INCENTIVE_CLOSED, incentive_id),
+        (rewarder, refunded),
+    );
+}
+
+/// Emit event when an incentive's budget runs out
+pub fn emit_incentive_exhausted(env: &Env, incentive_id: u64, total_budget: u64) {
+    env.events()
+        .publish((INCENTIVE_EXHAUSTED, incentive_id), total_budget);
+}
+
+/// Emit event when a reward is credited to a participant's claimable balance
+pub fn emit_rewards_accrued(
     env: &Env,
     waste_id: u64,
     recipient: &Address,
     amount: i128,
 ) {
     env.events().publish(
-        (TOKENS_REWARDED, waste_id),
+        (REWARDS_ACCRUED, waste_id),
         (recipient, amount),
     );
 }
 
+/// Emit event when a participant withdraws from their claimable balance
+pub fn emit_rewards_claimed(
+    env: &Env,
+    recipient: &Address,
+    amount: i128,
+    remaining: i128,
+) {
+    env.events().publish(
+        (REWARDS_CLAIMED, recipient),
+        (amount, remaining),
+    );
+}
+
+/// Emit event when a reward share is put on a vesting schedule
+pub fn emit_vesting_started(env: &Env, schedule: &VestingSchedule) {
+    env.events().publish(
+        (VESTING_STARTED, schedule.waste_id),
+        (&schedule.beneficiary, schedule.id, schedule.total),
+    );
+}
+
+/// Emit event when vested rewards are moved to a claimable balance
+pub fn emit_vesting_released(env: &Env, recipient: &Address, amount: i128) {
+    env.events().publish((VESTING_RELEASED, recipient), amount);
+}
+
+/// Emit event when the unvested part of a schedule is clawed back
+pub fn emit_vesting_clawed_back(env: &Env, schedule_id: u64, waste_id: u64, amount: i128) {
+    env.events().publish(
+        (VESTING_CLAWED_BACK, waste_id),
+        (schedule_id, amount),
+    );
+}
+
+/// Emit event when a rewarder disputes a waste's rewards
+pub fn emit_dispute_raised(env: &Env, waste_id: u64, disputer: &Address) {
+    env.events().publish(
+        (DISPUTE_RAISED, waste_id),
+        disputer,
+    );
+}
+
+/// Emit event when the admin decides a dispute against a waste's rewards
+pub fn emit_dispute_resolved(env: &Env, waste_id: u64, admin: &Address, succeeded: bool) {
+    env.events().publish(
+        (DISPUTE_RESOLVED, waste_id),
+        (admin, succeeded),
+    );
+}
+
 /// Emit event when waste is deactivated
 pub fn emit_waste_deactivated(
     env: &Env,
@@ -102,3 +208,97 @@ pub fn emit_waste_confirmation_reset(
         owner,
     );
 }
+
+/// Emit event when recycling credits are minted from a waste
+pub fn emit_credits_minted(
+    env: &Env,
+    waste_id: u64,
+    owner: &Address,
+    waste_type: WasteType,
+    grams: u64,
+) {
+    env.events().publish(
+        (CREDITS_MINTED, waste_id),
+        (owner, waste_type, grams),
+    );
+}
+
+/// Emit event when recycling credits change hands
+pub fn emit_credits_transferred(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    waste_type: WasteType,
+    grams: u64,
+) {
+    env.events().publish(
+        (CREDITS_TRANSFERRED, from),
+        (to, waste_type, grams),
+    );
+}
+
+/// Emit event when recycling credits are retired
+pub fn emit_credits_retired(
+    env: &Env,
+    certificate_id: u64,
+    owner: &Address,
+    waste_type: WasteType,
+    grams: u64,
+) {
+    env.events().publish(
+        (CREDITS_RETIRED, certificate_id),
+        (owner, waste_type, grams),
+    );
+}
+
+/// Emit event when a manufacturer contributes to a pooled incentive
+pub fn emit_pool_contributed(
+    env: &Env,
+    incentive_id: u64,
+    funder: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (POOL_CONTRIBUTED, incentive_id),
+        (funder, amount),
+    );
+}
+
+/// Emit event when a closing pool returns a funder's unspent contribution
+pub fn emit_pool_refunded(
+    env: &Env,
+    incentive_id: u64,
+    funder: &Address,
+    amount: i128,
+) {
+    env.events().publish(
+        (POOL_REFUNDED, incentive_id),
+        (funder, amount),
+    );
+}
+
+/// Emit event when an incentive's per-participant limits are set
+pub fn emit_incentive_limits_set(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    limits: &IncentiveLimits,
+) {
+    env.events().publish(
+        (INCENTIVE_LIMITED, incentive_id),
+        (rewarder, limits.clone()),
+    );
+}
+
+/// Emit event when an incentive's expiry or region is set
+pub fn emit_incentive_scope_set(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    scope: &IncentiveScope,
+) {
+    env.events().publish(
+        (INCENTIVE_SCOPED, incentive_id),
+        (rewarder, scope.clone()),
+    );
+}
@@ -1,12 +1,23 @@
 #![no_std]
 
 mod contract;
+#[path = "../../../stellar-contract/src/pool.rs"]
+mod pool;
 mod storage;
 mod test;
 mod test_update_incentive;
 mod test_deactivate_waste;
 mod test_reset_waste_confirmation;
 mod test_incentive_events;
+mod test_recycling_credits;
+mod test_waste_catalogue;
+mod test_carbon_metrics;
+mod test_reward_claims;
+mod test_incentive_claims;
+mod test_incentive_pools;
+mod test_incentive_limits;
+mod test_best_incentives;
+mod test_vesting;
 mod testutils;
 mod test_data_structures;
 mod events;
@@ -1,6 +1,9 @@
 use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
-use crate::types::{Incentive, Material, Participant, ParticipantStats, WasteTransfer, WasteType};
+use crate::types::{
+    Incentive, IncentiveLimits, IncentiveScope, IncentiveUsage, Material, Participant, ParticipantStats, PoolContribution, PoolPayout,
+    RetirementCertificate, VestingPolicy, VestingSchedule, WasteCategory, WasteTransfer, WasteType,
+};
 
 // Storage keys
 const ADMIN: Symbol = symbol_short!("ADMIN");
@@ -10,7 +13,12 @@ const COLLECTOR_PCT: Symbol = symbol_short!("COL_PCT");
 const OWNER_PCT: Symbol = symbol_short!("OWN_PCT");
 const TOTAL_EARNED: Symbol = symbol_short!("EARNED");
 const TOTAL_WEIGHT: Symbol = symbol_short!("TOT_WGT");
+const TOTAL_CO2E: Symbol = symbol_short!("TOT_CO2");
 const INCENTIVE_COUNTER: Symbol = symbol_short!("INC_CNT");
+const CERTIFICATE_COUNTER: Symbol = symbol_short!("CERT_CNT");
+const CATEGORY_COUNTER: Symbol = symbol_short!("CAT_CNT");
+const VESTING_COUNTER: Symbol = symbol_short!("VEST_CNT");
+const VESTING_POLICY: Symbol = symbol_short!("VEST_POL");
 
 pub struct Storage;
 
@@ -92,6 +100,58 @@ impl Storage {
         env.storage().instance().set(&TOTAL_WEIGHT, &weight);
     }
 
+    // Carbon accounting functions
+    pub fn get_total_co2e(env: &Env) -> u64 {
+        env.storage().instance().get(&TOTAL_CO2E).unwrap_or(0)
+    }
+
+    pub fn add_to_total_co2e(env: &Env, co2e: u64) {
+        let current = Self::get_total_co2e(env);
+        Self::set_total_co2e(env, current + co2e);
+    }
+
+    pub fn set_total_co2e(env: &Env, co2e: u64) {
+        env.storage().instance().set(&TOTAL_CO2E, &co2e);
+    }
+
+    pub fn get_waste_co2e(env: &Env, waste_id: u64) -> Option<u64> {
+        let key = (symbol_short!("W_CO2E"), waste_id);
+        env.storage().instance().get(&key)
+    }
+
+    pub fn set_waste_co2e(env: &Env, waste_id: u64, co2e: u64) {
+        let key = (symbol_short!("W_CO2E"), waste_id);
+        env.storage().instance().set(&key, &co2e);
+    }
+
+    pub fn remove_waste_co2e(env: &Env, waste_id: u64) {
+        let key = (symbol_short!("W_CO2E"), waste_id);
+        env.storage().instance().remove(&key);
+    }
+
+    pub fn get_participant_co2e(env: &Env, address: &Address) -> u64 {
+        let key = (symbol_short!("P_CO2E"), address);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    pub fn set_participant_co2e(env: &Env, address: &Address, co2e: u64) {
+        let key = (symbol_short!("P_CO2E"), address);
+        env.storage().instance().set(&key, &co2e);
+    }
+
+    pub fn get_emission_factor(env: &Env, waste_type: WasteType) -> u64 {
+        let key = (symbol_short!("EMISSION"), waste_type);
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(waste_type.default_emission_factor())
+    }
+
+    pub fn set_emission_factor(env: &Env, waste_type: WasteType, co2e_per_kg: u64) {
+        let key = (symbol_short!("EMISSION"), waste_type);
+        env.storage().instance().set(&key, &co2e_per_kg);
+    }
+
     // Participant functions
     pub fn get_participant(env: &Env, address: &Address) -> Option<Participant> {
         let key = (symbol_short!("PART"), address);
@@ -158,6 +218,33 @@ impl Storage {
         env.storage().instance().get(&key).unwrap_or(Vec::new(env))
     }
 
+    // Waste catalogue functions
+    pub fn get_category_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&CATEGORY_COUNTER)
+            .unwrap_or(WasteType::BUILTIN_COUNT)
+    }
+
+    pub fn next_category_id(env: &Env) -> u32 {
+        let id = Self::get_category_count(env);
+        env.storage().instance().set(&CATEGORY_COUNTER, &(id + 1));
+        id
+    }
+
+    pub fn set_waste_category(env: &Env, category: &WasteCategory) {
+        let key = (symbol_short!("CATEGORY"), category.id);
+        env.storage().instance().set(&key, category);
+    }
+
+    pub fn get_waste_category(env: &Env, waste_type: WasteType) -> Option<WasteCategory> {
+        let key = (symbol_short!("CATEGORY"), waste_type.0);
+        env.storage()
+            .instance()
+            .get(&key)
+            .or_else(|| WasteCategory::builtin(env, waste_type))
+    }
+
     // Material storage functions
     pub fn next_material_id(env: &Env) -> u64 {
         let key = symbol_short!("MAT_CNT");
@@ -206,4 +293,236 @@ impl Storage {
         stats.total_earned += amount;
         Self::set_stats(env, address, &stats);
     }
+
+    // Incentive escrow functions
+    pub fn get_incentive_escrow(env: &Env, incentive_id: u64) -> i128 {
+        let key = (symbol_short!("ESCROW"), incentive_id);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    pub fn set_incentive_escrow(env: &Env, incentive_id: u64, amount: i128) {
+        let key = (symbol_short!("ESCROW"), incentive_id);
+        env.storage().instance().set(&key, &amount);
+    }
+
+    // Incentive limit functions
+    pub fn get_incentive_limits(env: &Env, incentive_id: u64) -> Option<IncentiveLimits> {
+        let key = (symbol_short!("INC_LIM"), incentive_id);
+        env.storage().instance().get(&key)
+    }
+
+    pub fn set_incentive_limits(env: &Env, incentive_id: u64, limits: &IncentiveLimits) {
+        let key = (symbol_short!("INC_LIM"), incentive_id);
+        env.storage().instance().set(&key, limits);
+    }
+
+    pub fn get_incentive_usage(env: &Env, incentive_id: u64, address: &Address) -> IncentiveUsage {
+        let key = (symbol_short!("INC_USE"), incentive_id, address.clone());
+        env.storage().instance().get(&key).unwrap_or_default()
+    }
+
+    pub fn set_incentive_usage(env: &Env, incentive_id: u64, address: &Address, usage: &IncentiveUsage) {
+        let key = (symbol_short!("INC_USE"), incentive_id, address.clone());
+        env.storage().instance().set(&key, usage);
+    }
+
+    pub fn get_incentive_scope(env: &Env, incentive_id: u64) -> IncentiveScope {
+        let key = (symbol_short!("INC_SCOPE"), incentive_id);
+        env.storage().instance().get(&key).unwrap_or_default()
+    }
+
+    pub fn set_incentive_scope(env: &Env, incentive_id: u64, scope: &IncentiveScope) {
+        let key = (symbol_short!("INC_SCOPE"), incentive_id);
+        env.storage().instance().set(&key, scope);
+    }
+
+    // Pooled incentive functions
+    pub fn get_pool_payout(env: &Env, incentive_id: u64) -> Option<PoolPayout> {
+        let key = (symbol_short!("POOL"), incentive_id);
+        env.storage().instance().get(&key)
+    }
+
+    pub fn set_pool_payout(env: &Env, incentive_id: u64, payout: PoolPayout) {
+        let key = (symbol_short!("POOL"), incentive_id);
+        env.storage().instance().set(&key, &payout);
+    }
+
+    pub fn get_pool_contributions(env: &Env, incentive_id: u64) -> Vec<PoolContribution> {
+        let key = (symbol_short!("POOL_FND"), incentive_id);
+        env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+    }
+
+    pub fn set_pool_contributions(env: &Env, incentive_id: u64, contributions: &Vec<PoolContribution>) {
+        let key = (symbol_short!("POOL_FND"), incentive_id);
+        env.storage().instance().set(&key, contributions);
+    }
+
+    pub fn is_incentive_closed(env: &Env, incentive_id: u64) -> bool {
+        let key = (symbol_short!("INC_SHUT"), incentive_id);
+        env.storage().instance().has(&key)
+    }
+
+    pub fn set_incentive_closed(env: &Env, incentive_id: u64) {
+        let key = (symbol_short!("INC_SHUT"), incentive_id);
+        env.storage().instance().set(&key, &true);
+    }
+
+    pub fn get_open_dispute(env: &Env, waste_id: u64) -> Option<Address> {
+        let key = (symbol_short!("DISP_OPEN"), waste_id);
+        env.storage().instance().get(&key)
+    }
+
+    pub fn set_open_dispute(env: &Env, waste_id: u64, disputer: &Address) {
+        let key = (symbol_short!("DISP_OPEN"), waste_id);
+        env.storage().instance().set(&key, disputer);
+    }
+
+    pub fn remove_open_dispute(env: &Env, waste_id: u64) {
+        let key = (symbol_short!("DISP_OPEN"), waste_id);
+        env.storage().instance().remove(&key);
+    }
+
+    pub fn is_dispute_succeeded(env: &Env, waste_id: u64) -> bool {
+        let key = (symbol_short!("DISP_OK"), waste_id);
+        env.storage().instance().has(&key)
+    }
+
+    pub fn set_dispute_succeeded(env: &Env, waste_id: u64) {
+        let key = (symbol_short!("DISP_OK"), waste_id);
+        env.storage().instance().set(&key, &true);
+    }
+
+    pub fn is_reward_paid(env: &Env, waste_id: u64) -> bool {
+        let key = (symbol_short!("PAID"), waste_id);
+        env.storage().instance().has(&key)
+    }
+
+    pub fn set_reward_paid(env: &Env, waste_id: u64) {
+        let key = (symbol_short!("PAID"), waste_id);
+        env.storage().instance().set(&key, &true);
+    }
+
+    pub fn remove_reward_paid(env: &Env, waste_id: u64) {
+        let key = (symbol_short!("PAID"), waste_id);
+        env.storage().instance().remove(&key);
+    }
+
+    // Claimable reward functions
+    pub fn get_claimable(env: &Env, address: &Address) -> i128 {
+        let key = (symbol_short!("CLAIM"), address);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    pub fn set_claimable(env: &Env, address: &Address, amount: i128) {
+        let key = (symbol_short!("CLAIM"), address);
+        env.storage().instance().set(&key, &amount);
+    }
+
+    pub fn add_claimable(env: &Env, address: &Address, amount: i128) {
+        let current = Self::get_claimable(env, address);
+        Self::set_claimable(env, address, current + amount);
+    }
+
+    // Recycling credit functions
+    pub fn is_material_credited(env: &Env, material_id: u64) -> bool {
+        let key = (symbol_short!("CRED_MAT"), material_id);
+        env.storage().instance().has(&key)
+    }
+
+    pub fn set_material_credited(env: &Env, material_id: u64) {
+        let key = (symbol_short!("CRED_MAT"), material_id);
+        env.storage().instance().set(&key, &true);
+    }
+
+    pub fn get_credit_balance(env: &Env, owner: &Address, waste_type: WasteType) -> u64 {
+        let key = (symbol_short!("CREDITS"), owner, waste_type);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    pub fn set_credit_balance(env: &Env, owner: &Address, waste_type: WasteType, grams: u64) {
+        let key = (symbol_short!("CREDITS"), owner, waste_type);
+        env.storage().instance().set(&key, &grams);
+    }
+
+    pub fn get_total_retired(env: &Env, waste_type: WasteType) -> u64 {
+        let key = (symbol_short!("RETIRED"), waste_type);
+        env.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    pub fn add_to_total_retired(env: &Env, waste_type: WasteType, grams: u64) {
+        let key = (symbol_short!("RETIRED"), waste_type);
+        let current = Self::get_total_retired(env, waste_type);
+        env.storage().instance().set(&key, &(current + grams));
+    }
+
+    pub fn next_certificate_id(env: &Env) -> u64 {
+        let current: u64 = env.storage().instance().get(&CERTIFICATE_COUNTER).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&CERTIFICATE_COUNTER, &next);
+        next
+    }
+
+    pub fn set_certificate(env: &Env, certificate: &RetirementCertificate) {
+        let key = (symbol_short!("CERT"), certificate.id);
+        env.storage().instance().set(&key, certificate);
+    }
+
+    pub fn get_certificate(env: &Env, certificate_id: u64) -> Option<RetirementCertificate> {
+        let key = (symbol_short!("CERT"), certificate_id);
+        env.storage().instance().get(&key)
+    }
+
+    // Vesting functions
+    pub fn get_vesting_policy(env: &Env) -> Option<VestingPolicy> {
+        env.storage().instance().get(&VESTING_POLICY)
+    }
+
+    pub fn set_vesting_policy(env: &Env, policy: &VestingPolicy) {
+        env.storage().instance().set(&VESTING_POLICY, policy);
+    }
+
+    pub fn remove_vesting_policy(env: &Env) {
+        env.storage().instance().remove(&VESTING_POLICY);
+    }
+
+    pub fn next_vesting_id(env: &Env) -> u64 {
+        let current: u64 = env.storage().instance().get(&VESTING_COUNTER).unwrap_or(0);
+        let next = current + 1;
+        env.storage().instance().set(&VESTING_COUNTER, &next);
+        next
+    }
+
+    pub fn get_vesting_schedule(env: &Env, schedule_id: u64) -> Option<VestingSchedule> {
+        let key = (symbol_short!("VEST"), schedule_id);
+        env.storage().instance().get(&key)
+    }
+
+    pub fn set_vesting_schedule(env: &Env, schedule: &VestingSchedule) {
+        let key = (symbol_short!("VEST"), schedule.id);
+        env.storage().instance().set(&key, schedule);
+    }
+
+    pub fn get_participant_vesting(env: &Env, address: &Address) -> Vec<u64> {
+        let key = (symbol_short!("VEST_P"), address);
+        env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+    }
+
+    pub fn get_waste_vesting(env: &Env, waste_id: u64) -> Vec<u64> {
+        let key = (symbol_short!("VEST_W"), waste_id);
+        env.storage().instance().get(&key).unwrap_or(Vec::new(env))
+    }
+
+    pub fn add_vesting_schedule(env: &Env, schedule: &VestingSchedule) {
+        Self::set_vesting_schedule(env, schedule);
+
+        let key = (symbol_short!("VEST_P"), &schedule.beneficiary);
+        let mut ids = Self::get_participant_vesting(env, &schedule.beneficiary);
+        ids.push_back(schedule.id);
+        env.storage().instance().set(&key, &ids);
+
+        let key = (symbol_short!("VEST_W"), schedule.waste_id);
+        let mut ids = Self::get_waste_vesting(env, schedule.waste_id);
+        ids.push_back(schedule.id);
+        env.storage().instance().set(&key, &ids);
+    }
 }
@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::contract::ScavengerContract;
+use crate::types::{Incentive, IncentiveLimits, IncentiveScope, Role, WasteType};
+
+// Central Lagos, scaled by 1e6
+const LAT: i64 = 6_524_000;
+const LON: i64 = 3_379_000;
+
+struct Setup<'a> {
+    client: crate::contract::ScavengerContractClient<'a>,
+    minter: token::StellarAssetClient<'a>,
+    acme: Address,
+    globex: Address,
+    recycler: Address,
+}
+
+impl Setup<'_> {
+    /// Create an incentive and escrow its whole budget
+    fn funded(&self, rewarder: &Address, waste_type: WasteType, reward_points: u64, budget: u64) -> Incentive {
+        let incentive = self.client.create_incentive(rewarder, &waste_type, &reward_points, &0);
+        self.minter.mint(rewarder, &(budget as i128));
+        self.client.top_up_incentive(rewarder, &incentive.id, &(budget as i128))
+    }
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register(ScavengerContract, ());
+    let client = crate::contract::ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env.register_stellar_asset_contract(admin.clone());
+    let charity_address = Address::generate(env);
+    client.initialize(&admin, &token_address, &charity_address, &5, &50);
+
+    let acme = Address::generate(env);
+    let globex = Address::generate(env);
+    let recycler = Address::generate(env);
+    client.register_participant(&acme, &Role::Manufacturer, &String::from_str(env, "Acme"), &0, &0);
+    client.register_participant(&globex, &Role::Manufacturer, &String::from_str(env, "Globex"), &0, &0);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(env, "Recycler"), &LAT, &LON);
+
+    Setup {
+        client,
+        minter: token::StellarAssetClient::new(env, &token_address),
+        acme,
+        globex,
+        recycler,
+    }
+}
+
+fn no_limits() -> IncentiveLimits {
+    IncentiveLimits {
+        max_weight_per_participant: 0,
+        max_reward_per_participant: 0,
+        period: 0,
+        max_reward_per_period: 0,
+        min_account_age: 0,
+    }
+}
+
+#[test]
+fn test_ranked_across_manufacturers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let low = s.funded(&s.acme, WasteType::Paper, 50, 100_000);
+    let high = s.funded(&s.globex, WasteType::Paper, 80, 100_000);
+    let mid = s.funded(&s.acme, WasteType::Paper, 60, 100_000);
+    s.funded(&s.globex, WasteType::Metal, 500, 100_000);
+
+    let quotes = s.client.get_best_incentives(&WasteType::Paper, &10_000, &LAT, &LON, &None);
+    assert_eq!(quotes.len(), 3);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, high.id);
+    assert_eq!(quotes.get(0).unwrap().rewarder, s.globex);
+    assert_eq!(quotes.get(0).unwrap().payout, 800);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, mid.id);
+    assert_eq!(quotes.get(2).unwrap().incentive_id, low.id);
+}
+
+#[test]
+fn test_caps_change_the_ranking() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let capped = s.funded(&s.acme, WasteType::Paper, 100, 100_000);
+    let flat = s.funded(&s.globex, WasteType::Paper, 60, 100_000);
+    s.client.set_incentive_limits(
+        &s.acme,
+        &capped.id,
+        &IncentiveLimits { max_reward_per_participant: 500, ..no_limits() },
+    );
+
+    // 100/kg capped at 500 loses to an uncapped 60/kg on 10kg
+    let quotes = s.client.get_best_incentives(&WasteType::Paper, &10_000, &LAT, &LON, &Some(s.recycler.clone()));
+    assert_eq!(quotes.get(0).unwrap().incentive_id, flat.id);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, capped.id);
+    assert_eq!(quotes.get(1).unwrap().payout, 500);
+}
+
+#[test]
+fn test_budget_escrow_expiry_and_region_filter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let open = s.funded(&s.acme, WasteType::Paper, 10, 100_000);
+    // Too little budget left for a 10kg delivery
+    s.funded(&s.acme, WasteType::Paper, 100, 500);
+    // Budget set but nothing escrowed to pay it
+    s.client.create_incentive(&s.globex, &WasteType::Paper, &95, &100_000);
+    let expiring = s.funded(&s.globex, WasteType::Paper, 90, 100_000);
+    s.client.set_incentive_scope(
+        &s.globex,
+        &expiring.id,
+        &IncentiveScope { expires_at: 1_000, ..Default::default() },
+    );
+    let elsewhere = s.funded(&s.globex, WasteType::Paper, 80, 100_000);
+    s.client.set_incentive_scope(
+        &s.globex,
+        &elsewhere.id,
+        &IncentiveScope {
+            expires_at: 0,
+            min_latitude: 50_000_000,
+            max_latitude: 55_000_000,
+            min_longitude: -5_000_000,
+            max_longitude: 2_000_000,
+        },
+    );
+
+    let quotes = s.client.get_best_incentives(&WasteType::Paper, &10_000, &LAT, &LON, &None);
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, expiring.id);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, open.id);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let quotes = s.client.get_best_incentives(&WasteType::Paper, &10_000, &LAT, &LON, &None);
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, open.id);
+
+    let quotes = s.client.get_best_incentives(&WasteType::Paper, &10_000, &52_000_000, &0, &None);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, elsewhere.id);
+}
+
+#[test]
+fn test_best_incentives_for_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.funded(&s.acme, WasteType::Paper, 100, 100_000);
+    s.funded(&s.globex, WasteType::Metal, 500, 100_000);
+
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &2_500);
+    let quotes = s.client.get_best_incentives_for_waste(&material.id);
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, incentive.id);
+    assert_eq!(quotes.get(0).unwrap().payout, 200);
+}
+
+#[test]
+#[should_panic(expected = "Incentive expired")]
+fn test_expired_incentive_cannot_be_claimed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.funded(&s.acme, WasteType::Paper, 100, 100_000);
+    s.client.set_incentive_scope(&s.acme, &incentive.id, &IncentiveScope { expires_at: 1_000, ..Default::default() });
+
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &10_000);
+    s.client.confirm_waste(&material.id, &s.acme);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.acme);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    s.client.distribute_rewards(&material.id, &incentive.id, &s.acme);
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::contract::ScavengerContract;
+use crate::types::{Role, WasteType};
+
+fn create_test_contract(env: &Env) -> (crate::contract::ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register(ScavengerContract, ());
+    let client = crate::contract::ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env.register_stellar_asset_contract(admin.clone());
+    let charity_address = Address::generate(env);
+
+    client.initialize(&admin, &token_address, &charity_address, &5, &50);
+
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(env, "Recycler"), &0, &0);
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_metrics_include_co2e_avoided() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = create_test_contract(&env);
+
+    // 2.5kg of metal at 4kg CO2e/kg and 1kg of glass at 0.3kg CO2e/kg
+    let metal = client.submit_material(&recycler, &WasteType::Metal, &2_500);
+    let glass = client.submit_material(&recycler, &WasteType::Glass, &1_000);
+    assert_eq!(client.get_metrics().total_co2e_avoided, 0);
+
+    client.confirm_waste(&metal.id, &recycler);
+    client.confirm_waste(&glass.id, &recycler);
+
+    let metrics = client.get_metrics();
+    assert_eq!(metrics.total_wastes_count, 2);
+    assert_eq!(metrics.total_weight, 3_500);
+    assert_eq!(metrics.total_co2e_avoided, 10_300);
+}
+
+#[test]
+fn test_set_emission_factor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler) = create_test_contract(&env);
+
+    assert_eq!(client.get_emission_factor(&WasteType::Paper), 900);
+    let first = client.submit_material(&recycler, &WasteType::Paper, &1_000);
+    client.confirm_waste(&first.id, &recycler);
+
+    client.set_emission_factor(&admin, &WasteType::Paper, &1_200);
+    assert_eq!(client.get_emission_factor(&WasteType::Paper), 1_200);
+    let second = client.submit_material(&recycler, &WasteType::Paper, &1_000);
+    client.confirm_waste(&second.id, &recycler);
+
+    assert_eq!(client.get_metrics().total_co2e_avoided, 2_100);
+}
+
+#[test]
+fn test_waste_and_participant_co2e() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = create_test_contract(&env);
+
+    let collector = Address::generate(&env);
+    client.register_participant(&collector, &Role::Collector, &String::from_str(&env, "Collector"), &0, &0);
+
+    let metal = client.submit_material(&recycler, &WasteType::Metal, &2_500);
+    let paper = client.submit_material(&collector, &WasteType::Paper, &2_000);
+    assert_eq!(client.get_waste_co2e(&metal.id), 0);
+
+    client.confirm_waste(&metal.id, &collector);
+    client.confirm_waste(&paper.id, &recycler);
+
+    assert_eq!(client.get_waste_co2e(&metal.id), 10_000);
+    assert_eq!(client.get_waste_co2e(&paper.id), 1_800);
+    assert_eq!(client.get_participant_co2e(&recycler), 10_000);
+    assert_eq!(client.get_participant_co2e(&collector), 1_800);
+}
+
+#[test]
+fn test_reset_confirmation_withdraws_co2e() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = create_test_contract(&env);
+
+    let metal = client.submit_material(&recycler, &WasteType::Metal, &2_500);
+    client.confirm_waste(&metal.id, &recycler);
+    // Confirming again does not count the waste twice
+    client.confirm_waste(&metal.id, &recycler);
+    assert_eq!(client.get_metrics().total_co2e_avoided, 10_000);
+
+    client.reset_waste_confirmation(&metal.id, &recycler);
+    assert_eq!(client.get_waste_co2e(&metal.id), 0);
+    assert_eq!(client.get_participant_co2e(&recycler), 0);
+    assert_eq!(client.get_metrics().total_co2e_avoided, 0);
+
+    client.confirm_waste(&metal.id, &recycler);
+    assert_eq!(client.get_metrics().total_co2e_avoided, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Only admin can perform this action")]
+fn test_set_emission_factor_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = create_test_contract(&env);
+
+    client.set_emission_factor(&recycler, &WasteType::Paper, &1_200);
+}
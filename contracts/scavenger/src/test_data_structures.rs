@@ -249,6 +249,8 @@ fn test_global_metrics_serialization() {
     let metrics = crate::types::GlobalMetrics {
         total_wastes_count: 5000,
         total_tokens_earned: 1000000000,
+        total_weight: 250000,
+        total_co2e_avoided: 225000,
     };
 
     env.as_contract(&contract_id, || {
@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::testutils::RewardFixture;
+use crate::types::{Incentive, Material, WasteType};
+
+fn setup(env: &Env) -> RewardFixture<'_> {
+    RewardFixture::new(env, 5, 50)
+}
+
+/// A funded 100 points/kg paper incentive and a confirmed 10kg delivery
+fn delivered(s: &RewardFixture) -> (Incentive, Material) {
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &0);
+    s.client.top_up_incentive(&s.manufacturer, &incentive.id, &5_000);
+
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.manufacturer);
+
+    (incentive, material)
+}
+
+#[test]
+fn test_top_up_escrows_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &0);
+    s.client.top_up_incentive(&s.manufacturer, &incentive.id, &5_000);
+    let incentive = s.client.top_up_incentive(&s.manufacturer, &incentive.id, &1_000);
+
+    assert_eq!(incentive.remaining_budget, 6_000);
+    assert_eq!(s.client.get_incentive_escrow(&incentive.id), 6_000);
+    assert_eq!(s.token.balance(&s.client.address), 6_000);
+}
+
+#[test]
+fn test_submitter_claims_incentive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let (incentive, material) = delivered(&s);
+
+    // 10kg * 100 = 1000; owner share 500, remainder to the manufacturer holding it
+    assert_eq!(s.client.claim_incentive(&material.id, &incentive.id, &s.recycler), 1_000);
+    assert!(s.client.is_reward_paid(&material.id));
+    assert_eq!(s.client.get_incentive_escrow(&incentive.id), 4_000);
+    assert_eq!(s.client.get_claimable(&s.recycler), 500);
+    assert_eq!(s.client.get_incentive_by_id(&incentive.id).unwrap().remaining_budget, 4_000);
+
+    s.client.claim_rewards(&s.recycler, &500);
+    assert_eq!(s.token.balance(&s.recycler), 500);
+}
+
+#[test]
+#[should_panic(expected = "Reward already paid")]
+fn test_claim_after_distribution_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let (incentive, material) = delivered(&s);
+
+    s.client.distribute_rewards(&material.id, &incentive.id, &s.manufacturer);
+    s.client.claim_incentive(&material.id, &incentive.id, &s.recycler);
+}
+
+#[test]
+#[should_panic(expected = "Waste not delivered to incentive manufacturer")]
+fn test_claim_before_delivery_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &0);
+    s.client.top_up_incentive(&s.manufacturer, &incentive.id, &5_000);
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+
+    s.client.claim_incentive(&material.id, &incentive.id, &s.recycler);
+}
+
+#[test]
+#[should_panic(expected = "Only the owner or submitter can claim")]
+fn test_claim_by_stranger_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let (incentive, material) = delivered(&s);
+
+    s.client.claim_incentive(&material.id, &incentive.id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient escrowed funds")]
+fn test_claim_unfunded_incentive_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &100_000);
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.manufacturer);
+
+    s.client.claim_incentive(&material.id, &incentive.id, &s.recycler);
+}
+
+#[test]
+fn test_close_refunds_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let (incentive, _material) = delivered(&s);
+
+    assert_eq!(s.client.close_incentive(&s.manufacturer, &incentive.id), 5_000);
+    assert_eq!(s.token.balance(&s.manufacturer), 1_000_000);
+    assert_eq!(s.client.get_incentive_escrow(&incentive.id), 0);
+}
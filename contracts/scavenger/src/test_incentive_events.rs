@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use soroban_sdk::Env;
+
+use crate::testutils::RewardFixture;
+use crate::types::{Incentive, PoolPayout, WasteType};
+
+fn setup(env: &Env) -> RewardFixture<'_> {
+    RewardFixture::new(env, 5, 50)
+}
+
+/// Spend an incentive's whole 1000-point budget on a confirmed 10kg delivery
+fn exhaust(s: &RewardFixture) -> Incentive {
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &0);
+    s.client.top_up_incentive(&s.manufacturer, &incentive.id, &1_000);
+
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.manufacturer);
+    s.client.claim_incentive(&material.id, &incentive.id, &s.recycler);
+
+    s.client.get_incentive_by_id(&incentive.id).unwrap()
+}
+
+#[test]
+fn test_budget_exhaustion_deactivates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = exhaust(&s);
+    assert!(!incentive.active);
+    assert_eq!(incentive.remaining_budget, 0);
+    assert_eq!(s.client.get_incentive_escrow(&incentive.id), 0);
+}
+
+#[test]
+fn test_top_up_reactivates_exhausted_incentive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = exhaust(&s);
+    let incentive = s.client.top_up_incentive(&s.manufacturer, &incentive.id, &2_000);
+
+    assert!(incentive.active);
+    assert_eq!(incentive.total_budget, 3_000);
+    assert_eq!(incentive.remaining_budget, 2_000);
+    assert_eq!(s.client.get_incentive_escrow(&incentive.id), 2_000);
+    assert_eq!(s.token.balance(&s.client.address), 2_000 + 1_000);
+}
+
+#[test]
+fn test_top_up_keeps_deactivated_incentive_off() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &1_000);
+    s.client.deactivate_incentive(&s.manufacturer, &incentive.id);
+
+    let incentive = s.client.top_up_incentive(&s.manufacturer, &incentive.id, &500);
+    assert!(!incentive.active);
+    assert_eq!(incentive.remaining_budget, 1_500);
+}
+
+#[test]
+fn test_close_incentive_refunds_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &5_000);
+    s.client.top_up_incentive(&s.manufacturer, &incentive.id, &5_000);
+
+    assert_eq!(s.client.close_incentive(&s.manufacturer, &incentive.id), 5_000);
+    assert!(s.client.is_incentive_closed(&incentive.id));
+    assert_eq!(s.token.balance(&s.manufacturer), 1_000_000);
+
+    let incentive = s.client.get_incentive_by_id(&incentive.id).unwrap();
+    assert!(!incentive.active);
+    assert_eq!(incentive.remaining_budget, 0);
+}
+
+#[test]
+fn test_closing_pool_marks_it_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.manufacturer, &WasteType::Paper, &100, &PoolPayout::ProRata);
+    s.client.contribute_to_pool(&s.manufacturer, &pool.id, &1_000, &0);
+    s.client.close_incentive_pool(&s.manufacturer, &pool.id);
+
+    assert!(s.client.is_incentive_closed(&pool.id));
+}
+
+#[test]
+#[should_panic(expected = "Incentive is closed")]
+fn test_closed_incentive_cannot_be_topped_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &1_000);
+    s.client.close_incentive(&s.manufacturer, &incentive.id);
+    s.client.top_up_incentive(&s.manufacturer, &incentive.id, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Only rewarder can top up")]
+fn test_only_rewarder_tops_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Paper, &100, &1_000);
+    s.client.top_up_incentive(&s.recycler, &incentive.id, &1_000);
+}
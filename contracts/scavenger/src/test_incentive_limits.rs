@@ -0,0 +1,180 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Ledger, Address, Env};
+
+use crate::testutils::RewardFixture;
+use crate::types::{IncentiveLimits, WasteType};
+
+struct Setup<'a> {
+    client: crate::contract::ScavengerContractClient<'a>,
+    manufacturer: Address,
+    recycler: Address,
+    incentive_id: u64,
+}
+
+impl Setup<'_> {
+    /// Submit, confirm and deliver `weight` grams of paper to the manufacturer
+    fn deliver(&self, weight: u64) -> u64 {
+        let material = self.client.submit_material(&self.recycler, &WasteType::Paper, &weight);
+        self.client.confirm_waste(&material.id, &self.manufacturer);
+        self.client.transfer_waste(&material.id, &self.recycler, &self.manufacturer);
+        material.id
+    }
+
+    fn claim(&self, waste_id: u64) -> i128 {
+        self.client.claim_incentive(&waste_id, &self.incentive_id, &self.recycler)
+    }
+
+    fn limit(&self, limits: &IncentiveLimits) {
+        self.client.set_incentive_limits(&self.manufacturer, &self.incentive_id, limits);
+    }
+}
+
+/// A funded 100 points/kg paper incentive with no limits set
+fn setup(env: &Env) -> Setup<'_> {
+    let RewardFixture { client, manufacturer, recycler, .. } = RewardFixture::new(env, 5, 50);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &0);
+    client.top_up_incentive(&manufacturer, &incentive.id, &100_000);
+
+    Setup {
+        client,
+        manufacturer,
+        recycler,
+        incentive_id: incentive.id,
+    }
+}
+
+fn limits() -> IncentiveLimits {
+    IncentiveLimits {
+        max_weight_per_participant: 0,
+        max_reward_per_participant: 0,
+        period: 0,
+        max_reward_per_period: 0,
+        min_account_age: 0,
+    }
+}
+
+#[test]
+fn test_weight_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.limit(&IncentiveLimits { max_weight_per_participant: 15_000, ..limits() });
+
+    assert_eq!(s.claim(s.deliver(10_000)), 1_000);
+    // Only 5kg of the second delivery is still eligible
+    let waste_id = s.deliver(10_000);
+    assert_eq!(s.client.calculate_incentive_reward(&waste_id, &s.incentive_id), 500);
+    assert_eq!(s.claim(waste_id), 500);
+
+    let usage = s.client.get_incentive_usage(&s.incentive_id, &s.recycler);
+    assert_eq!(usage.weight, 15_000);
+    assert_eq!(usage.reward, 1_500);
+    assert_eq!(s.client.get_incentive_by_id(&s.incentive_id).unwrap().remaining_budget, 98_500);
+}
+
+#[test]
+fn test_reward_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.limit(&IncentiveLimits { max_reward_per_participant: 1_200, ..limits() });
+
+    assert_eq!(s.claim(s.deliver(10_000)), 1_000);
+    assert_eq!(s.claim(s.deliver(10_000)), 200);
+    assert_eq!(s.client.calculate_incentive_reward(&s.deliver(10_000), &s.incentive_id), 0);
+}
+
+#[test]
+fn test_daily_cap_resets_each_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.limit(&IncentiveLimits {
+        period: IncentiveLimits::DAILY,
+        max_reward_per_period: 1_000,
+        ..limits()
+    });
+
+    assert_eq!(s.claim(s.deliver(10_000)), 1_000);
+    let waste_id = s.deliver(10_000);
+    assert_eq!(s.client.calculate_incentive_reward(&waste_id, &s.incentive_id), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = IncentiveLimits::DAILY);
+    assert_eq!(s.client.get_incentive_usage(&s.incentive_id, &s.recycler).period_reward, 0);
+    assert_eq!(s.claim(waste_id), 1_000);
+
+    let usage = s.client.get_incentive_usage(&s.incentive_id, &s.recycler);
+    assert_eq!(usage.reward, 2_000);
+    assert_eq!(usage.period_start, IncentiveLimits::DAILY);
+    assert_eq!(usage.period_reward, 1_000);
+}
+
+#[test]
+fn test_minimum_account_age() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.limit(&IncentiveLimits { min_account_age: IncentiveLimits::WEEKLY, ..limits() });
+
+    let waste_id = s.deliver(10_000);
+    assert_eq!(s.client.calculate_incentive_reward(&waste_id, &s.incentive_id), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = IncentiveLimits::WEEKLY);
+    assert_eq!(s.claim(waste_id), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Account too new for incentive")]
+fn test_new_account_cannot_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.limit(&IncentiveLimits { min_account_age: IncentiveLimits::WEEKLY, ..limits() });
+
+    s.claim(s.deliver(10_000));
+}
+
+#[test]
+#[should_panic(expected = "Incentive limit reached")]
+fn test_claim_beyond_cap_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.limit(&IncentiveLimits { max_weight_per_participant: 10_000, ..limits() });
+
+    s.claim(s.deliver(10_000));
+    s.claim(s.deliver(10_000));
+}
+
+#[test]
+fn test_zero_reward_settlement_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    // Under a kilogram earns nothing, so it must not use up the waste or caps
+    let waste_id = s.deliver(900);
+    assert!(s.client.try_claim_incentive(&waste_id, &s.incentive_id, &s.recycler).is_err());
+    assert!(!s.client.is_reward_paid(&waste_id));
+    assert_eq!(s.client.get_incentive_usage(&s.incentive_id, &s.recycler).weight, 0);
+
+    // A capped-out participant is turned away the same way
+    s.limit(&IncentiveLimits { max_reward_per_participant: 1_000, ..limits() });
+    s.claim(s.deliver(10_000));
+    let waste_id = s.deliver(5_000);
+    assert!(s.client.try_claim_incentive(&waste_id, &s.incentive_id, &s.recycler).is_err());
+    assert!(!s.client.is_reward_paid(&waste_id));
+    assert_eq!(s.client.get_incentive_usage(&s.incentive_id, &s.recycler).weight, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Only rewarder can set limits")]
+fn test_only_rewarder_sets_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client.set_incentive_limits(&s.recycler, &s.incentive_id, &limits());
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::contract::ScavengerContract;
+use crate::types::{Incentive, PoolPayout, Role, WasteType};
+
+struct Setup<'a> {
+    client: crate::contract::ScavengerContractClient<'a>,
+    token: token::Client<'a>,
+    lead: Address,
+    partner: Address,
+    recycler: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    let contract_id = env.register(ScavengerContract, ());
+    let client = crate::contract::ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env.register_stellar_asset_contract(admin.clone());
+    let charity_address = Address::generate(env);
+    client.initialize(&admin, &token_address, &charity_address, &5, &50);
+
+    let lead = Address::generate(env);
+    let partner = Address::generate(env);
+    let recycler = Address::generate(env);
+    client.register_participant(&lead, &Role::Manufacturer, &String::from_str(env, "Lead"), &0, &0);
+    client.register_participant(&partner, &Role::Manufacturer, &String::from_str(env, "Partner"), &0, &0);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(env, "Recycler"), &0, &0);
+
+    let minter = token::StellarAssetClient::new(env, &token_address);
+    minter.mint(&lead, &10_000);
+    minter.mint(&partner, &10_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_address),
+        lead,
+        partner,
+        recycler,
+    }
+}
+
+/// Deliver a confirmed 10kg of paper to the partner and claim the
+/// 100 points/kg pool reward for it
+fn claim_delivery(s: &Setup, pool: &Incentive) -> i128 {
+    let material = s.client.submit_material(&s.recycler, &WasteType::Paper, &10_000);
+    s.client.confirm_waste(&material.id, &s.lead);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.partner);
+    s.client.claim_incentive(&material.id, &pool.id, &s.recycler)
+}
+
+#[test]
+fn test_contributions_build_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::ProRata);
+    assert_eq!(pool.total_budget, 0);
+    assert_eq!(s.client.get_pool_payout(&pool.id), Some(PoolPayout::ProRata));
+
+    s.client.contribute_to_pool(&s.lead, &pool.id, &3_000, &0);
+    let contribution = s.client.contribute_to_pool(&s.partner, &pool.id, &1_000, &0);
+    assert_eq!(contribution.contributed, 1_000);
+    assert_eq!(contribution.remaining, 1_000);
+
+    let pool = s.client.get_incentive_by_id(&pool.id).unwrap();
+    assert_eq!(pool.total_budget, 4_000);
+    assert_eq!(pool.remaining_budget, 4_000);
+    assert_eq!(s.client.get_incentive_escrow(&pool.id), 4_000);
+    assert_eq!(s.token.balance(&s.client.address), 4_000);
+    assert_eq!(s.client.get_pool_contributions(&pool.id).len(), 2);
+}
+
+#[test]
+fn test_pro_rata_payout_and_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::ProRata);
+    s.client.contribute_to_pool(&s.lead, &pool.id, &3_000, &0);
+    s.client.contribute_to_pool(&s.partner, &pool.id, &1_000, &0);
+
+    // Delivered to a funder other than the pool creator
+    assert_eq!(claim_delivery(&s, &pool), 1_000);
+
+    let contributions = s.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 2_250);
+    assert_eq!(contributions.get(1).unwrap().remaining, 750);
+    assert_eq!(s.client.get_incentive_escrow(&pool.id), 3_000);
+
+    s.client.close_incentive_pool(&s.lead, &pool.id);
+    assert_eq!(s.token.balance(&s.lead), 9_250);
+    assert_eq!(s.token.balance(&s.partner), 9_750);
+    assert_eq!(s.client.get_incentive_escrow(&pool.id), 0);
+    assert!(!s.client.get_incentive_by_id(&pool.id).unwrap().active);
+}
+
+#[test]
+fn test_configured_shares_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::Shares);
+    s.client.contribute_to_pool(&s.lead, &pool.id, &2_000, &2_500);
+    s.client.contribute_to_pool(&s.partner, &pool.id, &2_000, &7_500);
+
+    claim_delivery(&s, &pool);
+
+    let contributions = s.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 1_750);
+    assert_eq!(contributions.get(1).unwrap().remaining, 1_250);
+}
+
+#[test]
+fn test_exhausted_share_drawn_from_others() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::Shares);
+    s.client.contribute_to_pool(&s.lead, &pool.id, &100, &9_000);
+    s.client.contribute_to_pool(&s.partner, &pool.id, &5_000, &1_000);
+
+    claim_delivery(&s, &pool);
+
+    let contributions = s.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 0);
+    assert_eq!(contributions.get(1).unwrap().remaining, 4_100);
+}
+
+#[test]
+#[should_panic(expected = "Shares exceed 100%")]
+fn test_shares_cannot_exceed_whole() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::Shares);
+    s.client.contribute_to_pool(&s.lead, &pool.id, &1_000, &6_000);
+    s.client.contribute_to_pool(&s.partner, &pool.id, &1_000, &5_000);
+}
+
+#[test]
+#[should_panic(expected = "Only manufacturers can fund pools")]
+fn test_only_manufacturers_contribute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::ProRata);
+    s.client.contribute_to_pool(&s.recycler, &pool.id, &1_000, &0);
+}
+
+#[test]
+#[should_panic(expected = "Contribute to pooled incentives instead")]
+fn test_pool_cannot_be_topped_up_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    let pool = s.client.create_incentive_pool(&s.lead, &WasteType::Paper, &100, &PoolPayout::ProRata);
+    s.client.top_up_incentive(&s.lead, &pool.id, &1_000);
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::contract::ScavengerContract;
+use crate::types::{Role, WasteType};
+
+fn create_test_contract(env: &Env) -> (crate::contract::ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register(ScavengerContract, ());
+    let client = crate::contract::ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env.register_stellar_asset_contract(admin.clone());
+    let charity_address = Address::generate(env);
+
+    client.initialize(&admin, &token_address, &charity_address, &5, &50);
+
+    (client, admin, token_address, charity_address)
+}
+
+fn confirmed_material(
+    env: &Env,
+    client: &crate::contract::ScavengerContractClient<'_>,
+    waste_type: WasteType,
+    weight: u64,
+) -> (Address, u64) {
+    let recycler = Address::generate(env);
+    let confirmer = Address::generate(env);
+
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(env, "Recycler"), &0, &0);
+    client.register_participant(&confirmer, &Role::Collector, &String::from_str(env, "Collector"), &0, &0);
+
+    let material = client.submit_material(&recycler, &waste_type, &weight);
+    client.confirm_waste(&material.id, &confirmer);
+
+    (recycler, material.id)
+}
+
+#[test]
+fn test_mint_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _charity) = create_test_contract(&env);
+
+    let (recycler, material_id) = confirmed_material(&env, &client, WasteType::PetPlastic, 2500);
+
+    let minted = client.mint_credits(&material_id, &recycler);
+
+    assert_eq!(minted, 2500);
+    assert!(client.is_material_credited(&material_id));
+    assert_eq!(client.get_credit_balance(&recycler, &WasteType::PetPlastic), 2500);
+}
+
+#[test]
+#[should_panic(expected = "Material must be confirmed")]
+fn test_mint_credits_unconfirmed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _charity) = create_test_contract(&env);
+
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &0, &0);
+    let material = client.submit_material(&recycler, &WasteType::Glass, &1000);
+
+    client.mint_credits(&material.id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Credits already minted for material")]
+fn test_mint_credits_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _charity) = create_test_contract(&env);
+
+    let (recycler, material_id) = confirmed_material(&env, &client, WasteType::Glass, 1000);
+
+    client.mint_credits(&material_id, &recycler);
+    client.mint_credits(&material_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Only current owner can mint credits")]
+fn test_mint_credits_non_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _charity) = create_test_contract(&env);
+
+    let (_recycler, material_id) = confirmed_material(&env, &client, WasteType::Glass, 1000);
+
+    client.mint_credits(&material_id, &Address::generate(&env));
+}
+
+#[test]
+fn test_transfer_and_retire_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _charity) = create_test_contract(&env);
+
+    let (recycler, material_id) = confirmed_material(&env, &client, WasteType::Metal, 5000);
+    let buyer = Address::generate(&env);
+
+    client.mint_credits(&material_id, &recycler);
+    client.transfer_credits(&recycler, &buyer, &WasteType::Metal, &3000);
+
+    assert_eq!(client.get_credit_balance(&recycler, &WasteType::Metal), 2000);
+    assert_eq!(client.get_credit_balance(&buyer, &WasteType::Metal), 3000);
+
+    let certificate = client.retire_credits(&buyer, &WasteType::Metal, &3000);
+
+    assert_eq!(certificate.id, 1);
+    assert_eq!(certificate.owner, buyer);
+    assert_eq!(certificate.grams, 3000);
+    assert_eq!(client.get_retirement_certificate(&1), Some(certificate));
+    assert_eq!(client.get_credit_balance(&buyer, &WasteType::Metal), 0);
+    assert_eq!(client.get_total_retired(&WasteType::Metal), 3000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient credit balance")]
+fn test_retired_credits_cannot_be_reused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _token, _charity) = create_test_contract(&env);
+
+    let (recycler, material_id) = confirmed_material(&env, &client, WasteType::Paper, 1000);
+
+    client.mint_credits(&material_id, &recycler);
+    client.retire_credits(&recycler, &WasteType::Paper, &1000);
+    client.retire_credits(&recycler, &WasteType::Paper, &1000);
+}
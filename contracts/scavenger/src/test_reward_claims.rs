@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use soroban_sdk::Env;
+
+use crate::testutils::RewardFixture;
+use crate::types::WasteType;
+
+fn setup(env: &Env) -> RewardFixture<'_> {
+    RewardFixture::new(env, 10, 40)
+}
+
+/// Distribute a 10kg metal reward of 2000 tokens through one collector
+fn distribute(s: &RewardFixture) -> i128 {
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Metal, &200, &200_000);
+    let material = s.client.submit_material(&s.recycler, &WasteType::Metal, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.collector);
+
+    s.client.distribute_rewards(&material.id, &incentive.id, &s.manufacturer)
+}
+
+#[test]
+fn test_distribution_accrues_claimable_balances() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    assert_eq!(distribute(&s), 2_000);
+
+    // Collector share (200) plus the remainder (1000); owner share 800
+    assert_eq!(s.client.get_claimable(&s.collector), 1_200);
+    assert_eq!(s.client.get_claimable(&s.recycler), 800);
+
+    // Funds sit in the contract until claimed
+    assert_eq!(s.token.balance(&s.client.address), 2_000);
+    assert_eq!(s.token.balance(&s.manufacturer), 998_000);
+    assert_eq!(s.token.balance(&s.collector), 0);
+}
+
+#[test]
+fn test_claim_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    distribute(&s);
+
+    assert_eq!(s.client.claim_rewards(&s.collector, &200), 1_000);
+    assert_eq!(s.token.balance(&s.collector), 200);
+
+    assert_eq!(s.client.claim_rewards(&s.collector, &1_000), 0);
+    assert_eq!(s.token.balance(&s.collector), 1_200);
+    assert_eq!(s.client.get_claimable(&s.collector), 0);
+    assert_eq!(s.token.balance(&s.client.address), 800);
+
+    // Earnings statistics are unaffected by claiming
+    assert_eq!(s.client.get_participant_stats(&s.collector).total_earned, 1_200);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient claimable balance")]
+fn test_claim_more_than_accrued() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    distribute(&s);
+
+    s.client.claim_rewards(&s.recycler, &801);
+}
+
+#[test]
+#[should_panic(expected = "Claim amount must be positive")]
+fn test_claim_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    distribute(&s);
+
+    s.client.claim_rewards(&s.recycler, &0);
+}
@@ -0,0 +1,327 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::testutils::RewardFixture;
+use crate::types::{PoolPayout, Role, VestingPolicy, WasteType};
+
+fn setup(env: &Env) -> RewardFixture<'_> {
+    RewardFixture::new(env, 10, 40)
+}
+
+fn policy(lock_period: u64, vesting_period: u64) -> VestingPolicy {
+    VestingPolicy {
+        threshold: 500,
+        lock_period,
+        vesting_period,
+    }
+}
+
+/// Distribute a 10kg metal reward of 2000 tokens through one collector
+/// The collector earns shares of 200 and 1000, the recycler a share of 800
+fn distribute(s: &RewardFixture) -> u64 {
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Metal, &200, &200_000);
+    let material = s.client.submit_material(&s.recycler, &WasteType::Metal, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.collector);
+
+    s.client.distribute_rewards(&material.id, &incentive.id, &s.manufacturer);
+    material.id
+}
+
+/// Have the manufacturer dispute a waste's rewards and the admin uphold it
+fn dispute_succeeds(s: &RewardFixture, waste_id: u64) {
+    s.client.raise_dispute(&s.manufacturer, &waste_id);
+    s.client.resolve_dispute(&s.admin, &waste_id, &true);
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_large_shares_vest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    // Only the share under the threshold is claimable at once
+    assert_eq!(s.client.get_claimable(&s.collector), 200);
+    assert_eq!(s.client.get_claimable(&s.recycler), 0);
+
+    let schedules = s.client.get_vesting_schedules(&s.collector);
+    assert_eq!(schedules.len(), 1);
+    let schedule = schedules.get(0).unwrap();
+    assert_eq!(schedule.total, 1_000);
+    assert_eq!(schedule.waste_id, waste_id);
+    assert_eq!(s.client.get_vesting_schedule(&schedule.id), Some(schedule));
+    assert_eq!(s.client.get_vesting_schedules(&s.recycler).get(0).unwrap().total, 800);
+
+    // Vesting shares still count as earned
+    assert_eq!(s.client.get_participant_stats(&s.collector).total_earned, 1_200);
+}
+
+#[test]
+fn test_linear_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    distribute(&s);
+
+    set_time(&env, 250);
+    assert_eq!(s.client.get_releasable(&s.collector), 250);
+    assert_eq!(s.client.release_vested(&s.collector), 250);
+    assert_eq!(s.client.get_claimable(&s.collector), 450);
+    assert_eq!(s.client.get_releasable(&s.collector), 0);
+
+    set_time(&env, 5_000);
+    assert_eq!(s.client.release_vested(&s.collector), 750);
+    assert_eq!(s.client.get_claimable(&s.collector), 1_200);
+
+    s.client.claim_rewards(&s.collector, &1_200);
+    assert_eq!(s.token.balance(&s.collector), 1_200);
+}
+
+#[test]
+fn test_lock_until_dispute_window_passes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(500, 0));
+    distribute(&s);
+
+    set_time(&env, 499);
+    assert_eq!(s.client.get_releasable(&s.recycler), 0);
+
+    set_time(&env, 500);
+    assert_eq!(s.client.release_vested(&s.recycler), 800);
+    assert_eq!(s.client.get_claimable(&s.recycler), 800);
+}
+
+#[test]
+#[should_panic(expected = "Nothing vested to release")]
+fn test_release_during_lock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(500, 0));
+    distribute(&s);
+
+    s.client.release_vested(&s.recycler);
+}
+
+#[test]
+fn test_clawback_unvested() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    set_time(&env, 400);
+    s.client.release_vested(&s.collector);
+
+    s.client.raise_dispute(&s.manufacturer, &waste_id);
+    assert_eq!(s.client.get_open_dispute(&waste_id), Some(s.manufacturer.clone()));
+    s.client.resolve_dispute(&s.admin, &waste_id, &true);
+    assert!(s.client.is_dispute_succeeded(&waste_id));
+    assert_eq!(s.client.get_open_dispute(&waste_id), None);
+
+    // 600 of the collector's share and 480 of the recycler's were unvested
+    assert_eq!(s.client.clawback_unvested(&s.admin, &waste_id), 1_080);
+    assert_eq!(s.token.balance(&s.manufacturer), 998_000 + 1_080);
+    assert_eq!(s.client.get_participant_stats(&s.collector).total_earned, 600);
+    assert_eq!(s.client.get_total_earned(), 920);
+
+    // Vested shares stay with their beneficiaries
+    set_time(&env, 5_000);
+    assert_eq!(s.client.get_releasable(&s.collector), 0);
+    assert_eq!(s.client.release_vested(&s.recycler), 320);
+    assert!(s.client.get_vesting_schedules(&s.recycler).get(0).unwrap().clawed_back);
+}
+
+#[test]
+fn test_clawback_restores_incentive_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+
+    // A 2000 token reward exhausts the budget
+    let incentive = s.client.create_incentive(&s.manufacturer, &WasteType::Metal, &200, &2_000);
+    let material = s.client.submit_material(&s.recycler, &WasteType::Metal, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.collector);
+    s.client.distribute_rewards(&material.id, &incentive.id, &s.manufacturer);
+    assert!(!s.client.get_incentive_by_id(&incentive.id).unwrap().active);
+    assert!(s.client.is_reward_paid(&material.id));
+
+    set_time(&env, 400);
+    dispute_succeeds(&s, material.id);
+    assert_eq!(s.client.clawback_unvested(&s.admin, &material.id), 1_080);
+
+    let restored = s.client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(restored.remaining_budget, 1_080);
+    assert!(restored.active);
+    assert!(!s.client.is_reward_paid(&material.id));
+}
+
+#[test]
+#[should_panic(expected = "Nothing to claw back")]
+fn test_clawback_after_fully_vested() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    set_time(&env, 1_000);
+    dispute_succeeds(&s, waste_id);
+    s.client.clawback_unvested(&s.admin, &waste_id);
+}
+
+#[test]
+#[should_panic(expected = "Dispute not succeeded")]
+fn test_clawback_requires_successful_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    s.client.clawback_unvested(&s.admin, &waste_id);
+}
+
+#[test]
+#[should_panic(expected = "Dispute not succeeded")]
+fn test_rejected_dispute_blocks_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    s.client.raise_dispute(&s.manufacturer, &waste_id);
+    s.client.resolve_dispute(&s.admin, &waste_id, &false);
+    assert!(!s.client.is_dispute_succeeded(&waste_id));
+    assert_eq!(s.client.get_open_dispute(&waste_id), None);
+
+    s.client.clawback_unvested(&s.admin, &waste_id);
+}
+
+#[test]
+#[should_panic(expected = "No open dispute")]
+fn test_admin_cannot_resolve_undisputed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    s.client.resolve_dispute(&s.admin, &waste_id, &true);
+}
+
+#[test]
+#[should_panic(expected = "Only a paying rewarder can dispute")]
+fn test_only_paying_rewarder_disputes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    s.client.raise_dispute(&s.collector, &waste_id);
+}
+
+#[test]
+fn test_clawback_returns_to_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+
+    let partner = Address::generate(&env);
+    s.client.register_participant(&partner, &Role::Manufacturer, &String::from_str(&env, "Partner"), &0, &0);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&partner, &1_000);
+
+    let pool = s.client.create_incentive_pool(&s.manufacturer, &WasteType::Metal, &200, &PoolPayout::ProRata);
+    s.client.contribute_to_pool(&s.manufacturer, &pool.id, &3_000, &0);
+    s.client.contribute_to_pool(&partner, &pool.id, &1_000, &0);
+
+    let material = s.client.submit_material(&s.recycler, &WasteType::Metal, &10_000);
+    s.client.confirm_waste(&material.id, &s.manufacturer);
+    s.client.transfer_waste(&material.id, &s.recycler, &s.collector);
+    s.client.distribute_rewards(&material.id, &pool.id, &s.manufacturer);
+
+    set_time(&env, 400);
+    dispute_succeeds(&s, material.id);
+    assert_eq!(s.client.clawback_unvested(&s.admin, &material.id), 1_080);
+
+    // Funders paid 1500 and 500 of the reward, and get the clawback back
+    // in the same proportion
+    let contributions = s.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 2_310);
+    assert_eq!(contributions.get(1).unwrap().remaining, 770);
+    assert_eq!(s.client.get_incentive_escrow(&pool.id), 3_080);
+    assert_eq!(s.client.get_incentive_by_id(&pool.id).unwrap().remaining_budget, 3_080);
+    assert!(!s.client.is_reward_paid(&material.id));
+
+    // Closing the pool refunds what was clawed back
+    s.client.close_incentive_pool(&s.manufacturer, &pool.id);
+    assert_eq!(s.token.balance(&partner), 770);
+}
+
+#[test]
+#[should_panic(expected = "Only admin can perform this action")]
+fn test_only_admin_resolves_disputes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    let waste_id = distribute(&s);
+
+    s.client.raise_dispute(&s.manufacturer, &waste_id);
+    s.client.resolve_dispute(&s.manufacturer, &waste_id, &true);
+}
+
+#[test]
+fn test_clear_vesting_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    s.client.set_vesting_policy(&s.admin, &policy(0, 1_000));
+    assert_eq!(s.client.get_vesting_policy(), Some(policy(0, 1_000)));
+
+    s.client.clear_vesting_policy(&s.admin);
+    assert_eq!(s.client.get_vesting_policy(), None);
+
+    distribute(&s);
+    assert_eq!(s.client.get_claimable(&s.collector), 1_200);
+    assert_eq!(s.client.get_vesting_schedules(&s.collector).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Only admin can perform this action")]
+fn test_only_admin_sets_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client.set_vesting_policy(&s.manufacturer, &policy(0, 1_000));
+}
+
+#[test]
+#[should_panic(expected = "Vesting policy must lock or vest")]
+fn test_policy_must_lock_or_vest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client.set_vesting_policy(&s.admin, &policy(0, 0));
+}
@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::contract::ScavengerContract;
+use crate::types::{Material, Role, WasteCategory, WasteType};
+
+fn create_test_contract(env: &Env) -> (crate::contract::ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register(ScavengerContract, ());
+    let client = crate::contract::ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env.register_stellar_asset_contract(admin.clone());
+    let charity_address = Address::generate(env);
+
+    client.initialize(&admin, &token_address, &charity_address, &5, &50);
+
+    (client, admin)
+}
+
+fn add_textiles(env: &Env, client: &crate::contract::ScavengerContractClient<'_>, admin: &Address) -> WasteType {
+    let category = WasteCategory {
+        id: 0,
+        name: String::from_str(env, "TEXTILES"),
+        is_plastic: false,
+        is_biodegradable: true,
+        is_infinitely_recyclable: false,
+        reward_multiplier: 2,
+        max_weight: 50_000,
+        is_active: false,
+    };
+    client.add_waste_category(admin, &category).waste_type()
+}
+
+#[test]
+fn test_builtin_categories() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = create_test_contract(&env);
+
+    let categories = client.get_waste_categories();
+    assert_eq!(categories.len(), 5);
+
+    let pet = client.get_waste_category(&WasteType::PetPlastic).unwrap();
+    assert_eq!(pet.name, String::from_str(&env, "PETPLASTIC"));
+    assert!(pet.is_plastic);
+    assert_eq!(pet.reward_multiplier, 3);
+    assert_eq!(client.get_waste_category(&WasteType(5)), None);
+}
+
+#[test]
+fn test_add_category_and_submit_material() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_test_contract(&env);
+
+    let textiles = add_textiles(&env, &client, &admin);
+    assert_eq!(textiles, WasteType(5));
+    assert_eq!(client.get_waste_categories().len(), 6);
+
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &0, &0);
+
+    let material = client.submit_material(&recycler, &textiles, &10_000);
+    assert_eq!(material.waste_type, textiles);
+    assert!(client.get_waste_category(&textiles).unwrap().is_active);
+}
+
+#[test]
+fn test_reward_points_follow_catalogue_multiplier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_test_contract(&env);
+
+    let textiles = add_textiles(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let metal = Material::new(1, WasteType::Metal, 5_000, owner.clone(), 0);
+    let cloth = Material::new(2, textiles, 5_000, owner, 0);
+
+    env.as_contract(&client.address, || {
+        // 5kg * 5 * 10 and 5kg * 2 * 10
+        assert_eq!(metal.calculate_reward_points(&env), 250);
+        assert_eq!(cloth.calculate_reward_points(&env), 100);
+    });
+
+    let mut updated = client.get_waste_category(&textiles).unwrap();
+    updated.reward_multiplier = 6;
+    client.update_waste_category(&admin, &updated);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(cloth.calculate_reward_points(&env), 300);
+    });
+}
+
+#[test]
+fn test_incentive_for_catalogue_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_test_contract(&env);
+
+    let textiles = add_textiles(&env, &client, &admin);
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &0, &0);
+
+    let incentive = client.create_incentive(&manufacturer, &textiles, &40, &10_000);
+    assert_eq!(incentive.waste_type, textiles);
+    assert_eq!(client.get_incentives_by_waste_type(&textiles).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Weight exceeds category maximum")]
+fn test_implausible_weight_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_test_contract(&env);
+
+    let textiles = add_textiles(&env, &client, &admin);
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &0, &0);
+
+    client.submit_material(&recycler, &textiles, &50_001);
+}
+
+#[test]
+#[should_panic(expected = "Unknown waste type")]
+fn test_unknown_waste_type_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = create_test_contract(&env);
+
+    let recycler = Address::generate(&env);
+    client.register_participant(&recycler, &Role::Recycler, &String::from_str(&env, "Recycler"), &0, &0);
+
+    client.submit_material(&recycler, &WasteType(42), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Waste type is not active")]
+fn test_inactive_category_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = create_test_contract(&env);
+
+    let mut glass = client.get_waste_category(&WasteType::Glass).unwrap();
+    glass.is_active = false;
+    client.update_waste_category(&admin, &glass);
+
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(&env, "Manufacturer"), &0, &0);
+
+    client.create_incentive(&manufacturer, &WasteType::Glass, &40, &7_000);
+}
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
 use crate::contract::{ScavengerContract, ScavengerContractClient};
 use crate::types::{Role, WasteType};
 
@@ -62,3 +62,44 @@ impl<'a> TestEnv<'a> {
         soroban_sdk::token::Client::new(&self.env, &self.token)
     }
 }
+
+/// Contract with a Stellar asset token, a manufacturer holding 1,000,000
+/// tokens, a recycler and a collector, shared by the reward tests
+pub struct RewardFixture<'a> {
+    pub client: ScavengerContractClient<'a>,
+    pub token: token::Client<'a>,
+    pub admin: Address,
+    pub manufacturer: Address,
+    pub recycler: Address,
+    pub collector: Address,
+}
+
+impl<'a> RewardFixture<'a> {
+    pub fn new(env: &'a Env, collector_percentage: u32, owner_percentage: u32) -> Self {
+        let contract_id = env.register(ScavengerContract, ());
+        let client = ScavengerContractClient::new(env, &contract_id);
+
+        let admin = Address::generate(env);
+        let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let charity_address = Address::generate(env);
+        client.initialize(&admin, &token_address, &charity_address, &collector_percentage, &owner_percentage);
+
+        let manufacturer = Address::generate(env);
+        let recycler = Address::generate(env);
+        let collector = Address::generate(env);
+        client.register_participant(&manufacturer, &Role::Manufacturer, &String::from_str(env, "Manufacturer"), &0, &0);
+        client.register_participant(&recycler, &Role::Recycler, &String::from_str(env, "Recycler"), &0, &0);
+        client.register_participant(&collector, &Role::Collector, &String::from_str(env, "Collector"), &0, &0);
+
+        token::StellarAssetClient::new(env, &token_address).mint(&manufacturer, &1_000_000);
+
+        Self {
+            client,
+            token: token::Client::new(env, &token_address),
+            admin,
+            manufacturer,
+            recycler,
+            collector,
+        }
+    }
+}
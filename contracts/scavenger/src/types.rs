@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, Env, String};
 
 /// Participant role in the scavenger system
 #[contracttype]
@@ -16,15 +16,101 @@ impl Role {
     }
 }
 
-/// Waste type in the recycling system
+/// Waste type in the recycling system, keyed by waste catalogue id
+///
+/// Ids 0-4 are the built-in categories; admins can add more at runtime.
+/// This replaced an integer enum, so records written by earlier builds
+/// (stored as `U32` rather than a one-element `Vec`) no longer decode.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum WasteType {
-    Paper = 0,
-    PetPlastic = 1,
-    Plastic = 2,
-    Metal = 3,
-    Glass = 4,
+pub struct WasteType(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl WasteType {
+    pub const Paper: WasteType = WasteType(0);
+    pub const PetPlastic: WasteType = WasteType(1);
+    pub const Plastic: WasteType = WasteType(2);
+    pub const Metal: WasteType = WasteType(3);
+    pub const Glass: WasteType = WasteType(4);
+
+    /// Number of built-in waste categories
+    pub const BUILTIN_COUNT: u32 = 5;
+
+    /// Check if this is one of the built-in waste categories
+    pub fn is_builtin(&self) -> bool {
+        self.0 < Self::BUILTIN_COUNT
+    }
+
+    /// Default grams of CO2e avoided per kilogram recycled
+    /// Categories added at runtime avoid nothing until the admin sets a factor
+    pub fn default_emission_factor(&self) -> u64 {
+        match *self {
+            WasteType::Paper => 900,
+            WasteType::PetPlastic => 1_500,
+            WasteType::Plastic => 1_400,
+            WasteType::Metal => 4_000,
+            WasteType::Glass => 300,
+            _ => 0,
+        }
+    }
+}
+
+/// Default maximum plausible weight for a single submission (in grams)
+pub const DEFAULT_MAX_CATEGORY_WEIGHT: u64 = 1_000_000_000;
+
+/// Waste category in the admin-managed catalogue
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasteCategory {
+    /// Catalogue id, used as the waste type
+    pub id: u32,
+    /// Display name
+    pub name: String,
+    pub is_plastic: bool,
+    pub is_biodegradable: bool,
+    pub is_infinitely_recyclable: bool,
+    /// Default reward multiplier per kilogram
+    pub reward_multiplier: u64,
+    /// Maximum plausible weight for a single submission (in grams)
+    pub max_weight: u64,
+    /// Whether new wastes and incentives may use this category
+    pub is_active: bool,
+}
+
+impl WasteCategory {
+    /// Default catalogue entry for a built-in waste type
+    pub fn builtin(env: &Env, waste_type: WasteType) -> Option<Self> {
+        let (name, is_plastic, is_biodegradable, is_infinitely_recyclable, reward_multiplier) =
+            match waste_type {
+                WasteType::Paper => ("PAPER", false, true, false, 1),
+                WasteType::PetPlastic => ("PETPLASTIC", true, false, false, 3),
+                WasteType::Plastic => ("PLASTIC", true, false, false, 2),
+                WasteType::Metal => ("METAL", false, false, true, 5),
+                WasteType::Glass => ("GLASS", false, false, true, 2),
+                _ => return None,
+            };
+
+        Some(Self {
+            id: waste_type.0,
+            name: String::from_str(env, name),
+            is_plastic,
+            is_biodegradable,
+            is_infinitely_recyclable,
+            reward_multiplier,
+            max_weight: DEFAULT_MAX_CATEGORY_WEIGHT,
+            is_active: true,
+        })
+    }
+
+    /// Waste type this category is keyed by
+    pub fn waste_type(&self) -> WasteType {
+        WasteType(self.id)
+    }
+
+    /// Check if a submission weight is plausible for this category
+    pub fn allows_weight(&self, weight: u64) -> bool {
+        weight <= self.max_weight
+    }
 }
 
 /// Participant information
@@ -84,6 +170,133 @@ impl Incentive {
     }
 }
 
+/// Anti-farming limits on how much of an incentive one participant can earn
+/// A zero value leaves that limit off
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncentiveLimits {
+    /// Maximum weight in grams rewarded per participant
+    pub max_weight_per_participant: u64,
+    /// Maximum reward points per participant
+    pub max_reward_per_participant: u64,
+    /// Length of the rolling cap window in seconds (see `DAILY` and `WEEKLY`)
+    pub period: u64,
+    /// Maximum reward points per participant within one window
+    pub max_reward_per_period: u64,
+    /// Minimum time since registration, in seconds, before a participant can earn
+    pub min_account_age: u64,
+}
+
+impl IncentiveLimits {
+    /// One day in seconds
+    pub const DAILY: u64 = 86_400;
+    /// One week in seconds
+    pub const WEEKLY: u64 = 604_800;
+
+    /// Start of the cap window containing `timestamp`
+    pub fn period_start(&self, timestamp: u64) -> u64 {
+        if self.period == 0 {
+            return 0;
+        }
+        timestamp - timestamp % self.period
+    }
+}
+
+/// Where and until when an incentive pays out
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IncentiveScope {
+    /// Timestamp from which the incentive stops paying (0 never expires)
+    pub expires_at: u64,
+    /// Bounding box submitters must be registered within
+    /// A box with every bound at zero accepts any location
+    pub min_latitude: i64,
+    pub max_latitude: i64,
+    pub min_longitude: i64,
+    pub max_longitude: i64,
+}
+
+impl IncentiveScope {
+    /// Whether the incentive has expired at `timestamp`
+    pub fn is_expired_at(&self, timestamp: u64) -> bool {
+        self.expires_at != 0 && timestamp >= self.expires_at
+    }
+
+    /// Whether a location falls within the incentive's region
+    pub fn covers(&self, latitude: i64, longitude: i64) -> bool {
+        let anywhere = self.min_latitude == 0
+            && self.max_latitude == 0
+            && self.min_longitude == 0
+            && self.max_longitude == 0;
+
+        anywhere
+            || ((self.min_latitude..=self.max_latitude).contains(&latitude)
+                && (self.min_longitude..=self.max_longitude).contains(&longitude))
+    }
+}
+
+/// What an incentive would pay for a delivery, used to rank incentives
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncentiveQuote {
+    /// Incentive being quoted
+    pub incentive_id: u64,
+    /// Manufacturer offering the incentive
+    pub rewarder: Address,
+    /// Reward points per kilogram
+    pub reward_points: u64,
+    /// Reward the delivery would earn after caps are applied
+    pub payout: i128,
+}
+
+/// How much of an incentive a participant has earned so far
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IncentiveUsage {
+    /// Weight rewarded in grams
+    pub weight: u64,
+    /// Reward points earned
+    pub reward: u64,
+    /// Start of the current cap window
+    pub period_start: u64,
+    /// Reward points earned within the current cap window
+    pub period_reward: u64,
+}
+
+/// How a pooled incentive's payouts are split between its funders
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolPayout {
+    /// In proportion to each funder's unspent contribution
+    ProRata,
+    /// In proportion to each funder's configured share
+    Shares,
+}
+
+/// A manufacturer's stake in a pooled incentive
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolContribution {
+    /// Manufacturer who contributed
+    pub funder: Address,
+    /// Total tokens contributed
+    pub contributed: i128,
+    /// Contributed tokens not yet paid out
+    pub remaining: i128,
+    /// Configured share of payouts in basis points (share-based pools only)
+    pub share_bps: u32,
+}
+
+impl PoolContribution {
+    /// Weight of this contribution when splitting a payout
+    pub fn payout_weight(&self, payout: PoolPayout) -> i128 {
+        match payout {
+            PoolPayout::ProRata => self.remaining,
+            PoolPayout::Shares => self.share_bps as i128,
+        }
+    }
+}
+
 /// Waste material submission
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -132,6 +345,15 @@ impl Material {
             confirmer: submitter, // Default to submitter, will be updated on confirmation
         }
     }
+
+    /// Reward points for the material: weight in kg * catalogue multiplier * 10
+    /// Unknown waste types use a multiplier of 1
+    pub fn calculate_reward_points(&self, env: &Env) -> u64 {
+        let multiplier = crate::storage::Storage::get_waste_category(env, self.waste_type)
+            .map(|c| c.reward_multiplier)
+            .unwrap_or(1);
+        (self.weight / 1000) * multiplier * 10
+    }
 }
 
 /// Waste transfer record
@@ -198,4 +420,87 @@ pub struct GlobalMetrics {
     pub total_wastes_count: u64,
     /// Total amount of tokens earned across all participants
     pub total_tokens_earned: i128,
-}
\ No newline at end of file
+    /// Total weight of submitted materials in grams
+    pub total_weight: u64,
+    /// Emissions avoided by confirmed materials in grams of CO2e
+    pub total_co2e_avoided: u64,
+}
+
+/// Proof that recycling credits were permanently retired
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetirementCertificate {
+    /// Unique identifier for the certificate
+    pub id: u64,
+    /// Address that retired the credits
+    pub owner: Address,
+    /// Waste type the credits were denominated in
+    pub waste_type: WasteType,
+    /// Amount of credits retired in grams
+    pub grams: u64,
+    /// Timestamp when the credits were retired
+    pub retired_at: u64,
+}
+
+/// Lock-up applied to large reward shares so they can be clawed back if a
+/// delivery turns out to be fraudulent
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingPolicy {
+    /// Shares above this amount vest instead of becoming claimable at once
+    pub threshold: i128,
+    /// Dispute window in seconds before any of a share vests
+    pub lock_period: u64,
+    /// Seconds over which a share then vests linearly (0 vests it all at once)
+    pub vesting_period: u64,
+}
+
+/// A reward share that becomes claimable over time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Unique identifier for the schedule
+    pub id: u64,
+    /// Participant the share belongs to
+    pub beneficiary: Address,
+    /// Waste the share was earned for
+    pub waste_id: u64,
+    /// Incentive that paid the share
+    pub incentive_id: u64,
+    /// Tokens in the schedule
+    pub total: i128,
+    /// Tokens already moved to the claimable balance
+    pub released: i128,
+    /// Timestamp the share was earned
+    pub start: u64,
+    /// Dispute window in seconds before any of the share vests
+    pub lock_period: u64,
+    /// Seconds over which the share vests after the lock
+    pub vesting_period: u64,
+    /// Whether the unvested part was clawed back
+    pub clawed_back: bool,
+}
+
+impl VestingSchedule {
+    /// Tokens vested by `timestamp`
+    pub fn vested_at(&self, timestamp: u64) -> i128 {
+        let unlocked_at = self.start + self.lock_period;
+        if self.clawed_back {
+            return self.total;
+        }
+        if timestamp < unlocked_at {
+            return 0;
+        }
+
+        let elapsed = timestamp - unlocked_at;
+        if elapsed >= self.vesting_period {
+            return self.total;
+        }
+        self.total * elapsed as i128 / self.vesting_period as i128
+    }
+
+    /// Tokens vested by `timestamp` but not yet released
+    pub fn releasable_at(&self, timestamp: u64) -> i128 {
+        self.vested_at(timestamp) - self.released
+    }
+}
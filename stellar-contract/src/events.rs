@@ -1,10 +1,42 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, Bytes, Env, Symbol, Vec};
 
-use crate::types::WasteType;
+use crate::types::{
+    BuyOrderStatus, DisputeStatus, DisputeTarget, EvidenceKind, ListingStatus, LocationPrivacy,
+    RoundingMode, WasteStatus, WasteType,
+};
 
 const WASTE_REGISTERED: Symbol = symbol_short!("recycled");
 const DONATION_MADE: Symbol = symbol_short!("donated");
 const WASTE_CONFIRMED: Symbol = symbol_short!("confirmed");
+const CONFIRMATION_ADDED: Symbol = symbol_short!("conf_add");
+const WASTE_SPLIT: Symbol = symbol_short!("split");
+const WASTE_STATUS: Symbol = symbol_short!("status");
+const BATCH_CREATED: Symbol = symbol_short!("batch");
+const CREDITS_MINTED: Symbol = symbol_short!("cr_mint");
+const CREDITS_TRANSFERRED: Symbol = symbol_short!("cr_xfer");
+const CREDITS_RETIRED: Symbol = symbol_short!("cr_retire");
+const EVIDENCE_ADDED: Symbol = symbol_short!("evidence");
+const DISPUTE_OPENED: Symbol = symbol_short!("disp_open");
+const DISPUTE_RESOLVED: Symbol = symbol_short!("disp_res");
+const WEIGHT_DISCREPANCY: Symbol = symbol_short!("discrep");
+const REWARD_RATE_SET: Symbol = symbol_short!("rate_set");
+const LOCATION_PRIVACY: Symbol = symbol_short!("loc_priv");
+const EMISSION_FACTOR_SET: Symbol = symbol_short!("emis_set");
+const INCENTIVE_CREATED: Symbol = symbol_short!("inc_set");
+const INCENTIVE_UPDATED: Symbol = symbol_short!("inc_upd");
+const INCENTIVE_TOPPED_UP: Symbol = symbol_short!("inc_topup");
+const INCENTIVE_DEACTIVATED: Symbol = symbol_short!("inc_deact");
+const INCENTIVE_CLOSED: Symbol = symbol_short!("inc_close");
+const INCENTIVE_EXHAUSTED: Symbol = symbol_short!("inc_exhst");
+const INCENTIVE_CLAIMED: Symbol = symbol_short!("inc_claim");
+const POOL_CONTRIBUTED: Symbol = symbol_short!("pool_add");
+const POOL_REFUNDED: Symbol = symbol_short!("pool_rfnd");
+const LISTING_CREATED: Symbol = symbol_short!("listed");
+const LISTING_CLOSED: Symbol = symbol_short!("unlisted");
+const OFFER_PLACED: Symbol = symbol_short!("offer");
+const BUY_ORDER_POSTED: Symbol = symbol_short!("buy_post");
+const BUY_ORDER_FILLED: Symbol = symbol_short!("buy_fill");
+const BUY_ORDER_CLOSED: Symbol = symbol_short!("buy_close");
 
 /// Emit event when waste is registered
 pub fn emit_waste_registered(
@@ -46,3 +78,340 @@ pub fn emit_waste_confirmed(
         confirmer,
     );
 }
+
+/// Emit event when a confirmation is recorded towards a quorum
+pub fn emit_confirmation_added(
+    env: &Env,
+    waste_id: u128,
+    confirmer: &Address,
+    count: u32,
+    required: u32,
+) {
+    env.events().publish(
+        (CONFIRMATION_ADDED, waste_id),
+        (confirmer, count, required),
+    );
+}
+
+/// Emit event when a waste lot is split into child lots
+pub fn emit_waste_split(
+    env: &Env,
+    waste_id: u128,
+    owner: &Address,
+    child_ids: &Vec<u128>,
+) {
+    env.events().publish(
+        (WASTE_SPLIT, waste_id),
+        (owner, child_ids.clone()),
+    );
+}
+
+/// Emit event when a waste moves to a new lifecycle status
+pub fn emit_waste_status_changed(
+    env: &Env,
+    waste_id: u128,
+    previous: WasteStatus,
+    next: WasteStatus,
+) {
+    env.events().publish(
+        (WASTE_STATUS, waste_id),
+        (previous, next),
+    );
+}
+
+/// Emit event when a manufacturer records a product batch
+pub fn emit_product_batch_created(
+    env: &Env,
+    batch_id: u64,
+    manufacturer: &Address,
+    recycled_weight: u128,
+    total_weight: u128,
+) {
+    env.events().publish(
+        (BATCH_CREATED, batch_id),
+        (manufacturer, recycled_weight, total_weight),
+    );
+}
+
+/// Emit event when recycling credits are minted from a waste
+pub fn emit_credits_minted(
+    env: &Env,
+    waste_id: u128,
+    owner: &Address,
+    waste_type: WasteType,
+    grams: u128,
+) {
+    env.events().publish(
+        (CREDITS_MINTED, waste_id),
+        (owner, waste_type, grams),
+    );
+}
+
+/// Emit event when recycling credits change hands
+pub fn emit_credits_transferred(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    waste_type: WasteType,
+    grams: u128,
+) {
+    env.events().publish(
+        (CREDITS_TRANSFERRED, from),
+        (to, waste_type, grams),
+    );
+}
+
+/// Emit event when recycling credits are retired
+pub fn emit_credits_retired(
+    env: &Env,
+    certificate_id: u64,
+    owner: &Address,
+    waste_type: WasteType,
+    grams: u128,
+) {
+    env.events().publish(
+        (CREDITS_RETIRED, certificate_id),
+        (owner, waste_type, grams),
+    );
+}
+
+/// Emit event when evidence is attached to a waste
+pub fn emit_evidence_added(
+    env: &Env,
+    waste_id: u128,
+    submitted_by: &Address,
+    kind: EvidenceKind,
+    hash: &Bytes,
+) {
+    env.events().publish(
+        (EVIDENCE_ADDED, waste_id),
+        (submitted_by, kind, hash.clone()),
+    );
+}
+
+/// Emit event when a dispute is opened against a confirmation or verification
+pub fn emit_dispute_opened(
+    env: &Env,
+    dispute_id: u64,
+    waste_id: u128,
+    target: DisputeTarget,
+    disputer: &Address,
+    bond: u128,
+) {
+    env.events().publish(
+        (DISPUTE_OPENED, dispute_id),
+        (waste_id, target, disputer, bond),
+    );
+}
+
+/// Emit event when an arbitrator resolves a dispute
+pub fn emit_dispute_resolved(
+    env: &Env,
+    dispute_id: u64,
+    arbitrator: &Address,
+    status: DisputeStatus,
+) {
+    env.events().publish(
+        (DISPUTE_RESOLVED, dispute_id),
+        (arbitrator, status),
+    );
+}
+
+/// Emit event when a handover weight falls outside the configured tolerance
+pub fn emit_weight_discrepancy(
+    env: &Env,
+    waste_id: u128,
+    receiver: &Address,
+    prior_weight: u128,
+    measured_weight: u128,
+) {
+    env.events().publish(
+        (WEIGHT_DISCREPANCY, waste_id),
+        (receiver, prior_weight, measured_weight),
+    );
+}
+
+/// Emit event when the admin changes a reward table entry
+pub fn emit_reward_rate_set(
+    env: &Env,
+    waste_type: WasteType,
+    points_per_kg: u64,
+    rounding: RoundingMode,
+    version: u32,
+) {
+    env.events().publish(
+        (REWARD_RATE_SET, waste_type),
+        (points_per_kg, rounding, version),
+    );
+}
+
+/// Emit event when a participant changes how their location is stored
+pub fn emit_location_privacy_set(env: &Env, participant: &Address, mode: LocationPrivacy) {
+    env.events().publish((LOCATION_PRIVACY, participant), mode);
+}
+
+/// Emit event when the admin changes an emission factor
+pub fn emit_emission_factor_set(
+    env: &Env,
+    waste_type: WasteType,
+    avoided_per_kg: u64,
+    transport_per_tonne_km: u64,
+) {
+    env.events().publish(
+        (EMISSION_FACTOR_SET, waste_type),
+        (avoided_per_kg, transport_per_tonne_km),
+    );
+}
+
+/// Emit event when a waste is listed on the marketplace
+pub fn emit_listing_created(
+    env: &Env,
+    listing_id: u64,
+    waste_id: u128,
+    seller: &Address,
+    price: i128,
+) {
+    env.events()
+        .publish((LISTING_CREATED, listing_id), (waste_id, seller, price));
+}
+
+/// Emit event when an offer is placed on a listing
+pub fn emit_offer_placed(
+    env: &Env,
+    offer_id: u64,
+    listing_id: u64,
+    buyer: &Address,
+    amount: i128,
+) {
+    env.events()
+        .publish((OFFER_PLACED, listing_id), (offer_id, buyer, amount));
+}
+
+/// Emit event when a listing is sold, cancelled or expires
+pub fn emit_listing_closed(env: &Env, listing_id: u64, status: ListingStatus) {
+    env.events().publish((LISTING_CLOSED, listing_id), status);
+}
+
+/// Emit event when a manufacturer posts a buy order
+pub fn emit_buy_order_posted(
+    env: &Env,
+    order_id: u64,
+    buyer: &Address,
+    waste_type: WasteType,
+    quantity: u128,
+    price_per_kg: i128,
+) {
+    env.events().publish(
+        (BUY_ORDER_POSTED, waste_type),
+        (order_id, buyer, quantity, price_per_kg),
+    );
+}
+
+/// Emit event when a waste is sold into a buy order
+pub fn emit_buy_order_filled(
+    env: &Env,
+    order_id: u64,
+    waste_id: u128,
+    seller: &Address,
+    weight: u128,
+    payment: i128,
+) {
+    env.events().publish(
+        (BUY_ORDER_FILLED, order_id),
+        (waste_id, seller, weight, payment),
+    );
+}
+
+/// Emit event when a buy order is filled, cancelled or expires
+pub fn emit_buy_order_closed(env: &Env, order_id: u64, status: BuyOrderStatus) {
+    env.events().publish((BUY_ORDER_CLOSED, order_id), status);
+}
+
+/// Emit event when a manufacturer creates an incentive
+pub fn emit_incentive_created(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    waste_type: WasteType,
+    reward: u128,
+    max_waste_amount: u128,
+    total_budget: u128,
+) {
+    env.events().publish(
+        (INCENTIVE_CREATED, incentive_id),
+        (rewarder, waste_type, reward, max_waste_amount, total_budget),
+    );
+}
+
+/// Emit event when an incentive's terms are updated
+pub fn emit_incentive_updated(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    reward: u128,
+    max_waste_amount: u128,
+    total_budget: u128,
+) {
+    env.events().publish(
+        (INCENTIVE_UPDATED, incentive_id),
+        (rewarder, reward, max_waste_amount, total_budget),
+    );
+}
+
+/// Emit event when funds are escrowed for an incentive
+pub fn emit_incentive_topped_up(
+    env: &Env,
+    incentive_id: u64,
+    rewarder: &Address,
+    amount: i128,
+    escrow: i128,
+) {
+    env.events().publish(
+        (INCENTIVE_TOPPED_UP, incentive_id),
+        (rewarder, amount, escrow),
+    );
+}
+
+/// Emit event when an incentive is deactivated by its rewarder
+pub fn emit_incentive_deactivated(env: &Env, incentive_id: u64, rewarder: &Address) {
+    env.events()
+        .publish((INCENTIVE_DEACTIVATED, incentive_id), rewarder);
+}
+
+/// Emit event when an incentive is closed and its escrow refunded
+pub fn emit_incentive_closed(env: &Env, incentive_id: u64, rewarder: &Address, refunded: i128) {
+    env.events()
+        .publish((INCENTIVE_CLOSED, incentive_id), (rewarder, refunded));
+}
+
+/// Emit event when an incentive's budget runs out and it deactivates
+pub fn emit_incentive_exhausted(env: &Env, incentive_id: u64, total_budget: u128) {
+    env.events()
+        .publish((INCENTIVE_EXHAUSTED, incentive_id), total_budget);
+}
+
+/// Emit event when a submitter claims an incentive's reward for a material
+pub fn emit_incentive_claimed(
+    env: &Env,
+    incentive_id: u64,
+    material_id: u64,
+    claimant: &Address,
+    reward: u128,
+) {
+    env.events().publish(
+        (INCENTIVE_CLAIMED, incentive_id),
+        (material_id, claimant, reward),
+    );
+}
+
+/// Emit event when a manufacturer contributes to a pooled incentive
+pub fn emit_pool_contributed(env: &Env, incentive_id: u64, funder: &Address, amount: i128) {
+    env.events()
+        .publish((POOL_CONTRIBUTED, incentive_id), (funder, amount));
+}
+
+/// Emit event when a closing pool returns a funder's unspent contribution
+pub fn emit_pool_refunded(env: &Env, incentive_id: u64, funder: &Address, amount: i128) {
+    env.events()
+        .publish((POOL_REFUNDED, incentive_id), (funder, amount));
+}
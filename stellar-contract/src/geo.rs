@@ -0,0 +1,200 @@
+//! Integer-only geospatial helpers for coordinates scaled by 1e6
+//!
+//! Locations are bucketed into a fixed grid of `CELL_SIZE` micro-degree
+//! cells so proximity queries only have to scan the cells around a point.
+//! The same grid backs the coarse location privacy mode.
+
+use soroban_sdk::{Bytes, BytesN, Env};
+
+/// Grid cell edge in micro-degrees (0.1 degrees, roughly 11 km of latitude)
+pub const CELL_SIZE: i128 = 100_000;
+
+/// Number of longitude cells around the globe
+const LON_CELLS: i128 = 360_000_000 / CELL_SIZE;
+
+/// Metres per degree of latitude on a sphere of the mean Earth radius
+const METRES_PER_DEGREE: i128 = 111_195;
+
+const MICRO: i128 = 1_000_000;
+const MAX_LAT: i128 = 90 * MICRO;
+const MAX_LON: i128 = 180 * MICRO;
+
+/// Whether a point lies within [-90, 90] degrees latitude and
+/// [-180, 180] degrees longitude
+pub fn is_valid_coordinates(latitude: i128, longitude: i128) -> bool {
+    (-MAX_LAT..=MAX_LAT).contains(&latitude) && (-MAX_LON..=MAX_LON).contains(&longitude)
+}
+
+/// Grid cell containing a point, as (latitude row, longitude column)
+pub fn cell_of(latitude: i128, longitude: i128) -> (i32, i32) {
+    let lat = latitude.clamp(-MAX_LAT, MAX_LAT - 1);
+    let row = lat.div_euclid(CELL_SIZE);
+    let col = wrap_column(longitude.div_euclid(CELL_SIZE));
+    (row as i32, col as i32)
+}
+
+/// Centre of the grid cell containing a point
+/// Used to store a location without revealing more than its cell
+pub fn coarsen(latitude: i128, longitude: i128) -> (i128, i128) {
+    let (row, col) = cell_of(latitude, longitude);
+    (
+        row as i128 * CELL_SIZE + CELL_SIZE / 2,
+        col as i128 * CELL_SIZE + CELL_SIZE / 2,
+    )
+}
+
+/// Salted commitment to a location: sha256(latitude || longitude || salt)
+/// Coordinates are encoded as 16-byte big-endian integers
+pub fn location_commitment(env: &Env, latitude: i128, longitude: i128, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &latitude.to_be_bytes());
+    preimage.extend_from_array(&longitude.to_be_bytes());
+    preimage.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Cells that may hold points within `radius` metres of a location
+/// Returns inclusive (min, max) row and column bounds; columns may run past
+/// the antimeridian and must be passed through `wrap_column`
+pub fn cells_within(latitude: i128, longitude: i128, radius: u64) -> ((i32, i32), (i32, i32)) {
+    let (row, col) = cell_of(latitude, longitude);
+
+    let lat_span = micro_degrees_for(radius as i128, MICRO) / CELL_SIZE + 1;
+
+    // Longitude cells shrink towards the poles, so size the span for the
+    // highest latitude the search can reach
+    let edge = (latitude.abs() + micro_degrees_for(radius as i128, MICRO)).min(MAX_LAT);
+    let cos = cos_scaled(edge).max(1);
+    let lon_span = micro_degrees_for(radius as i128, cos) / CELL_SIZE + 1;
+
+    let min_row = (row as i128 - lat_span).max(-MAX_LAT / CELL_SIZE);
+    let max_row = (row as i128 + lat_span).min(MAX_LAT / CELL_SIZE - 1);
+
+    // Searches wider than the globe scan every column once
+    let (min_col, max_col) = if lon_span * 2 + 1 >= LON_CELLS {
+        (-LON_CELLS / 2, LON_CELLS / 2 - 1)
+    } else {
+        (col as i128 - lon_span, col as i128 + lon_span)
+    };
+
+    (
+        (min_row as i32, max_row as i32),
+        (min_col as i32, max_col as i32),
+    )
+}
+
+/// Normalise a longitude column onto the grid, wrapping at the antimeridian
+pub fn wrap_column(col: i128) -> i128 {
+    (col + LON_CELLS / 2).rem_euclid(LON_CELLS) - LON_CELLS / 2
+}
+
+/// Approximate ground distance in metres between two points
+/// Uses an equirectangular projection, which is accurate to well under 1%
+/// at the radii proximity queries work with
+pub fn distance(lat_a: i128, lon_a: i128, lat_b: i128, lon_b: i128) -> u64 {
+    let d_lat = (lat_a - lat_b).abs();
+    let mut d_lon = (lon_a - lon_b).abs() % (360 * MICRO);
+    if d_lon > 180 * MICRO {
+        d_lon = 360 * MICRO - d_lon;
+    }
+
+    let mean_lat = (lat_a + lat_b) / 2;
+    let y = d_lat * METRES_PER_DEGREE / MICRO;
+    let x = d_lon * cos_scaled(mean_lat) / MICRO * METRES_PER_DEGREE / MICRO;
+
+    isqrt((x * x + y * y) as u128) as u64
+}
+
+/// Cosine of a latitude given in micro-degrees, scaled by 1e6
+/// Bhaskara I's rational approximation, within 0.2% over [-90, 90] degrees
+pub fn cos_scaled(latitude: i128) -> i128 {
+    let t = latitude.clamp(-MAX_LAT, MAX_LAT);
+    let t2 = t * t;
+    let c = 32_400 * MICRO * MICRO;
+    MICRO * (c - 4 * t2) / (c + t2)
+}
+
+/// Micro-degrees spanned by `metres` along a parallel whose cosine is `cos`
+fn micro_degrees_for(metres: i128, cos: i128) -> i128 {
+    metres * MICRO * MICRO / (METRES_PER_DEGREE * cos)
+}
+
+/// Integer square root (floor)
+pub fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(1_000_000_000_000), 1_000_000);
+    }
+
+    #[test]
+    fn test_cos_scaled() {
+        assert_eq!(cos_scaled(0), 1_000_000);
+        assert_eq!(cos_scaled(90_000_000), 0);
+        // cos(60) = 0.5
+        assert!((cos_scaled(60_000_000) - 500_000).abs() < 2_000);
+        assert_eq!(cos_scaled(-45_000_000), cos_scaled(45_000_000));
+    }
+
+    #[test]
+    fn test_distance() {
+        // One degree of latitude
+        assert_eq!(distance(0, 0, 1_000_000, 0), 111_195);
+        // One degree of longitude at 60N is about half as long
+        let d = distance(60_000_000, 0, 60_000_000, 1_000_000);
+        assert!((55_000..56_000).contains(&d));
+        // Across the antimeridian
+        assert_eq!(distance(0, 179_950_000, 0, -179_950_000), 11_119);
+    }
+
+    #[test]
+    fn test_cell_of() {
+        assert_eq!(cell_of(0, 0), (0, 0));
+        assert_eq!(cell_of(150_000, -50_000), (1, -1));
+        assert_eq!(cell_of(90_000_000, 180_000_000), (899, -1800));
+    }
+
+    #[test]
+    fn test_is_valid_coordinates() {
+        assert!(is_valid_coordinates(90_000_000, -180_000_000));
+        assert!(!is_valid_coordinates(90_000_001, 0));
+        assert!(!is_valid_coordinates(0, 180_000_001));
+    }
+
+    #[test]
+    fn test_coarsen() {
+        assert_eq!(coarsen(6_524_000, 3_379_000), (6_550_000, 3_350_000));
+        assert_eq!(coarsen(-10, -10), (-50_000, -50_000));
+    }
+
+    #[test]
+    fn test_cells_within() {
+        let (rows, cols) = cells_within(0, 0, 5_000);
+        assert_eq!(rows, (-1, 1));
+        assert_eq!(cols, (-1, 1));
+
+        // Near the pole every column is scanned
+        let (rows, cols) = cells_within(89_990_000, 0, 50_000);
+        assert_eq!(rows.1, 899);
+        assert_eq!(cols, (-1800, 1799));
+    }
+}
@@ -1,17 +1,26 @@
 #![no_std]
 
 mod events;
+mod geo;
+mod pool;
 mod types;
 
 
 
+pub use geo::location_commitment;
 pub use types::{
-    Material, ParticipantRole, RecyclingStats, TransferItemType, TransferRecord, TransferStatus,
-    Waste, WasteTransfer, WasteType,
+    BatchInput, BuyOrder, BuyOrderStatus, BuyOrderTerms, CarbonImpact, Dispute, DisputeStatus, DisputeTarget,
+    EmissionFactor, Evidence, EvidenceKind, Incentive, IncentiveQuote, IncentiveScope, Listing, ListingStatus, LocationPrivacy, Material, Offer, OfferStatus, ParticipantRole, PoolContribution, PoolPayout, ProductBatch,
+    ProvenanceRecord, QuorumRule, RecyclingStats, RetirementCertificate,
+    RewardRate, RoundingMode, ScaleDevice, SubTypeStats, TransferItemType, TransferRecord, TransferStatus, Waste,
+    SubmissionProof, WasteCategory, WasteStatus, WasteSubType, WasteTransfer, WasteType, WeighReceipt,
 };
 
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, Address, Bytes, BytesN, Env,
+    String, Symbol, Vec,
+};
 
 // Storage keys
 const ADMIN: Symbol = symbol_short!("ADMIN");
@@ -20,6 +29,26 @@ const COLLECTOR_PCT: Symbol = symbol_short!("COL_PCT");
 const OWNER_PCT: Symbol = symbol_short!("OWN_PCT");
 const TOTAL_WEIGHT: Symbol = symbol_short!("TOT_WGT");
 const TOTAL_TOKENS: Symbol = symbol_short!("TOT_TKN");
+const TOTAL_IMPACT: Symbol = symbol_short!("TOT_CO2");
+const MARKET_TOKEN: Symbol = symbol_short!("MKT_TKN");
+
+// Evidence limits
+const MAX_EVIDENCE_LEN: u32 = 128;
+const MAX_EVIDENCE_PER_WASTE: u32 = 16;
+
+// Allowed handover weight discrepancy in basis points when none is configured
+const DEFAULT_WEIGHT_TOLERANCE_BPS: u32 = 500;
+
+// Maximum age of a scale receipt in seconds
+const MAX_RECEIPT_AGE: u64 = 3_600;
+// Distance a location-bound scale may be from the waste (in 1e-6 degrees)
+const DEVICE_LOCATION_TOLERANCE: i128 = 1_000;
+
+// Reputation change applied when a dispute is resolved
+const DISPUTE_REPUTATION_STEP: i128 = 10;
+
+// Largest radius a proximity query may search, in metres
+const MAX_SEARCH_RADIUS: u64 = 50_000;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -36,26 +65,6 @@ pub struct Participant {
 }
 
 
-/// Represents a manufacturer incentive program for recycling specific waste types
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Incentive {
-    /// Unique identifier for the incentive
-    pub id: u64,
-    /// Type of waste this incentive applies to
-    pub waste_type: WasteType,
-    /// Reward amount per unit (in tokens)
-    pub reward: u128,
-    /// Maximum waste amount eligible for this incentive (in grams)
-    pub max_waste_amount: u128,
-    /// Address of the manufacturer offering the incentive
-    pub rewarder: Address,
-    /// Whether this incentive is currently active
-    pub is_active: bool,
-    /// Timestamp when the incentive was created
-    pub created_at: u64,
-}
-
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ParticipantInfo {
@@ -245,6 +254,8 @@ impl ScavengerContract {
             panic!("Participant already registered");
         }
 
+        Self::require_valid_coordinates(latitude, longitude);
+
         let participant = Participant {
             address: address.clone(),
             role,
@@ -259,6 +270,8 @@ impl ScavengerContract {
 
         // Store participant using helper function
         Self::set_participant(&env, &address, &participant);
+        // Privacy modes are chosen after registering, so the location is exact
+        Self::index_participant(&env, &address, None, Some((latitude, longitude)));
 
         participant
     }
@@ -293,6 +306,13 @@ impl ScavengerContract {
         }
     }
 
+    /// Validate that coordinates scaled by 1e6 are within range
+    fn require_valid_coordinates(latitude: i128, longitude: i128) {
+        if !geo::is_valid_coordinates(latitude, longitude) {
+            panic!("Invalid coordinates");
+        }
+    }
+
     /// Validate that a participant is registered before allowing restricted actions
     fn require_registered(env: &Env, address: &Address) {
         let key = (address.clone(),);
@@ -325,9 +345,526 @@ impl ScavengerContract {
         env.storage().instance().has(&key)
     }
 
-    /// Convert waste type enum to a human-readable string.
+    /// Store a location-tracked waste record
+    /// Internal helper function for v2 waste storage
+    fn set_waste_v2(env: &Env, waste: &Waste) {
+        env.storage().instance().set(&("waste_v2", waste.waste_id), waste);
+    }
+
+    /// Retrieve a location-tracked waste record by ID (internal helper)
+    fn get_waste_v2_internal(env: &Env, waste_id: u128) -> Option<Waste> {
+        env.storage().instance().get(&("waste_v2", waste_id))
+    }
+
+    /// Move a waste to a new lifecycle status
+    /// Panics on a disallowed transition and emits a status change event
+    fn transition_waste(env: &Env, waste: &mut Waste, next: WasteStatus) {
+        let previous = waste.status;
+        if !waste.transition_to(next) {
+            panic!("Invalid status transition");
+        }
+        events::emit_waste_status_changed(env, waste.waste_id, previous, next);
+    }
+
+    /// Get a location-tracked waste record by ID
+    /// Returns None if the waste doesn't exist
+    pub fn get_waste_v2(env: Env, waste_id: u128) -> Option<Waste> {
+        Self::get_waste_v2_internal(&env, waste_id)
+    }
+
+    /// Get the location-tracked transfer history for a waste
+    /// Returns chronologically ordered list of transfers; a lot created by
+    /// a split starts with the history of the lot it was split from
+    pub fn get_transfer_history_v2(env: Env, waste_id: u128) -> Vec<WasteTransfer> {
+        let mut history = match Self::get_waste_parent(env.clone(), waste_id) {
+            Some(parent_id) => Self::get_transfer_history_v2(env.clone(), parent_id),
+            None => Vec::new(&env),
+        };
+        let own: Vec<WasteTransfer> = env
+            .storage()
+            .instance()
+            .get(&("transfer_history", waste_id))
+            .unwrap_or(Vec::new(&env));
+        history.append(&own);
+        history
+    }
+
+    /// Append an evidence hash to a waste's evidence log
+    /// Enforces size limits and emits an evidence event
+    fn record_evidence(
+        env: &Env,
+        waste_id: u128,
+        hash: Bytes,
+        kind: EvidenceKind,
+        submitted_by: &Address,
+    ) -> Evidence {
+        if hash.is_empty() {
+            panic!("Evidence hash cannot be empty");
+        }
+        if hash.len() > MAX_EVIDENCE_LEN {
+            panic!("Evidence hash too large");
+        }
+
+        let key = ("waste_evidence", waste_id);
+        let mut log: Vec<Evidence> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        if log.len() >= MAX_EVIDENCE_PER_WASTE {
+            panic!("Evidence limit reached");
+        }
+
+        let evidence = Evidence {
+            hash: hash.clone(),
+            kind,
+            submitted_by: submitted_by.clone(),
+            submitted_at: env.ledger().timestamp(),
+        };
+        log.push_back(evidence.clone());
+        env.storage().instance().set(&key, &log);
+
+        events::emit_evidence_added(env, waste_id, submitted_by, kind, &hash);
+
+        evidence
+    }
+
+    /// Append evidence to an existing waste or material
+    /// Allowed for the current owner, or the confirmer of a confirmed waste
+    pub fn add_waste_evidence(env: Env, waste_id: u128, submitter: Address, hash: Bytes) -> Evidence {
+        submitter.require_auth();
+
+        let authorized = if let Some(waste) = Self::get_waste_v2_internal(&env, waste_id) {
+            waste.current_owner == submitter || (waste.is_confirmed && waste.confirmer == submitter)
+        } else {
+            let material_id = u64::try_from(waste_id).expect("Waste not found");
+            let material = Self::get_waste_internal(&env, material_id).expect("Waste not found");
+            material.submitter == submitter
+        };
+
+        if !authorized {
+            panic!("Not authorized to add evidence");
+        }
+
+        Self::record_evidence(&env, waste_id, hash, EvidenceKind::Supplement, &submitter)
+    }
+
+    /// Get all evidence attached to a waste or material
+    pub fn get_waste_evidence(env: Env, waste_id: u128) -> Vec<Evidence> {
+        env.storage()
+            .instance()
+            .get(&("waste_evidence", waste_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========== Scale Device Functions ==========
+
+    /// Register a smart scale's ed25519 public key (admin only)
+    /// A device is bound either to an operator or to its installed location
+    pub fn register_scale_device(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+        operator: Option<Address>,
+        latitude: i128,
+        longitude: i128,
+    ) -> ScaleDevice {
+        Self::require_admin(&env, &admin);
+        Self::require_valid_coordinates(latitude, longitude);
+
+        let key = ("scale_device", public_key.clone());
+        if env.storage().instance().has(&key) {
+            panic!("Device already registered");
+        }
+
+        if let Some(operator) = operator.clone() {
+            Self::require_registered(&env, &operator);
+        }
+
+        let device = ScaleDevice {
+            public_key,
+            operator,
+            latitude,
+            longitude,
+            is_active: true,
+            registered_at: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&key, &device);
+
+        device
+    }
+
+    /// Stop accepting receipts from a scale device (admin only)
+    pub fn deactivate_scale_device(env: Env, admin: Address, public_key: BytesN<32>) -> ScaleDevice {
+        Self::require_admin(&env, &admin);
+
+        let key = ("scale_device", public_key);
+        let mut device: ScaleDevice = env.storage().instance().get(&key).expect("Device not found");
+        device.is_active = false;
+        env.storage().instance().set(&key, &device);
+
+        device
+    }
+
+    /// Get a registered scale device by public key
+    pub fn get_scale_device(env: Env, public_key: BytesN<32>) -> Option<ScaleDevice> {
+        env.storage().instance().get(&("scale_device", public_key))
+    }
+
+    /// Check if a waste's weight was attested by a scale device
+    pub fn is_weight_attested(env: Env, waste_id: u128) -> bool {
+        env.storage().instance().has(&("weight_attested", waste_id))
+    }
+
+    /// Get the public key of the device that attested a waste's weight
+    pub fn get_weight_attestation(env: Env, waste_id: u128) -> Option<BytesN<32>> {
+        env.storage().instance().get(&("weight_attested", waste_id))
+    }
+
+    /// Verify a device-signed receipt against a submission and mark the waste attested
+    /// `location` is the waste's coordinates, when known, for location-bound devices
+    fn attest_weight(
+        env: &Env,
+        waste_id: u128,
+        submitter: &Address,
+        waste_type: WasteType,
+        grams: u128,
+        location: Option<(i128, i128)>,
+        receipt: &WeighReceipt,
+    ) {
+        let device: ScaleDevice = env
+            .storage()
+            .instance()
+            .get(&("scale_device", receipt.device.clone()))
+            .expect("Device not registered");
+
+        if !device.is_active {
+            panic!("Device is not active");
+        }
+
+        match device.operator {
+            Some(operator) => {
+                if operator != *submitter {
+                    panic!("Device is bound to another participant");
+                }
+            }
+            None => {
+                let (latitude, longitude) = location.expect("Device is bound to a location");
+                if (latitude - device.latitude).abs() > DEVICE_LOCATION_TOLERANCE
+                    || (longitude - device.longitude).abs() > DEVICE_LOCATION_TOLERANCE
+                {
+                    panic!("Waste location does not match device");
+                }
+            }
+        }
+
+        if receipt.waste_type != waste_type || receipt.grams != grams {
+            panic!("Receipt does not match submission");
+        }
+
+        let now = env.ledger().timestamp();
+        if receipt.timestamp > now || now - receipt.timestamp > MAX_RECEIPT_AGE {
+            panic!("Receipt has expired");
+        }
+
+        let nonce_key = ("receipt_nonce", receipt.device.clone(), receipt.nonce);
+        if env.storage().instance().has(&nonce_key) {
+            panic!("Receipt already used");
+        }
+
+        env.crypto()
+            .ed25519_verify(&receipt.device, &receipt.payload(env, &env.current_contract_address()), &receipt.signature);
+
+        env.storage().instance().set(&nonce_key, &true);
+        env.storage()
+            .instance()
+            .set(&("weight_attested", waste_id), &receipt.device);
+    }
+
+    // ========== Waste Catalogue Functions ==========
+
+    /// Add a new waste category to the catalogue (admin only)
+    /// The category's id and active flag are assigned by the contract.
+    /// Returns the stored category; its id is used as the WasteType in every
+    /// waste and incentive API
+    pub fn add_waste_category(env: Env, admin: Address, category: WasteCategory) -> WasteCategory {
+        Self::require_admin(&env, &admin);
+
+        if category.max_weight == 0 {
+            panic!("Max weight must be greater than zero");
+        }
+
+        let id = Self::get_category_count(&env);
+        env.storage().instance().set(&("category_count",), &(id + 1));
+
+        let category = WasteCategory {
+            id,
+            is_active: true,
+            ..category
+        };
+        env.storage().instance().set(&("waste_category", id), &category);
+
+        category
+    }
+
+    /// Replace the metadata of an existing waste category (admin only)
+    /// Built-in categories can be updated the same way
+    pub fn update_waste_category(env: Env, admin: Address, category: WasteCategory) -> WasteCategory {
+        Self::require_admin(&env, &admin);
+
+        if Self::get_waste_category(env.clone(), category.waste_type()).is_none() {
+            panic!("Unknown waste type");
+        }
+
+        if category.max_weight == 0 {
+            panic!("Max weight must be greater than zero");
+        }
+
+        env.storage()
+            .instance()
+            .set(&("waste_category", category.id), &category);
+
+        category
+    }
+
+    /// Get a waste category from the catalogue
+    /// Built-in categories fall back to their defaults until updated
+    pub fn get_waste_category(env: Env, waste_type: WasteType) -> Option<WasteCategory> {
+        WasteCategory::load(&env, waste_type)
+    }
+
+    /// Get every category in the catalogue, ordered by id
+    pub fn get_waste_categories(env: Env) -> Vec<WasteCategory> {
+        let mut results = Vec::new(&env);
+        for id in 0..Self::get_category_count(&env) {
+            if let Some(category) = Self::get_waste_category(env.clone(), WasteType(id)) {
+                results.push_back(category);
+            }
+        }
+        results
+    }
+
+    /// Number of catalogue ids handed out, including the built-in categories
+    fn get_category_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&("category_count",))
+            .unwrap_or(WasteType::BUILTIN_COUNT)
+    }
+
+    /// Look up an active catalogue category, panicking if it cannot be used
+    fn require_waste_category(env: &Env, waste_type: WasteType) -> WasteCategory {
+        let category =
+            Self::get_waste_category(env.clone(), waste_type).expect("Unknown waste type");
+
+        if !category.is_active {
+            panic!("Waste type is not active");
+        }
+
+        category
+    }
+
+    /// Validate a submission's weight against its catalogue category
+    fn require_plausible_weight(env: &Env, waste_type: WasteType, weight: u128) {
+        let category = Self::require_waste_category(env, waste_type);
+        if !category.allows_weight(weight) {
+            panic!("Weight exceeds category maximum");
+        }
+    }
+
+    // ========== Reward Table Functions ==========
+
+    /// Set the reward rate for a waste type (admin only)
+    /// Materials verified earlier keep the rate that applied when they were verified
+    pub fn set_reward_rate(
+        env: Env,
+        admin: Address,
+        waste_type: WasteType,
+        points_per_kg: u64,
+        rounding: RoundingMode,
+    ) -> RewardRate {
+        Self::require_admin(&env, &admin);
+
+        if Self::get_waste_category(env.clone(), waste_type).is_none() {
+            panic!("Unknown waste type");
+        }
+
+        let previous = Self::get_reward_rate(env.clone(), waste_type);
+        let rate = RewardRate {
+            waste_type,
+            points_per_kg,
+            rounding,
+            version: previous.version + 1,
+            effective_from: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&("reward_rate", waste_type), &rate);
+
+        events::emit_reward_rate_set(&env, waste_type, points_per_kg, rounding, rate.version);
+
+        rate
+    }
+
+    /// Get the current reward rate for a waste type
+    /// Falls back to the catalogue multiplier until the admin sets a rate
+    pub fn get_reward_rate(env: Env, waste_type: WasteType) -> RewardRate {
+        if let Some(rate) = env.storage().instance().get(&("reward_rate", waste_type)) {
+            return rate;
+        }
+
+        RewardRate::standard(waste_type, waste_type.reward_multiplier(&env))
+    }
+
+    /// Get the reward rate a material was verified at
+    /// Returns None if the material has not been verified
+    pub fn get_material_reward_rate(env: Env, material_id: u64) -> Option<RewardRate> {
+        env.storage()
+            .instance()
+            .get(&("material_rate", material_id))
+    }
+
+    /// Reward points a verified material earned at its recorded rate
+    fn applied_reward_points(env: &Env, material: &Material) -> u64 {
+        let rate = Self::get_material_reward_rate(env.clone(), material.id)
+            .unwrap_or_else(|| Self::get_reward_rate(env.clone(), material.waste_type));
+        material.reward_points(&rate)
+    }
+
+    /// Record a verification at the current reward rate and credit the submitter
+    fn reward_verified_material(env: &Env, material: &Material) {
+        let rate = Self::get_reward_rate(env.clone(), material.waste_type);
+        env.storage()
+            .instance()
+            .set(&("material_rate", material.id), &rate);
+
+        // Calculate tokens earned
+        let tokens_earned = material.reward_points(&rate);
+
+        let weight = material.weight as u128;
+        let co2e = Self::get_emission_factor(env.clone(), material.waste_type).avoided_for(weight);
+        env.storage()
+            .instance()
+            .set(&("material_co2e", material.id), &co2e);
+        // Ownership can move on, so remember who was paid for the verification
+        env.storage()
+            .instance()
+            .set(&("material_rewardee", material.id), &material.submitter);
+
+        // Update submitter stats
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", material.submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+
+        stats.record_verification(material, &rate);
+        stats.record_co2e_avoided(co2e);
+        env.storage()
+            .instance()
+            .set(&("stats", material.submitter.clone()), &stats);
+
+        // Update submitter's participant stats with tokens earned
+        Self::update_participant_stats(env, &material.submitter, 0, tokens_earned);
+        Self::update_impact(env, &material.submitter, None, |impact| {
+            impact.record_avoided(weight, co2e)
+        });
+    }
+
+    // ========== Carbon Accounting Functions ==========
+
+    /// Set the emission factors for a waste type (admin only)
+    /// Figures already accounted keep the factor that applied at the time
+    pub fn set_emission_factor(
+        env: Env,
+        admin: Address,
+        waste_type: WasteType,
+        avoided_per_kg: u64,
+        transport_per_tonne_km: u64,
+    ) -> EmissionFactor {
+        Self::require_admin(&env, &admin);
+
+        if Self::get_waste_category(env.clone(), waste_type).is_none() {
+            panic!("Unknown waste type");
+        }
+
+        let factor = EmissionFactor {
+            waste_type,
+            avoided_per_kg,
+            transport_per_tonne_km,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&("emission_factor", waste_type), &factor);
+
+        events::emit_emission_factor_set(&env, waste_type, avoided_per_kg, transport_per_tonne_km);
+
+        factor
+    }
+
+    /// Get the emission factors for a waste type
+    pub fn get_emission_factor(env: Env, waste_type: WasteType) -> EmissionFactor {
+        env.storage()
+            .instance()
+            .get(&("emission_factor", waste_type))
+            .unwrap_or_else(|| EmissionFactor::default_for(waste_type))
+    }
+
+    /// Get the carbon account of a waste
+    /// Transport is accrued on every hop; avoided emissions once it is processed
+    pub fn get_waste_impact(env: Env, waste_id: u128) -> CarbonImpact {
+        env.storage()
+            .instance()
+            .get(&("waste_impact", waste_id))
+            .unwrap_or_default()
+    }
+
+    /// Get the carbon account of a participant
+    /// Recyclers are credited with avoided emissions, receivers with transport
+    pub fn get_participant_impact(env: Env, participant: Address) -> CarbonImpact {
+        env.storage()
+            .instance()
+            .get(&("impact", participant))
+            .unwrap_or_default()
+    }
+
+    /// Get the carbon account of the whole system
+    pub fn get_global_impact(env: Env) -> CarbonImpact {
+        env.storage()
+            .instance()
+            .get(&TOTAL_IMPACT)
+            .unwrap_or_default()
+    }
+
+    /// Apply a change to the global carbon account, a participant's and
+    /// optionally a waste's
+    fn update_impact(
+        env: &Env,
+        participant: &Address,
+        waste_id: Option<u128>,
+        apply: impl Fn(&mut CarbonImpact),
+    ) {
+        let mut global = Self::get_global_impact(env.clone());
+        apply(&mut global);
+        env.storage().instance().set(&TOTAL_IMPACT, &global);
+
+        let mut impact = Self::get_participant_impact(env.clone(), participant.clone());
+        apply(&mut impact);
+        env.storage()
+            .instance()
+            .set(&("impact", participant.clone()), &impact);
+
+        if let Some(waste_id) = waste_id {
+            let mut impact = Self::get_waste_impact(env.clone(), waste_id);
+            apply(&mut impact);
+            env.storage()
+                .instance()
+                .set(&("waste_impact", waste_id), &impact);
+        }
+    }
+
+    /// Convert a waste type to its catalogue display name.
     pub fn get_waste_type_string(env: Env, waste_type: WasteType) -> String {
-        String::from_str(&env, waste_type.as_str())
+        Self::get_waste_category(env.clone(), waste_type)
+            .map(|c| c.name)
+            .unwrap_or_else(|| String::from_str(&env, waste_type.as_str()))
     }
 
     /// Convert participant role enum to a human-readable string.
@@ -374,7 +911,6 @@ impl ScavengerContract {
     }
 
     /// Get the total count of incentive records
-    #[allow(dead_code)]
     fn get_incentive_count(env: &Env) -> u64 {
         env.storage()
             .instance()
@@ -383,7 +919,6 @@ impl ScavengerContract {
     }
 
     /// Increment and return the next incentive ID
-    #[allow(dead_code)]
     fn next_incentive_id(env: &Env) -> u64 {
         let count = Self::get_incentive_count(env);
         let next_id = count + 1;
@@ -469,11 +1004,27 @@ impl ScavengerContract {
 
     /// Create a new manufacturer incentive program
     /// Only manufacturers can create incentives
+    /// `reward` is paid per kilogram, for up to `max_waste_amount` grams of each
+    /// waste, until `total_budget` points have been paid out
+    /// A zero budget leaves the incentive inactive until it is topped up
     pub fn create_incentive(
         env: Env,
         waste_type: WasteType,
         reward: u128,
         max_waste_amount: u128,
+        total_budget: u128,
+        rewarder: Address,
+    ) -> Incentive {
+        Self::add_incentive(&env, waste_type, reward, max_waste_amount, total_budget, rewarder)
+    }
+
+    /// Validate and store a new incentive, indexing it under its rewarder
+    fn add_incentive(
+        env: &Env,
+        waste_type: WasteType,
+        reward: u128,
+        max_waste_amount: u128,
+        total_budget: u128,
         rewarder: Address,
     ) -> Incentive {
         rewarder.require_auth();
@@ -499,8 +1050,10 @@ impl ScavengerContract {
             panic!("Max waste amount must be greater than zero");
         }
 
+        Self::require_waste_category(env, waste_type);
+
         // Get next incentive ID
-        let incentive_id = Self::next_incentive_id(&env);
+        let incentive_id = Self::next_incentive_id(env);
 
         // Create incentive
         let incentive = Incentive {
@@ -508,13 +1061,34 @@ impl ScavengerContract {
             waste_type,
             reward,
             max_waste_amount,
+            total_budget,
+            remaining_budget: total_budget,
             rewarder: rewarder.clone(),
-            is_active: true,
+            // An unfunded pool stays inactive until its first contribution
+            is_active: total_budget > 0,
             created_at: env.ledger().timestamp(),
+            requires_attestation: false,
+            sub_types: Vec::new(env),
         };
 
-        // Store incentive
-        Self::set_incentive(&env, incentive_id, &incentive);
+        // Store incentive and index it under its rewarder
+        Self::set_incentive(env, incentive_id, &incentive);
+
+        let key = ("rewarder_incentives", rewarder.clone());
+        let mut rewarder_incentives: Vec<u64> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        rewarder_incentives.push_back(incentive_id);
+        env.storage().instance().set(&key, &rewarder_incentives);
+
+        events::emit_incentive_created(
+            env,
+            incentive_id,
+            &rewarder,
+            waste_type,
+            reward,
+            max_waste_amount,
+            total_budget,
+        );
 
         incentive
     }
@@ -537,20 +1111,274 @@ impl ScavengerContract {
         // Require auth from the rewarder
         incentive.rewarder.require_auth();
 
+        if is_active && Self::is_incentive_closed(env.clone(), incentive_id) {
+            panic!("Incentive is closed");
+        }
+        if is_active && incentive.remaining_budget == 0 {
+            panic!("Incentive budget exhausted");
+        }
+
+        let deactivated = incentive.is_active && !is_active;
         incentive.is_active = is_active;
         Self::set_incentive(&env, incentive_id, &incentive);
 
+        if deactivated {
+            events::emit_incentive_deactivated(&env, incentive_id, &incentive.rewarder);
+        }
+
+        incentive
+    }
+
+    /// Deactivate an incentive (rewarder only)
+    pub fn deactivate_incentive(env: Env, incentive_id: u64, rewarder: Address) -> Incentive {
+        let incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        if incentive.rewarder != rewarder {
+            panic!("Only incentive creator can deactivate");
+        }
+
+        Self::update_incentive_status(env, incentive_id, false)
+    }
+
+    /// Escrow marketplace tokens behind an incentive (rewarder only)
+    /// The tokens are added to the budget one point per token, and an incentive
+    /// that ran out of budget is reactivated
+    /// Returns the new escrow balance
+    pub fn top_up_incentive(env: Env, incentive_id: u64, amount: i128) -> i128 {
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        incentive.rewarder.require_auth();
+
+        if Self::is_incentive_closed(env.clone(), incentive_id) {
+            panic!("Incentive is closed");
+        }
+        if Self::get_pool_payout(env.clone(), incentive_id).is_some() {
+            panic!("Contribute to pooled incentives instead");
+        }
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let token = token::Client::new(&env, &Self::market_token(&env));
+        token.transfer(&incentive.rewarder, &env.current_contract_address(), &amount);
+
+        let escrow = Self::get_incentive_escrow(env.clone(), incentive_id) + amount;
+        env.storage()
+            .instance()
+            .set(&("incentive_escrow", incentive_id), &escrow);
+
+        let exhausted = !incentive.is_active && incentive.remaining_budget == 0;
+        incentive.total_budget += amount as u128;
+        incentive.remaining_budget += amount as u128;
+        if exhausted {
+            incentive.is_active = true;
+        }
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        events::emit_incentive_topped_up(&env, incentive_id, &incentive.rewarder, amount, escrow);
+
+        escrow
+    }
+
+    /// Close an incentive for good and refund its escrow (rewarder only)
+    /// A pooled incentive refunds each funder's unspent contribution
+    /// Returns the amount refunded
+    pub fn close_incentive(env: Env, incentive_id: u64) -> i128 {
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        incentive.rewarder.require_auth();
+
+        if Self::is_incentive_closed(env.clone(), incentive_id) {
+            panic!("Incentive is closed");
+        }
+
+        incentive.is_active = false;
+        incentive.remaining_budget = 0;
+        Self::set_incentive(&env, incentive_id, &incentive);
+        env.storage()
+            .instance()
+            .set(&("incentive_closed", incentive_id), &true);
+
+        let escrow = Self::get_incentive_escrow(env.clone(), incentive_id);
+        if escrow > 0 {
+            env.storage()
+                .instance()
+                .set(&("incentive_escrow", incentive_id), &0i128);
+
+            let token = token::Client::new(&env, &Self::market_token(&env));
+            let contract = env.current_contract_address();
+            if Self::get_pool_payout(env.clone(), incentive_id).is_some() {
+                let mut contributions = Self::get_pool_contributions(env.clone(), incentive_id);
+                for i in 0..contributions.len() {
+                    let mut contribution = contributions.get(i).unwrap();
+                    if contribution.remaining > 0 {
+                        token.transfer(&contract, &contribution.funder, &contribution.remaining);
+                        events::emit_pool_refunded(
+                            &env,
+                            incentive_id,
+                            &contribution.funder,
+                            contribution.remaining,
+                        );
+                        contribution.remaining = 0;
+                        contributions.set(i, contribution);
+                    }
+                }
+                env.storage()
+                    .instance()
+                    .set(&("pool_contributions", incentive_id), &contributions);
+            } else {
+                token.transfer(&contract, &incentive.rewarder, &escrow);
+            }
+        }
+
+        events::emit_incentive_closed(&env, incentive_id, &incentive.rewarder, escrow);
+
+        escrow
+    }
+
+    /// Get the marketplace tokens escrowed behind an incentive
+    pub fn get_incentive_escrow(env: Env, incentive_id: u64) -> i128 {
+        env.storage()
+            .instance()
+            .get(&("incentive_escrow", incentive_id))
+            .unwrap_or(0)
+    }
+
+    /// Check if an incentive has been closed
+    pub fn is_incentive_closed(env: Env, incentive_id: u64) -> bool {
+        env.storage()
+            .instance()
+            .has(&("incentive_closed", incentive_id))
+    }
+
+    // ========== Incentive Pool Functions ==========
+
+    /// Create an incentive whose budget is pooled by several manufacturers
+    /// The pool starts empty and inactive; each contribution escrows tokens and
+    /// adds them to the budget, one point per token
+    pub fn create_incentive_pool(
+        env: Env,
+        waste_type: WasteType,
+        reward: u128,
+        max_waste_amount: u128,
+        rewarder: Address,
+        payout: PoolPayout,
+    ) -> Incentive {
+        let incentive = Self::add_incentive(&env, waste_type, reward, max_waste_amount, 0, rewarder);
+        env.storage()
+            .instance()
+            .set(&("pool_payout", incentive.id), &payout);
         incentive
     }
 
-    /// Update an existing incentive's reward and maximum waste amount
+    /// Contribute tokens to a pooled incentive (registered manufacturers only)
+    /// `share_bps` sets the funder's share of payouts in share-based pools and
+    /// must be zero for pro-rata pools
+    /// A pool that is unfunded or ran out of budget is activated by the contribution
+    pub fn contribute_to_pool(
+        env: Env,
+        funder: Address,
+        incentive_id: u64,
+        amount: i128,
+        share_bps: u32,
+    ) -> PoolContribution {
+        funder.require_auth();
+
+        let participant: Participant = env
+            .storage()
+            .instance()
+            .get(&(funder.clone(),))
+            .expect("Funder not registered");
+        if !participant.role.can_manufacture() {
+            panic!("Only manufacturers can fund pools");
+        }
+
+        let payout = Self::get_pool_payout(env.clone(), incentive_id)
+            .expect("Incentive is not pooled");
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        if Self::is_incentive_closed(env.clone(), incentive_id) {
+            panic!("Incentive is closed");
+        }
+        if !incentive.is_active && incentive.remaining_budget > 0 {
+            panic!("Incentive not active");
+        }
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let mut contributions = Self::get_pool_contributions(env.clone(), incentive_id);
+        let contribution = pool::contribute(&mut contributions, payout, &funder, amount, share_bps);
+
+        let token = token::Client::new(&env, &Self::market_token(&env));
+        token.transfer(&funder, &env.current_contract_address(), &amount);
+
+        env.storage()
+            .instance()
+            .set(&("pool_contributions", incentive_id), &contributions);
+
+        incentive.total_budget += amount as u128;
+        incentive.remaining_budget += amount as u128;
+        incentive.is_active = true;
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        let escrow = Self::get_incentive_escrow(env.clone(), incentive_id) + amount;
+        env.storage()
+            .instance()
+            .set(&("incentive_escrow", incentive_id), &escrow);
+
+        events::emit_pool_contributed(&env, incentive_id, &funder, amount);
+        contribution
+    }
+
+    /// Get each funder's contribution to a pooled incentive
+    pub fn get_pool_contributions(env: Env, incentive_id: u64) -> Vec<PoolContribution> {
+        env.storage()
+            .instance()
+            .get(&("pool_contributions", incentive_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get how a pooled incentive splits payouts, or None if it is not pooled
+    pub fn get_pool_payout(env: Env, incentive_id: u64) -> Option<PoolPayout> {
+        env.storage()
+            .instance()
+            .get(&("pool_payout", incentive_id))
+    }
+
+    /// Check if an address has contributed to a pooled incentive
+    fn is_pool_funder(env: &Env, incentive_id: u64, address: &Address) -> bool {
+        Self::get_pool_contributions(env.clone(), incentive_id)
+            .iter()
+            .any(|contribution| contribution.funder == *address)
+    }
+
+    /// Charge a payout to a pool's funders by the pool's payout rule
+    fn draw_from_pool(env: &Env, incentive_id: u64, amount: i128) {
+        let payout = Self::get_pool_payout(env.clone(), incentive_id)
+            .expect("Incentive is not pooled");
+        let mut contributions = Self::get_pool_contributions(env.clone(), incentive_id);
+        pool::draw(&mut contributions, payout, amount);
+        env.storage()
+            .instance()
+            .set(&("pool_contributions", incentive_id), &contributions);
+    }
+
+    /// Update an existing incentive's reward, maximum waste amount and budget
     /// Only the rewarder can update their incentive
-    /// Only active incentives can be updated
+    /// Only active or budget-exhausted incentives can be updated
+    /// Points already paid out count against the new budget, and an exhausted
+    /// incentive is reactivated if the new budget leaves something to pay
     pub fn update_incentive(
         env: Env,
         incentive_id: u64,
         new_reward: u128,
         new_max_waste_amount: u128,
+        new_total_budget: u128,
     ) -> Incentive {
         // Step 1: Retrieve incentive (existence check)
         let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
@@ -560,7 +1388,11 @@ impl ScavengerContract {
         incentive.rewarder.require_auth();
 
         // Step 3: Active status check
-        if !incentive.is_active {
+        if Self::is_incentive_closed(env.clone(), incentive_id) {
+            panic!("Incentive is closed");
+        }
+        let exhausted = !incentive.is_active && incentive.remaining_budget == 0;
+        if !incentive.is_active && !exhausted {
             panic!("Incentive is not active");
         }
 
@@ -571,18 +1403,35 @@ impl ScavengerContract {
         if new_max_waste_amount == 0 {
             panic!("Max waste amount must be greater than zero");
         }
+        if new_total_budget == 0 {
+            panic!("Total budget must be greater than zero");
+        }
+        if new_total_budget != incentive.total_budget
+            && Self::get_pool_payout(env.clone(), incentive_id).is_some()
+        {
+            panic!("Pooled budgets follow contributions");
+        }
 
         // Step 5: Update fields (atomic)
         incentive.reward = new_reward;
         incentive.max_waste_amount = new_max_waste_amount;
+        if incentive.rebudget(new_total_budget) {
+            events::emit_incentive_exhausted(&env, incentive_id, new_total_budget);
+        } else if exhausted {
+            incentive.is_active = true;
+        }
 
         // Step 6: Persist to storage
         Self::set_incentive(&env, incentive_id, &incentive);
 
         // Step 7: Emit event
-        env.events().publish(
-            (symbol_short!("inc_upd"), incentive_id),
-            (incentive.rewarder.clone(), new_reward, new_max_waste_amount)
+        events::emit_incentive_updated(
+            &env,
+            incentive_id,
+            &incentive.rewarder,
+            new_reward,
+            new_max_waste_amount,
+            new_total_budget,
         );
 
         incentive
@@ -604,22 +1453,211 @@ impl ScavengerContract {
             return 0;
         }
 
-        // Convert waste_amount to u128 for calculation
-        let waste_amount_u128 = waste_amount as u128;
+        // Reward is per kg, for up to max_waste_amount grams
+        incentive.reward_for(waste_amount as u128)
+    }
+
+    /// Require device-attested weight for an incentive (rewarder only)
+    pub fn set_incentive_attestation(env: Env, incentive_id: u64, required: bool) -> Incentive {
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
 
-        // Cap waste amount at max_waste_amount
-        let eligible_amount = if waste_amount_u128 > incentive.max_waste_amount {
-            incentive.max_waste_amount
-        } else {
-            waste_amount_u128
-        };
+        incentive.rewarder.require_auth();
+
+        incentive.requires_attestation = required;
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Set when an incentive expires and the region it pays out in (rewarder only)
+    pub fn set_incentive_scope(env: Env, incentive_id: u64, scope: IncentiveScope) {
+        let incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        incentive.rewarder.require_auth();
+
+        if scope.radius > 0 {
+            Self::require_valid_coordinates(scope.latitude, scope.longitude);
+        }
+
+        env.storage()
+            .instance()
+            .set(&("incentive_scope", incentive_id), &scope);
+    }
+
+    /// Get an incentive's expiry and region
+    pub fn get_incentive_scope(env: Env, incentive_id: u64) -> IncentiveScope {
+        env.storage()
+            .instance()
+            .get(&("incentive_scope", incentive_id))
+            .unwrap_or_default()
+    }
+
+    /// Restrict an incentive to specific waste sub-categories (rewarder only)
+    /// Pass an empty list to accept every sub-category of the incentive's waste type
+    pub fn set_incentive_sub_types(
+        env: Env,
+        incentive_id: u64,
+        sub_types: Vec<WasteSubType>,
+    ) -> Incentive {
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        incentive.rewarder.require_auth();
+
+        for sub_type in sub_types.iter() {
+            if sub_type == WasteSubType::Unspecified {
+                panic!("Incentive must target a specific sub-type");
+            }
+            if !sub_type.applies_to(incentive.waste_type) {
+                panic!("Sub-type does not match waste type");
+            }
+        }
+
+        incentive.sub_types = sub_types;
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Calculate the incentive reward earned by a specific waste
+    /// Returns 0 when the waste type or targeted sub-type does not match, or
+    /// the incentive requires attested weight the waste does not have
+    pub fn calculate_waste_incentive_reward(env: Env, incentive_id: u64, waste_id: u128) -> u128 {
+        let incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+
+        if incentive.waste_type != waste.waste_type {
+            return 0;
+        }
+
+        if !incentive.sub_types.is_empty() && !incentive.sub_types.contains(waste.sub_type) {
+            return 0;
+        }
+
+        if incentive.requires_attestation && !Self::is_weight_attested(env.clone(), waste_id) {
+            return 0;
+        }
+
+        let weight = u64::try_from(waste.weight).expect("Waste weight too large");
+        Self::calculate_incentive_reward(env, incentive_id, weight)
+    }
+
+    /// Claim an incentive's reward for a verified material (submitter only)
+    /// Each material earns at most one incentive reward, drawn from the
+    /// incentive's budget and paid one token per point from its escrow
+    pub fn claim_incentive_reward(
+        env: Env,
+        incentive_id: u64,
+        material_id: u64,
+        claimant: Address,
+    ) -> u128 {
+        claimant.require_auth();
+
+        let material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if !material.verified {
+            panic!("Material not verified");
+        }
+
+        let claim_key = ("incentive_claim", material_id);
+        if env.storage().instance().has(&claim_key) {
+            panic!("Reward already claimed");
+        }
+        if Self::get_open_dispute(env.clone(), material_id as u128).is_some() {
+            panic!("Material is under dispute");
+        }
+
+        let mut incentive: Incentive = Self::get_incentive(&env, incentive_id)
+            .expect("Incentive not found");
+
+        // The material must have been handed to the manufacturer, or a pool
+        // funder, whose escrow pays the claim
+        let pooled = Self::get_pool_payout(env.clone(), incentive_id).is_some();
+        let history = Self::get_transfer_history(env.clone(), material_id);
+        if history.is_empty()
+            || (material.submitter != incentive.rewarder
+                && !(pooled && Self::is_pool_funder(&env, incentive_id, &material.submitter)))
+        {
+            panic!("Waste not delivered to incentive manufacturer");
+        }
+        // Either the original submitter or whoever delivered it may claim
+        let supplier = history.first().unwrap().from;
+        let deliverer = history.last().unwrap().from;
+        if claimant != supplier && claimant != deliverer {
+            panic!("Only a supplier of the material can claim");
+        }
+
+        if incentive.waste_type != material.waste_type {
+            panic!("Waste type mismatch");
+        }
+        if !incentive.is_active {
+            panic!("Incentive not active");
+        }
+        let scope = Self::get_incentive_scope(env.clone(), incentive_id);
+        if scope.is_expired_at(env.ledger().timestamp()) {
+            panic!("Incentive expired");
+        }
+        if !Self::supplier_in_scope(&env, &scope, &supplier) {
+            panic!("Supplier outside incentive region");
+        }
+        // Materials carry no sub-type, so targeted incentives cannot pay them
+        if !incentive.sub_types.is_empty() {
+            panic!("Incentive targets specific sub-types");
+        }
+        if incentive.requires_attestation
+            && !Self::is_weight_attested(env.clone(), material_id as u128)
+        {
+            panic!("Weight not attested");
+        }
+
+        let reward = incentive.reward_for(material.weight as u128);
+        if reward > incentive.remaining_budget {
+            panic!("Insufficient incentive budget");
+        }
+        let amount = i128::try_from(reward).expect("Reward too large");
+        let escrow = Self::get_incentive_escrow(env.clone(), incentive_id);
+        if escrow < amount {
+            panic!("Insufficient escrowed funds");
+        }
+
+        if incentive.draw(reward) {
+            events::emit_incentive_exhausted(&env, incentive_id, incentive.total_budget);
+        }
+        Self::set_incentive(&env, incentive_id, &incentive);
+        env.storage().instance().set(&claim_key, &incentive_id);
+        env.storage()
+            .instance()
+            .set(&("incentive_claim_reward", material_id), &(claimant.clone(), reward));
+
+        let points = u64::try_from(reward).expect("Reward too large");
+        Self::update_participant_stats(&env, &claimant, 0, points);
+
+        if amount > 0 {
+            if pooled {
+                Self::draw_from_pool(&env, incentive_id, amount);
+            }
+            env.storage()
+                .instance()
+                .set(&("incentive_escrow", incentive_id), &(escrow - amount));
+
+            let token = token::Client::new(&env, &Self::market_token(&env));
+            token.transfer(&env.current_contract_address(), &claimant, &amount);
+        }
 
-        // Calculate reward using checked arithmetic
-        // reward_per_gram * eligible_amount
-        eligible_amount
-            .checked_mul(incentive.reward)
-            .and_then(|result| result.checked_div(1000)) // Assuming reward is per kg, divide by 1000 for grams
-            .expect("Overflow in reward calculation")
+        events::emit_incentive_claimed(&env, incentive_id, material_id, &claimant, reward);
+
+        reward
+    }
+
+    /// Get the incentive a material's reward was claimed from, if any
+    pub fn get_incentive_claim(env: Env, material_id: u64) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&("incentive_claim", material_id))
     }
 
     /// Get all incentives for a specific waste type
@@ -641,14 +1679,17 @@ impl ScavengerContract {
         results
     }
 
-    /// Get all active incentives
-    pub fn get_active_incentives(env: Env) -> soroban_sdk::Vec<Incentive> {
+    /// Get all incentives targeting a specific waste sub-type
+    pub fn get_incentives_by_sub_type(
+        env: Env,
+        sub_type: WasteSubType,
+    ) -> soroban_sdk::Vec<Incentive> {
         let mut results = soroban_sdk::Vec::new(&env);
         let count = Self::get_incentive_count(&env);
 
         for i in 1..=count {
             if let Some(incentive) = Self::get_incentive(&env, i) {
-                if incentive.is_active {
+                if incentive.sub_types.contains(sub_type) {
                     results.push_back(incentive);
                 }
             }
@@ -657,9 +1698,216 @@ impl ScavengerContract {
         results
     }
 
+    /// Get all active incentives
+    pub fn get_active_incentives(env: Env) -> soroban_sdk::Vec<Incentive> {
+        let mut results = soroban_sdk::Vec::new(&env);
+        let count = Self::get_incentive_count(&env);
 
-    /// Get participant information
-    pub fn get_participant(env: Env, address: Address) -> Option<Participant> {
+        for i in 1..=count {
+            if let Some(incentive) = Self::get_incentive(&env, i) {
+                if incentive.is_active {
+                    results.push_back(incentive);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get the active incentives for a waste type, highest reward first
+    pub fn get_incentives(env: Env, waste_type: WasteType) -> soroban_sdk::Vec<Incentive> {
+        let mut results: soroban_sdk::Vec<Incentive> = soroban_sdk::Vec::new(&env);
+        let count = Self::get_incentive_count(&env);
+
+        for i in 1..=count {
+            if let Some(incentive) = Self::get_incentive(&env, i) {
+                if incentive.is_active && incentive.waste_type == waste_type {
+                    // Insert after every incentive paying at least as much
+                    let mut index = results.len();
+                    for (j, other) in results.iter().enumerate() {
+                        if other.reward < incentive.reward {
+                            index = j as u32;
+                            break;
+                        }
+                    }
+                    results.insert(index, incentive);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get all incentives created by a rewarder
+    pub fn get_incentives_by_rewarder(env: Env, rewarder: Address) -> soroban_sdk::Vec<Incentive> {
+        let ids: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&("rewarder_incentives", rewarder))
+            .unwrap_or(Vec::new(&env));
+
+        let mut results = soroban_sdk::Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(incentive) = Self::get_incentive(&env, id) {
+                results.push_back(incentive);
+            }
+        }
+
+        results
+    }
+
+    /// Get the active incentive with the highest reward for a specific manufacturer and waste type
+    /// Returns None if no active incentive is found
+    pub fn get_active_incentive(
+        env: Env,
+        manufacturer: Address,
+        waste_type: WasteType,
+    ) -> Option<Incentive> {
+        let mut best_incentive: Option<Incentive> = None;
+
+        for incentive in Self::get_incentives_by_rewarder(env, manufacturer).iter() {
+            if !incentive.is_active || incentive.waste_type != waste_type {
+                continue;
+            }
+            let better = match &best_incentive {
+                Some(best) => incentive.reward > best.reward,
+                None => true,
+            };
+            if better {
+                best_incentive = Some(incentive);
+            }
+        }
+
+        best_incentive
+    }
+
+    /// Rank every manufacturer's incentives for a delivery of `weight` grams
+    /// of a waste type from a location, best payout first
+    /// Incentives that are inactive, expired, out of region, target specific
+    /// sub-types, or lack the budget or escrow to pay in full are left out,
+    /// as are `participant`'s own incentives when given
+    pub fn get_best_incentives(
+        env: Env,
+        waste_type: WasteType,
+        weight: u64,
+        latitude: i128,
+        longitude: i128,
+        participant: Option<Address>,
+    ) -> soroban_sdk::Vec<IncentiveQuote> {
+        let mut quotes: soroban_sdk::Vec<IncentiveQuote> = soroban_sdk::Vec::new(&env);
+
+        for incentive in Self::get_incentives_by_waste_type(env.clone(), waste_type).iter() {
+            if participant.as_ref() == Some(&incentive.rewarder) {
+                continue;
+            }
+            let scope = Self::get_incentive_scope(env.clone(), incentive.id);
+            if !scope.covers(latitude, longitude) {
+                continue;
+            }
+            if let Some(quote) = Self::quote_incentive(&env, &incentive, &scope, weight) {
+                Self::insert_quote(&mut quotes, quote);
+            }
+        }
+
+        quotes
+    }
+
+    /// Rank every manufacturer's incentives for a submitted material, using
+    /// its supplier's registered location
+    /// Leaves out incentives needing attested weight the material lacks, and
+    /// returns nothing once the material's reward has been claimed
+    pub fn get_best_incentives_for_material(
+        env: Env,
+        material_id: u64,
+    ) -> soroban_sdk::Vec<IncentiveQuote> {
+        let material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        let mut quotes: soroban_sdk::Vec<IncentiveQuote> = soroban_sdk::Vec::new(&env);
+        if Self::get_incentive_claim(env.clone(), material_id).is_some() {
+            return quotes;
+        }
+
+        let supplier = Self::get_transfer_history(env.clone(), material_id)
+            .first()
+            .map(|transfer| transfer.from)
+            .unwrap_or(material.submitter);
+        let attested = Self::is_weight_attested(env.clone(), material_id as u128);
+        for incentive in Self::get_incentives_by_waste_type(env.clone(), material.waste_type).iter() {
+            if incentive.requires_attestation && !attested {
+                continue;
+            }
+            if incentive.rewarder == supplier {
+                continue;
+            }
+            let scope = Self::get_incentive_scope(env.clone(), incentive.id);
+            if !Self::supplier_in_scope(&env, &scope, &supplier) {
+                continue;
+            }
+            if let Some(quote) = Self::quote_incentive(&env, &incentive, &scope, material.weight) {
+                Self::insert_quote(&mut quotes, quote);
+            }
+        }
+
+        quotes
+    }
+
+    /// Whether a supplier's registered location falls within an incentive's region
+    fn supplier_in_scope(env: &Env, scope: &IncentiveScope, supplier: &Address) -> bool {
+        if scope.radius == 0 {
+            return true;
+        }
+        let participant: Option<Participant> = env.storage().instance().get(&(supplier.clone(),));
+        participant.is_some_and(|p| scope.covers(p.latitude, p.longitude))
+    }
+
+    /// Quote what an incentive would pay for `weight` grams, if it can pay in full
+    fn quote_incentive(
+        env: &Env,
+        incentive: &Incentive,
+        scope: &IncentiveScope,
+        weight: u64,
+    ) -> Option<IncentiveQuote> {
+        if !incentive.is_active || !incentive.sub_types.is_empty() {
+            return None;
+        }
+        if scope.is_expired_at(env.ledger().timestamp()) {
+            return None;
+        }
+
+        let payout = incentive.reward_for(weight as u128);
+        if payout == 0 || payout > incentive.remaining_budget {
+            return None;
+        }
+        let escrow = Self::get_incentive_escrow(env.clone(), incentive.id);
+        if i128::try_from(payout).map_or(true, |amount| amount > escrow) {
+            return None;
+        }
+
+        Some(IncentiveQuote {
+            incentive_id: incentive.id,
+            rewarder: incentive.rewarder.clone(),
+            reward: incentive.reward,
+            payout,
+        })
+    }
+
+    /// Insert a quote after every quote paying at least as much, so older
+    /// incentives come first on ties
+    fn insert_quote(quotes: &mut soroban_sdk::Vec<IncentiveQuote>, quote: IncentiveQuote) {
+        let mut index = quotes.len();
+        for (i, other) in quotes.iter().enumerate() {
+            if other.payout < quote.payout {
+                index = i as u32;
+                break;
+            }
+        }
+        quotes.insert(index, quote);
+    }
+
+
+    /// Get participant information
+    pub fn get_participant(env: Env, address: Address) -> Option<Participant> {
         let key = (address,);
         env.storage().instance().get(&key)
     }
@@ -736,13 +1984,309 @@ impl ScavengerContract {
             panic!("Participant is not registered");
         }
 
-        participant.latitude = latitude;
-        participant.longitude = longitude;
+        Self::require_valid_coordinates(latitude, longitude);
+
+        let Some(location) = Self::stored_location(&env, &address, latitude, longitude) else {
+            panic!("Location is committed; update the commitment instead");
+        };
+
+        let previous = (participant.latitude, participant.longitude);
+        (participant.latitude, participant.longitude) = location;
+        env.storage().instance().set(&key, &participant);
+        Self::index_participant(&env, &address, Some(previous), Some(location));
+
+        participant
+    }
+
+    // ========== Location Privacy Functions ==========
+
+    /// Choose how precisely the participant's location is stored
+    /// `Coarse` snaps the stored location to its grid cell centre. `Committed`
+    /// keeps only `commitment` (see `location_commitment`), clears the stored
+    /// location and hides the participant from proximity queries; leaving it
+    /// requires a fresh `update_location`.
+    /// Coordinates already submitted remain visible in ledger history, so
+    /// participants wanting privacy from the start should register at (0, 0)
+    /// and set their mode before sharing a location.
+    pub fn set_location_privacy(
+        env: Env,
+        address: Address,
+        mode: LocationPrivacy,
+        commitment: Option<BytesN<32>>,
+    ) -> Participant {
+        address.require_auth();
+        Self::require_registered(&env, &address);
+
+        let key = (address.clone(),);
+        let mut participant: Participant = env.storage().instance().get(&key).unwrap();
+
+        let previous = match Self::get_location_privacy(env.clone(), address.clone()) {
+            LocationPrivacy::Committed => None,
+            _ => Some((participant.latitude, participant.longitude)),
+        };
+
+        let commit_key = ("loc_commit", address.clone());
+        match (mode, commitment) {
+            (LocationPrivacy::Committed, Some(commitment)) => {
+                env.storage().instance().set(&commit_key, &commitment);
+            }
+            (LocationPrivacy::Committed, None) => panic!("Commitment required"),
+            (_, Some(_)) => panic!("Commitment only applies to committed locations"),
+            (_, None) => env.storage().instance().remove(&commit_key),
+        }
+
+        let location = match mode {
+            LocationPrivacy::Exact => previous,
+            LocationPrivacy::Coarse => previous.map(|(lat, lon)| geo::coarsen(lat, lon)),
+            LocationPrivacy::Committed => None,
+        };
+
+        (participant.latitude, participant.longitude) = location.unwrap_or((0, 0));
         env.storage().instance().set(&key, &participant);
+        env.storage()
+            .instance()
+            .set(&("loc_privacy", address.clone()), &mode);
+        Self::index_participant(&env, &address, previous, location);
+
+        events::emit_location_privacy_set(&env, &address, mode);
 
         participant
     }
 
+    /// Get how a participant's location is stored
+    pub fn get_location_privacy(env: Env, address: Address) -> LocationPrivacy {
+        env.storage()
+            .instance()
+            .get(&("loc_privacy", address))
+            .unwrap_or(LocationPrivacy::Exact)
+    }
+
+    /// Get a participant's location commitment, if they use one
+    pub fn get_location_commitment(env: Env, address: Address) -> Option<BytesN<32>> {
+        env.storage().instance().get(&("loc_commit", address))
+    }
+
+    /// Check a location revealed off-chain against a participant's commitment
+    /// The participant shares the coordinates and salt only with the counterparty,
+    /// who can verify them here without the location ever being stored
+    pub fn verify_location(
+        env: Env,
+        address: Address,
+        latitude: i128,
+        longitude: i128,
+        salt: BytesN<32>,
+    ) -> bool {
+        match Self::get_location_commitment(env.clone(), address) {
+            Some(commitment) => {
+                geo::location_commitment(&env, latitude, longitude, &salt) == commitment
+            }
+            None => false,
+        }
+    }
+
+    /// Location to store for a participant under their privacy mode
+    /// Returns None when only a commitment is kept
+    fn stored_location(
+        env: &Env,
+        address: &Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> Option<(i128, i128)> {
+        match Self::get_location_privacy(env.clone(), address.clone()) {
+            LocationPrivacy::Exact => Some((latitude, longitude)),
+            LocationPrivacy::Coarse => Some(geo::coarsen(latitude, longitude)),
+            LocationPrivacy::Committed => None,
+        }
+    }
+
+    // ========== Geospatial Functions ==========
+
+    /// Find active wastes whose current location is within `radius` metres
+    /// Optionally filtered by waste type
+    pub fn find_wastes_near(
+        env: Env,
+        latitude: i128,
+        longitude: i128,
+        radius: u64,
+        waste_type: Option<WasteType>,
+    ) -> Vec<u128> {
+        let mut results = Vec::new(&env);
+        for (row, col) in Self::search_cells(&env, latitude, longitude, radius).iter() {
+            let ids: Vec<u128> = env
+                .storage()
+                .instance()
+                .get(&("geo_wastes", row, col))
+                .unwrap_or(Vec::new(&env));
+
+            for waste_id in ids.iter() {
+                let Some(waste) = Self::get_waste_v2_internal(&env, waste_id) else {
+                    continue;
+                };
+                if !waste.is_active || waste_type.is_some_and(|t| t != waste.waste_type) {
+                    continue;
+                }
+
+                let (lat, lon) = Self::get_waste_location(env.clone(), waste_id);
+                if geo::distance(latitude, longitude, lat, lon) <= radius {
+                    results.push_back(waste_id);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Find registered participants within `radius` metres
+    /// Optionally filtered by role
+    pub fn find_participants_near(
+        env: Env,
+        latitude: i128,
+        longitude: i128,
+        radius: u64,
+        role: Option<ParticipantRole>,
+    ) -> Vec<Address> {
+        let mut results = Vec::new(&env);
+        for (row, col) in Self::search_cells(&env, latitude, longitude, radius).iter() {
+            let addresses: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&("geo_parts", row, col))
+                .unwrap_or(Vec::new(&env));
+
+            for address in addresses.iter() {
+                let Some(participant) = Self::get_participant(env.clone(), address.clone()) else {
+                    continue;
+                };
+                if !participant.is_registered || role.is_some_and(|r| r != participant.role) {
+                    continue;
+                }
+
+                let distance = geo::distance(
+                    latitude,
+                    longitude,
+                    participant.latitude,
+                    participant.longitude,
+                );
+                if distance <= radius {
+                    results.push_back(address);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get the current location of a waste
+    /// This is where it was last handed over, or where it was registered
+    pub fn get_waste_location(env: Env, waste_id: u128) -> (i128, i128) {
+        if let Some(location) = env.storage().instance().get(&("waste_location", waste_id)) {
+            return location;
+        }
+
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+        (waste.latitude, waste.longitude)
+    }
+
+    /// Grid cells a proximity query has to scan
+    fn search_cells(env: &Env, latitude: i128, longitude: i128, radius: u64) -> Vec<(i32, i32)> {
+        if radius > MAX_SEARCH_RADIUS {
+            panic!("Search radius too large");
+        }
+        Self::require_valid_coordinates(latitude, longitude);
+
+        let ((min_row, max_row), (min_col, max_col)) =
+            geo::cells_within(latitude, longitude, radius);
+        let mut cells = Vec::new(env);
+        for row in min_row..=max_row {
+            for col in min_col..=max_col {
+                cells.push_back((row, geo::wrap_column(col as i128) as i32));
+            }
+        }
+        cells
+    }
+
+    /// Move a waste to a new location in the geospatial index
+    fn index_waste(env: &Env, waste_id: u128, latitude: i128, longitude: i128) {
+        if let Some((lat, lon)) = env
+            .storage()
+            .instance()
+            .get::<_, (i128, i128)>(&("waste_location", waste_id))
+        {
+            Self::unindex_waste_at(env, waste_id, lat, lon);
+        }
+
+        let (row, col) = geo::cell_of(latitude, longitude);
+        let mut ids: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("geo_wastes", row, col))
+            .unwrap_or(Vec::new(env));
+        ids.push_back(waste_id);
+        env.storage().instance().set(&("geo_wastes", row, col), &ids);
+        env.storage()
+            .instance()
+            .set(&("waste_location", waste_id), &(latitude, longitude));
+    }
+
+    /// Remove a waste from the geospatial index
+    fn unindex_waste(env: &Env, waste_id: u128) {
+        if let Some((lat, lon)) = env
+            .storage()
+            .instance()
+            .get::<_, (i128, i128)>(&("waste_location", waste_id))
+        {
+            Self::unindex_waste_at(env, waste_id, lat, lon);
+        }
+    }
+
+    fn unindex_waste_at(env: &Env, waste_id: u128, latitude: i128, longitude: i128) {
+        let (row, col) = geo::cell_of(latitude, longitude);
+        let mut ids: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("geo_wastes", row, col))
+            .unwrap_or(Vec::new(env));
+        if let Some(index) = ids.first_index_of(waste_id) {
+            ids.remove(index);
+            env.storage().instance().set(&("geo_wastes", row, col), &ids);
+        }
+    }
+
+    /// Move a participant to a new location in the geospatial index
+    /// A `None` location leaves the participant out of the index
+    fn index_participant(
+        env: &Env,
+        address: &Address,
+        previous: Option<(i128, i128)>,
+        current: Option<(i128, i128)>,
+    ) {
+        if let Some((lat, lon)) = previous {
+            let (row, col) = geo::cell_of(lat, lon);
+            let mut addresses: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&("geo_parts", row, col))
+                .unwrap_or(Vec::new(env));
+            if let Some(index) = addresses.first_index_of(address) {
+                addresses.remove(index);
+                env.storage().instance().set(&("geo_parts", row, col), &addresses);
+            }
+        }
+
+        let Some((latitude, longitude)) = current else {
+            return;
+        };
+
+        let (row, col) = geo::cell_of(latitude, longitude);
+        let mut addresses: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&("geo_parts", row, col))
+            .unwrap_or(Vec::new(env));
+        addresses.push_back(address.clone());
+        env.storage().instance().set(&("geo_parts", row, col), &addresses);
+    }
+
     // ========== Waste Transfer History Functions ==========
 
     /// Get transfer history for a specific waste
@@ -862,11 +2406,13 @@ impl ScavengerContract {
         weight: u64,
         submitter: Address,
         description: String,
+        proof: Option<SubmissionProof>,
     ) -> Material {
         submitter.require_auth();
 
         // Validate submitter is registered
         Self::require_registered(&env, &submitter);
+        Self::require_plausible_weight(&env, waste_type, weight as u128);
 
         // Get next waste ID using the new storage system
         let waste_id = Self::next_waste_id(&env);
@@ -884,6 +2430,14 @@ impl ScavengerContract {
         // Store waste using the new storage systems
         Self::set_waste(&env, waste_id, &material);
 
+        if let Some(hash) = proof.as_ref().and_then(SubmissionProof::evidence) {
+            Self::record_evidence(&env, waste_id as u128, hash, EvidenceKind::Registration, &submitter);
+        }
+
+        if let Some(receipt) = proof.as_ref().and_then(SubmissionProof::receipt) {
+            Self::attest_weight(&env, waste_id as u128, &submitter, waste_type, weight as u128, None, &receipt);
+        }
+
         // Update stats
         let mut stats: RecyclingStats = env
             .storage()
@@ -911,6 +2465,7 @@ impl ScavengerContract {
         recycler: Address,
         latitude: i128,
         longitude: i128,
+        proof: Option<SubmissionProof>,
     ) -> u128 {
         recycler.require_auth();
 
@@ -918,6 +2473,17 @@ impl ScavengerContract {
             panic!("Participant not registered");
         }
 
+        Self::require_plausible_weight(&env, waste_type, weight);
+        Self::require_valid_coordinates(latitude, longitude);
+
+        // Recyclers keeping their location private only reveal the grid cell
+        // their waste is collected from
+        let (registered_lat, registered_lon) =
+            match Self::get_location_privacy(env.clone(), recycler.clone()) {
+                LocationPrivacy::Exact => (latitude, longitude),
+                _ => geo::coarsen(latitude, longitude),
+            };
+
         let waste_id = Self::next_waste_id(&env) as u128;
         let timestamp = env.ledger().timestamp();
 
@@ -926,8 +2492,8 @@ impl ScavengerContract {
             waste_type,
             weight,
             recycler.clone(),
-            latitude,
-            longitude,
+            registered_lat,
+            registered_lon,
             timestamp,
             true,
             false,
@@ -935,6 +2501,23 @@ impl ScavengerContract {
         );
 
         env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        Self::index_waste(&env, waste_id, registered_lat, registered_lon);
+
+        if let Some(hash) = proof.as_ref().and_then(SubmissionProof::evidence) {
+            Self::record_evidence(&env, waste_id, hash, EvidenceKind::Registration, &recycler);
+        }
+
+        if let Some(receipt) = proof.as_ref().and_then(SubmissionProof::receipt) {
+            Self::attest_weight(
+                &env,
+                waste_id,
+                &recycler,
+                waste_type,
+                weight,
+                Some((latitude, longitude)),
+                &receipt,
+            );
+        }
 
         let mut waste_list: Vec<u128> = env
             .storage()
@@ -953,8 +2536,8 @@ impl ScavengerContract {
             &recycler,
             waste_type,
             weight,
-            latitude,
-            longitude,
+            registered_lat,
+            registered_lon,
         );
 
         waste_id
@@ -968,8 +2551,24 @@ impl ScavengerContract {
         to: Address,
         latitude: i128,
         longitude: i128,
+        evidence: Option<Bytes>,
     ) -> WasteTransfer {
         from.require_auth();
+        Self::move_waste(env, waste_id, from, to, latitude, longitude, evidence)
+    }
+
+    /// Hand a waste over to a new owner once the sender has authorised it
+    fn move_waste(
+        env: Env,
+        waste_id: u128,
+        from: Address,
+        to: Address,
+        latitude: i128,
+        longitude: i128,
+        evidence: Option<Bytes>,
+    ) -> WasteTransfer {
+        Self::require_valid_coordinates(latitude, longitude);
+        Self::require_not_listed(&env, waste_id);
 
         let mut waste: types::Waste = env
             .storage()
@@ -989,9 +2588,27 @@ impl ScavengerContract {
             panic!("Invalid transfer");
         }
 
+        let receiver = Self::get_participant(env.clone(), to.clone()).expect("Receiver not registered");
+        let next_status = if receiver.role.can_manufacture() {
+            WasteStatus::Delivered
+        } else {
+            WasteStatus::Collected
+        };
+
+        Self::transition_waste(&env, &mut waste, next_status);
         waste.transfer_to(to.clone());
         env.storage().instance().set(&("waste_v2", waste_id), &waste);
 
+        // The receiver is accounted for hauling the waste from its last location
+        let (from_lat, from_lon) = Self::get_waste_location(env.clone(), waste_id);
+        let distance = geo::distance(from_lat, from_lon, latitude, longitude) as u128;
+        let co2e = Self::get_emission_factor(env.clone(), waste.waste_type)
+            .transport_for(waste.weight, distance);
+        Self::update_impact(&env, &to, Some(waste_id), |impact| {
+            impact.record_transport(distance, co2e)
+        });
+        Self::index_waste(&env, waste_id, latitude, longitude);
+
         let from_list: Vec<u128> = env
             .storage()
             .instance()
@@ -1018,7 +2635,7 @@ impl ScavengerContract {
             .set(&("participant_wastes", to.clone()), &to_list);
 
         let timestamp = env.ledger().timestamp();
-        let transfer = WasteTransfer::new(
+        let mut transfer = WasteTransfer::new(
             waste_id,
             from.clone(),
             to.clone(),
@@ -1028,6 +2645,11 @@ impl ScavengerContract {
             soroban_sdk::symbol_short!("transfer"),
         );
 
+        if let Some(hash) = evidence {
+            Self::record_evidence(&env, waste_id, hash.clone(), EvidenceKind::Transfer, &from);
+            transfer.evidence = Some(hash);
+        }
+
         let mut history: Vec<WasteTransfer> = env
             .storage()
             .instance()
@@ -1046,8 +2668,599 @@ impl ScavengerContract {
         transfer
     }
 
-    /// Transfer aggregated waste from collector to manufacturer
-    pub fn transfer_collected_waste(
+    // ========== Marketplace Functions ==========
+
+    /// Set the token marketplace prices and offers are denominated in (admin only)
+    pub fn set_market_token(env: Env, admin: Address, token: Address) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().set(&MARKET_TOKEN, &token);
+    }
+
+    /// Get the marketplace token, if configured
+    pub fn get_market_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&MARKET_TOKEN)
+    }
+
+    /// List an owned waste for sale at an asking price for `duration` seconds
+    /// The waste cannot be transferred or split while the listing is open
+    pub fn create_listing(
+        env: Env,
+        seller: Address,
+        waste_id: u128,
+        price: i128,
+        duration: u64,
+    ) -> Listing {
+        seller.require_auth();
+        Self::market_token(&env);
+
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+        if waste.current_owner != seller {
+            panic!("Caller does not own waste");
+        }
+        if !waste.is_active {
+            panic!("Cannot list deactivated waste");
+        }
+        Self::require_not_listed(&env, waste_id);
+
+        if price <= 0 {
+            panic!("Price must be greater than zero");
+        }
+        if duration == 0 {
+            panic!("Duration must be greater than zero");
+        }
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&("listing_count",))
+            .unwrap_or(0);
+        let listing_id = count + 1;
+        env.storage().instance().set(&("listing_count",), &listing_id);
+
+        let (latitude, longitude) = Self::get_waste_location(env.clone(), waste_id);
+        let timestamp = env.ledger().timestamp();
+        let listing = Listing {
+            listing_id,
+            waste_id,
+            seller: seller.clone(),
+            waste_type: waste.waste_type,
+            price,
+            latitude,
+            longitude,
+            status: ListingStatus::Open,
+            created_at: timestamp,
+            expires_at: timestamp + duration,
+        };
+
+        env.storage().instance().set(&("listing", listing_id), &listing);
+        env.storage()
+            .instance()
+            .set(&("waste_listing", waste_id), &listing_id);
+
+        let key = ("type_listings", waste.waste_type);
+        let mut ids: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        ids.push_back(listing_id);
+        env.storage().instance().set(&key, &ids);
+
+        events::emit_listing_created(&env, listing_id, waste_id, &seller, price);
+
+        listing
+    }
+
+    /// Withdraw an open listing (seller only); pending offers are refunded
+    pub fn cancel_listing(env: Env, seller: Address, listing_id: u64) -> Listing {
+        seller.require_auth();
+
+        let mut listing = Self::get_listing(env.clone(), listing_id).expect("Listing not found");
+        if listing.seller != seller {
+            panic!("Only the seller can cancel");
+        }
+        if listing.status != ListingStatus::Open {
+            panic!("Listing is not open");
+        }
+
+        Self::close_listing(&env, &mut listing, ListingStatus::Cancelled);
+        listing
+    }
+
+    /// Close a listing whose expiry has passed; anyone may call this
+    /// Pending offers are refunded
+    pub fn expire_listing(env: Env, listing_id: u64) -> Listing {
+        let mut listing = Self::get_listing(env.clone(), listing_id).expect("Listing not found");
+        if listing.status != ListingStatus::Open {
+            panic!("Listing is not open");
+        }
+        if env.ledger().timestamp() < listing.expires_at {
+            panic!("Listing has not expired");
+        }
+
+        Self::close_listing(&env, &mut listing, ListingStatus::Expired);
+        listing
+    }
+
+    /// Offer to buy a listed waste, escrowing `amount` of the marketplace token
+    /// Only participants the seller could transfer the waste to may bid
+    pub fn place_offer(
+        env: Env,
+        buyer: Address,
+        listing_id: u64,
+        amount: i128,
+        duration: u64,
+    ) -> Offer {
+        buyer.require_auth();
+
+        let listing = Self::get_listing(env.clone(), listing_id).expect("Listing not found");
+        let timestamp = env.ledger().timestamp();
+        if !listing.is_open_at(timestamp) {
+            panic!("Listing is not open");
+        }
+        if !Self::is_valid_transfer(env.clone(), listing.seller.clone(), buyer.clone()) {
+            panic!("Invalid transfer");
+        }
+        if amount <= 0 {
+            panic!("Offer amount must be greater than zero");
+        }
+        if duration == 0 {
+            panic!("Duration must be greater than zero");
+        }
+
+        let token = token::Client::new(&env, &Self::market_token(&env));
+        token.transfer(&buyer, &env.current_contract_address(), &amount);
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&("offer_count",))
+            .unwrap_or(0);
+        let offer_id = count + 1;
+        env.storage().instance().set(&("offer_count",), &offer_id);
+
+        let offer = Offer {
+            offer_id,
+            listing_id,
+            buyer: buyer.clone(),
+            amount,
+            status: OfferStatus::Pending,
+            created_at: timestamp,
+            expires_at: timestamp + duration,
+        };
+        env.storage().instance().set(&("offer", offer_id), &offer);
+
+        let key = ("listing_offers", listing_id);
+        let mut ids: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        ids.push_back(offer_id);
+        env.storage().instance().set(&key, &ids);
+
+        events::emit_offer_placed(&env, offer_id, listing_id, &buyer, amount);
+
+        offer
+    }
+
+    /// Withdraw a pending offer and return its escrowed payment (buyer only)
+    pub fn withdraw_offer(env: Env, buyer: Address, offer_id: u64) -> Offer {
+        buyer.require_auth();
+
+        let mut offer = Self::get_offer(env.clone(), offer_id).expect("Offer not found");
+        if offer.buyer != buyer {
+            panic!("Only the buyer can withdraw");
+        }
+        if offer.status != OfferStatus::Pending {
+            panic!("Offer is not pending");
+        }
+
+        Self::refund_offer(&env, &mut offer, OfferStatus::Withdrawn);
+        offer
+    }
+
+    /// Accept an offer (seller only)
+    /// Pays the seller from escrow and hands the waste to the buyer at the
+    /// listing location in the same transaction; other offers are refunded
+    pub fn accept_offer(env: Env, seller: Address, offer_id: u64) -> WasteTransfer {
+        seller.require_auth();
+
+        let mut offer = Self::get_offer(env.clone(), offer_id).expect("Offer not found");
+        let mut listing =
+            Self::get_listing(env.clone(), offer.listing_id).expect("Listing not found");
+        if listing.seller != seller {
+            panic!("Only the seller can accept");
+        }
+
+        let timestamp = env.ledger().timestamp();
+        if !listing.is_open_at(timestamp) {
+            panic!("Listing is not open");
+        }
+        if !offer.is_live_at(timestamp) {
+            panic!("Offer is not live");
+        }
+
+        offer.status = OfferStatus::Accepted;
+        env.storage().instance().set(&("offer", offer_id), &offer);
+        Self::close_listing(&env, &mut listing, ListingStatus::Sold);
+
+        let token = token::Client::new(&env, &Self::market_token(&env));
+        token.transfer(&env.current_contract_address(), &seller, &offer.amount);
+
+        Self::move_waste(
+            env,
+            listing.waste_id,
+            seller,
+            offer.buyer,
+            listing.latitude,
+            listing.longitude,
+            None,
+        )
+    }
+
+    /// Get a listing by ID
+    pub fn get_listing(env: Env, listing_id: u64) -> Option<Listing> {
+        env.storage().instance().get(&("listing", listing_id))
+    }
+
+    /// Get an offer by ID
+    pub fn get_offer(env: Env, offer_id: u64) -> Option<Offer> {
+        env.storage().instance().get(&("offer", offer_id))
+    }
+
+    /// Get every offer placed on a listing, in the order they were made
+    pub fn get_listing_offers(env: Env, listing_id: u64) -> Vec<Offer> {
+        let ids: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&("listing_offers", listing_id))
+            .unwrap_or(Vec::new(&env));
+
+        let mut offers = Vec::new(&env);
+        for offer_id in ids.iter() {
+            if let Some(offer) = Self::get_offer(env.clone(), offer_id) {
+                offers.push_back(offer);
+            }
+        }
+        offers
+    }
+
+    /// Get the open listings for a waste type
+    pub fn get_listings_by_type(env: Env, waste_type: WasteType) -> Vec<Listing> {
+        let ids: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&("type_listings", waste_type))
+            .unwrap_or(Vec::new(&env));
+
+        let timestamp = env.ledger().timestamp();
+        let mut listings = Vec::new(&env);
+        for listing_id in ids.iter() {
+            if let Some(listing) = Self::get_listing(env.clone(), listing_id) {
+                if listing.is_open_at(timestamp) {
+                    listings.push_back(listing);
+                }
+            }
+        }
+        listings
+    }
+
+    /// Find open listings within `radius` metres, optionally of one waste type
+    pub fn find_listings_near(
+        env: Env,
+        latitude: i128,
+        longitude: i128,
+        radius: u64,
+        waste_type: Option<WasteType>,
+    ) -> Vec<Listing> {
+        let timestamp = env.ledger().timestamp();
+        let mut listings = Vec::new(&env);
+        let wastes = Self::find_wastes_near(env.clone(), latitude, longitude, radius, waste_type);
+        for waste_id in wastes.iter() {
+            let Some(listing_id) = env
+                .storage()
+                .instance()
+                .get::<_, u64>(&("waste_listing", waste_id))
+            else {
+                continue;
+            };
+            if let Some(listing) = Self::get_listing(env.clone(), listing_id) {
+                if listing.is_open_at(timestamp) {
+                    listings.push_back(listing);
+                }
+            }
+        }
+        listings
+    }
+
+    /// The configured marketplace token
+    fn market_token(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&MARKET_TOKEN)
+            .expect("Market token not set")
+    }
+
+    /// Reject changes to a waste that is listed for sale
+    fn require_not_listed(env: &Env, waste_id: u128) {
+        if env.storage().instance().has(&("waste_listing", waste_id)) {
+            panic!("Waste is listed for sale");
+        }
+    }
+
+    /// Close a listing, releasing its waste and refunding pending offers
+    fn close_listing(env: &Env, listing: &mut Listing, status: ListingStatus) {
+        listing.status = status;
+        env.storage()
+            .instance()
+            .set(&("listing", listing.listing_id), listing);
+        env.storage()
+            .instance()
+            .remove(&("waste_listing", listing.waste_id));
+
+        let key = ("type_listings", listing.waste_type);
+        let mut ids: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        if let Some(index) = ids.first_index_of(listing.listing_id) {
+            ids.remove(index);
+            env.storage().instance().set(&key, &ids);
+        }
+
+        for mut offer in Self::get_listing_offers(env.clone(), listing.listing_id).iter() {
+            if offer.status == OfferStatus::Pending {
+                Self::refund_offer(env, &mut offer, OfferStatus::Refunded);
+            }
+        }
+
+        events::emit_listing_closed(env, listing.listing_id, status);
+    }
+
+    /// Return an offer's escrowed payment to the buyer
+    fn refund_offer(env: &Env, offer: &mut Offer, status: OfferStatus) {
+        let token = token::Client::new(env, &Self::market_token(env));
+        token.transfer(&env.current_contract_address(), &offer.buyer, &offer.amount);
+
+        offer.status = status;
+        env.storage().instance().set(&("offer", offer.offer_id), offer);
+    }
+
+    // ========== Buy Order Functions ==========
+
+    /// Post standing demand for up to `quantity` grams of a waste type at
+    /// `price_per_kg`, escrowing the full order value in the marketplace token
+    /// A non-zero `radius` limits fills to wastes within that many metres of
+    /// the given location
+    pub fn post_buy_order(env: Env, buyer: Address, terms: BuyOrderTerms) -> BuyOrder {
+        let BuyOrderTerms {
+            waste_type,
+            quantity,
+            price_per_kg,
+            latitude,
+            longitude,
+            radius,
+            duration,
+        } = terms;
+        buyer.require_auth();
+        Self::require_waste_category(&env, waste_type);
+
+        let participant: Participant = env
+            .storage()
+            .instance()
+            .get(&(buyer.clone(),))
+            .expect("Participant not found");
+        if !participant.is_registered {
+            panic!("Participant is not registered");
+        }
+        if participant.role != ParticipantRole::Manufacturer {
+            panic!("Only manufacturers can post buy orders");
+        }
+
+        if radius > 0 {
+            Self::require_valid_coordinates(latitude, longitude);
+        }
+        if quantity == 0 {
+            panic!("Quantity must be greater than zero");
+        }
+        if price_per_kg <= 0 {
+            panic!("Price must be greater than zero");
+        }
+        if duration == 0 {
+            panic!("Duration must be greater than zero");
+        }
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&("buy_order_count",))
+            .unwrap_or(0);
+        let order_id = count + 1;
+
+        let timestamp = env.ledger().timestamp();
+        let mut order = BuyOrder {
+            order_id,
+            buyer: buyer.clone(),
+            waste_type,
+            quantity_remaining: quantity,
+            price_per_kg,
+            latitude,
+            longitude,
+            radius,
+            escrow: 0,
+            status: BuyOrderStatus::Open,
+            created_at: timestamp,
+            expires_at: timestamp + duration,
+        };
+        order.escrow = order.cost_for(quantity);
+        if order.escrow <= 0 {
+            panic!("Order value must be greater than zero");
+        }
+
+        let token = token::Client::new(&env, &Self::market_token(&env));
+        token.transfer(&buyer, &env.current_contract_address(), &order.escrow);
+
+        env.storage().instance().set(&("buy_order_count",), &order_id);
+        env.storage().instance().set(&("buy_order", order_id), &order);
+
+        let key = ("type_buy_orders", waste_type);
+        let mut ids: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        ids.push_back(order_id);
+        env.storage().instance().set(&key, &ids);
+
+        events::emit_buy_order_posted(&env, order_id, &buyer, waste_type, quantity, price_per_kg);
+
+        order
+    }
+
+    /// Fill a buy order with an owned, confirmed waste
+    /// Pays the seller from escrow, hands the waste to the buyer where it lies
+    /// and depletes the order, all in one call
+    pub fn fill_buy_order(
+        env: Env,
+        seller: Address,
+        order_id: u64,
+        waste_id: u128,
+    ) -> WasteTransfer {
+        seller.require_auth();
+
+        let mut order = Self::get_buy_order(env.clone(), order_id).expect("Buy order not found");
+        if !order.is_open_at(env.ledger().timestamp()) {
+            panic!("Buy order is not open");
+        }
+
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+        if waste.current_owner != seller {
+            panic!("Caller does not own waste");
+        }
+        if !waste.is_active {
+            panic!("Cannot sell deactivated waste");
+        }
+        if !waste.is_confirmed {
+            panic!("Waste is not confirmed");
+        }
+        if waste.waste_type != order.waste_type {
+            panic!("Waste type does not match order");
+        }
+        if waste.weight > order.quantity_remaining {
+            panic!("Waste exceeds remaining quantity");
+        }
+
+        let (latitude, longitude) = Self::get_waste_location(env.clone(), waste_id);
+        if !order.covers(latitude, longitude) {
+            panic!("Waste is outside the order region");
+        }
+        if !Self::is_valid_transfer(env.clone(), seller.clone(), order.buyer.clone()) {
+            panic!("Invalid transfer");
+        }
+
+        // Rounding up each fill can overshoot the escrow by a unit on the last one
+        let payment = order.cost_for(waste.weight).min(order.escrow);
+        order.escrow -= payment;
+        order.quantity_remaining -= waste.weight;
+
+        if order.quantity_remaining == 0 {
+            Self::close_buy_order(&env, &mut order, BuyOrderStatus::Filled);
+        } else {
+            env.storage().instance().set(&("buy_order", order_id), &order);
+        }
+
+        let token = token::Client::new(&env, &Self::market_token(&env));
+        token.transfer(&env.current_contract_address(), &seller, &payment);
+
+        events::emit_buy_order_filled(&env, order_id, waste_id, &seller, waste.weight, payment);
+
+        Self::move_waste(env, waste_id, seller, order.buyer, latitude, longitude, None)
+    }
+
+    /// Withdraw an open buy order and recover its escrow (buyer only)
+    pub fn cancel_buy_order(env: Env, buyer: Address, order_id: u64) -> BuyOrder {
+        buyer.require_auth();
+
+        let mut order = Self::get_buy_order(env.clone(), order_id).expect("Buy order not found");
+        if order.buyer != buyer {
+            panic!("Only the buyer can cancel");
+        }
+        if order.status != BuyOrderStatus::Open {
+            panic!("Buy order is not open");
+        }
+
+        Self::close_buy_order(&env, &mut order, BuyOrderStatus::Cancelled);
+        order
+    }
+
+    /// Close a buy order whose expiry has passed and refund the buyer
+    /// Anyone may call this
+    pub fn expire_buy_order(env: Env, order_id: u64) -> BuyOrder {
+        let mut order = Self::get_buy_order(env.clone(), order_id).expect("Buy order not found");
+        if order.status != BuyOrderStatus::Open {
+            panic!("Buy order is not open");
+        }
+        if env.ledger().timestamp() < order.expires_at {
+            panic!("Buy order has not expired");
+        }
+
+        Self::close_buy_order(&env, &mut order, BuyOrderStatus::Expired);
+        order
+    }
+
+    /// Get a buy order by ID
+    pub fn get_buy_order(env: Env, order_id: u64) -> Option<BuyOrder> {
+        env.storage().instance().get(&("buy_order", order_id))
+    }
+
+    /// Get the open buy orders for a waste type, oldest first
+    pub fn get_buy_orders_by_type(env: Env, waste_type: WasteType) -> Vec<BuyOrder> {
+        let ids: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&("type_buy_orders", waste_type))
+            .unwrap_or(Vec::new(&env));
+
+        let timestamp = env.ledger().timestamp();
+        let mut orders = Vec::new(&env);
+        for order_id in ids.iter() {
+            if let Some(order) = Self::get_buy_order(env.clone(), order_id) {
+                if order.is_open_at(timestamp) {
+                    orders.push_back(order);
+                }
+            }
+        }
+        orders
+    }
+
+    /// Get the open buy orders a waste could fill as it stands
+    pub fn get_matching_buy_orders(env: Env, waste_id: u128) -> Vec<BuyOrder> {
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+        let (latitude, longitude) = Self::get_waste_location(env.clone(), waste_id);
+
+        let mut orders = Vec::new(&env);
+        for order in Self::get_buy_orders_by_type(env.clone(), waste.waste_type).iter() {
+            if waste.weight <= order.quantity_remaining
+                && order.covers(latitude, longitude)
+                && order.buyer != waste.current_owner
+            {
+                orders.push_back(order);
+            }
+        }
+        orders
+    }
+
+    /// Close a buy order, refunding any escrow left over
+    fn close_buy_order(env: &Env, order: &mut BuyOrder, status: BuyOrderStatus) {
+        if order.escrow > 0 {
+            let token = token::Client::new(env, &Self::market_token(env));
+            token.transfer(&env.current_contract_address(), &order.buyer, &order.escrow);
+            order.escrow = 0;
+        }
+
+        order.status = status;
+        env.storage()
+            .instance()
+            .set(&("buy_order", order.order_id), order);
+
+        let key = ("type_buy_orders", order.waste_type);
+        let mut ids: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        if let Some(index) = ids.first_index_of(order.order_id) {
+            ids.remove(index);
+            env.storage().instance().set(&key, &ids);
+        }
+
+        events::emit_buy_order_closed(env, order.order_id, status);
+    }
+
+    /// Transfer aggregated waste from collector to manufacturer
+    pub fn transfer_collected_waste(
         env: Env,
         waste_type: WasteType,
         collector: Address,
@@ -1057,6 +3270,7 @@ impl ScavengerContract {
         notes: soroban_sdk::Symbol,
     ) -> u128 {
         collector.require_auth();
+        Self::require_valid_coordinates(latitude, longitude);
 
         let collector_key = (collector.clone(),);
         let collector_participant: Participant = env
@@ -1083,7 +3297,7 @@ impl ScavengerContract {
         let waste_id = Self::next_waste_id(&env) as u128;
         let timestamp = env.ledger().timestamp();
 
-        let waste = types::Waste::new(
+        let mut waste = types::Waste::new(
             waste_id,
             waste_type,
             0,
@@ -1095,8 +3309,10 @@ impl ScavengerContract {
             false,
             manufacturer.clone(),
         );
+        waste.status = WasteStatus::Delivered;
 
         env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        Self::index_waste(&env, waste_id, latitude, longitude);
 
         let mut manufacturer_list: Vec<u128> = env
             .storage()
@@ -1136,102 +3352,951 @@ impl ScavengerContract {
         waste_id
     }
 
-    /// Confirm waste details
-    pub fn confirm_waste_details(
+    /// Record the weight measured by the receiver of the latest transfer
+    /// The discrepancy is computed against the prior hop's weight and the
+    /// waste's weight is updated to the agreed weight
+    pub fn record_handover_weight(
         env: Env,
         waste_id: u128,
-        confirmer: Address,
-    ) -> types::Waste {
-        confirmer.require_auth();
+        receiver: Address,
+        measured_weight: u128,
+    ) -> WasteTransfer {
+        receiver.require_auth();
 
-        let mut waste: types::Waste = env
-            .storage()
-            .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+        if measured_weight == 0 {
+            panic!("Measured weight must be greater than zero");
+        }
+
+        let mut waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+        if waste.current_owner != receiver {
+            panic!("Caller does not own waste");
+        }
+
+        if !waste.is_active {
+            panic!("Cannot measure deactivated waste");
+        }
+
+        Self::require_not_listed(&env, waste_id);
+
+        if Self::is_waste_credited(env.clone(), waste_id) {
+            panic!("Cannot measure credited waste");
+        }
+
+        let key = ("transfer_history", waste_id);
+        let mut history: Vec<WasteTransfer> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        let index = history.len().checked_sub(1).expect("No transfer to measure");
+        let mut transfer = history.get(index).unwrap();
+
+        if transfer.to != receiver {
+            panic!("No transfer to measure");
+        }
+        if transfer.is_measured() {
+            panic!("Handover weight already recorded");
+        }
+
+        let prior_weight = waste.weight;
+        let tolerance = Self::get_weight_tolerance(env.clone(), waste.waste_type);
+        transfer.record_measurement(prior_weight, measured_weight, tolerance);
+        history.set(index, transfer.clone());
+        env.storage().instance().set(&key, &history);
+
+        waste.weight = transfer.agreed_weight(prior_weight);
+        Self::set_waste_v2(&env, &waste);
+
+        // A device receipt only vouches for the weight it signed
+        if waste.weight != prior_weight {
+            env.storage().instance().remove(&("weight_attested", waste_id));
+        }
+
+        if transfer.flagged {
+            let mut flagged: Vec<u128> = env
+                .storage()
+                .instance()
+                .get(&("flagged_wastes",))
+                .unwrap_or(Vec::new(&env));
+            if !flagged.contains(waste_id) {
+                flagged.push_back(waste_id);
+                env.storage().instance().set(&("flagged_wastes",), &flagged);
+            }
+
+            events::emit_weight_discrepancy(
+                &env,
+                waste_id,
+                &receiver,
+                prior_weight,
+                measured_weight,
+            );
+        }
+
+        transfer
+    }
+
+    /// Set the allowed handover weight discrepancy for a waste type (admin only)
+    pub fn set_weight_tolerance(env: Env, admin: Address, waste_type: WasteType, tolerance_bps: u32) {
+        Self::require_admin(&env, &admin);
+
+        if tolerance_bps > 10_000 {
+            panic!("Tolerance cannot exceed 10000 basis points");
+        }
+
+        env.storage()
+            .instance()
+            .set(&("weight_tolerance", waste_type), &tolerance_bps);
+    }
+
+    /// Get the allowed handover weight discrepancy for a waste type in basis points
+    pub fn get_weight_tolerance(env: Env, waste_type: WasteType) -> u32 {
+        env.storage()
+            .instance()
+            .get(&("weight_tolerance", waste_type))
+            .unwrap_or(DEFAULT_WEIGHT_TOLERANCE_BPS)
+    }
+
+    /// Get all wastes with at least one handover outside tolerance
+    pub fn get_flagged_wastes(env: Env) -> Vec<u128> {
+        env.storage()
+            .instance()
+            .get(&("flagged_wastes",))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Split a waste lot into child lots whose weights sum to the parent's
+    /// Children inherit the parent's provenance and reference it for their
+    /// earlier transfer history, and the parent is retired once split
+    pub fn split_waste(
+        env: Env,
+        waste_id: u128,
+        owner: Address,
+        weights: Vec<u128>,
+    ) -> Vec<u128> {
+        owner.require_auth();
+
+        let mut parent = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+
+        if parent.current_owner != owner {
+            panic!("Caller does not own waste");
+        }
+
+        if !parent.is_active {
+            panic!("Cannot split deactivated waste");
+        }
+
+        Self::require_not_listed(&env, waste_id);
+
+        if Self::is_waste_credited(env.clone(), waste_id) {
+            panic!("Cannot split credited waste");
+        }
+
+        if weights.len() < 2 {
+            panic!("Split requires at least two parts");
+        }
+
+        let mut total: u128 = 0;
+        for weight in weights.iter() {
+            if weight == 0 {
+                panic!("Split weight must be greater than zero");
+            }
+            total = total.checked_add(weight).expect("Overflow in split weight");
+        }
+
+        if total != parent.weight {
+            panic!("Split weights must sum to waste weight");
+        }
+
+        let confirmers = Self::get_waste_confirmers(env.clone(), waste_id);
+        let (latitude, longitude) = Self::get_waste_location(env.clone(), waste_id);
+
+        let mut owner_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", owner.clone()))
+            .unwrap_or(Vec::new(&env));
+        if let Some(index) = owner_list.first_index_of(waste_id) {
+            owner_list.remove(index);
+        }
+
+        let mut child_ids = Vec::new(&env);
+        for weight in weights.iter() {
+            let child_id = Self::next_waste_id(&env) as u128;
+            let child = parent.split_child(child_id, weight);
+            Self::set_waste_v2(&env, &child);
+            Self::index_waste(&env, child_id, latitude, longitude);
+
+            // The child's custody chain continues from the parent's
+            env.storage()
+                .instance()
+                .set(&("waste_parent", child_id), &waste_id);
+            env.storage()
+                .instance()
+                .set(&("waste_confirmers", child_id), &confirmers);
+
+            owner_list.push_back(child_id);
+            child_ids.push_back(child_id);
+        }
+
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", owner.clone()), &owner_list);
+        env.storage()
+            .instance()
+            .set(&("waste_children", waste_id), &child_ids);
+
+        Self::transition_waste(&env, &mut parent, WasteStatus::Deactivated);
+        Self::set_waste_v2(&env, &parent);
+        Self::unindex_waste(&env, waste_id);
+
+        events::emit_waste_split(&env, waste_id, &owner, &child_ids);
+
+        child_ids
+    }
+
+    /// Get the IDs of the child lots a waste was split into
+    pub fn get_waste_children(env: Env, waste_id: u128) -> Vec<u128> {
+        env.storage()
+            .instance()
+            .get(&("waste_children", waste_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the ID of the waste a child lot was split from
+    /// Returns None if the waste was not created by a split
+    pub fn get_waste_parent(env: Env, waste_id: u128) -> Option<u128> {
+        env.storage().instance().get(&("waste_parent", waste_id))
+    }
+
+    /// Tag a waste with a sub-category such as a resin code, glass colour or
+    /// metal alloy (owner only)
+    /// The sub-type is fixed once the waste has been confirmed
+    pub fn set_waste_sub_type(
+        env: Env,
+        waste_id: u128,
+        owner: Address,
+        sub_type: WasteSubType,
+    ) -> Waste {
+        owner.require_auth();
+
+        let mut waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+
+        if waste.current_owner != owner {
+            panic!("Caller does not own waste");
+        }
+
+        if !waste.is_active {
+            panic!("Waste is not active");
+        }
+
+        if waste.is_confirmed {
+            panic!("Cannot change sub-type of confirmed waste");
+        }
+
+        if !sub_type.applies_to(waste.waste_type) {
+            panic!("Sub-type does not match waste type");
+        }
+
+        waste.sub_type = sub_type;
+        Self::set_waste_v2(&env, &waste);
+
+        waste
+    }
+
+    /// Confirm waste details
+    pub fn confirm_waste_details(
+        env: Env,
+        waste_id: u128,
+        confirmer: Address,
+        evidence: Option<Bytes>,
+    ) -> types::Waste {
+        confirmer.require_auth();
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if !waste.is_active {
+            panic!("Cannot confirm deactivated waste");
+        }
+
+        if waste.current_owner == confirmer {
+            panic!("Owner cannot confirm own waste");
+        }
+
+        if waste.is_confirmed {
+            panic!("Waste already confirmed");
+        }
+
+        let mut confirmers = Self::get_waste_confirmers(env.clone(), waste_id);
+        if confirmers.contains(&confirmer) {
+            panic!("Confirmer already confirmed waste");
+        }
+
+        let rule = Self::quorum_for(&env, waste.waste_type, waste.weight);
+        let required = rule.as_ref().map(|r| r.required).unwrap_or(1);
+
+        if let Some(rule) = rule {
+            // Quorum confirmers must be independent of the waste's custody chain
+            let history = Self::get_transfer_history_v2(env.clone(), waste_id);
+            for transfer in history.iter() {
+                if transfer.from == confirmer || transfer.to == confirmer {
+                    panic!("Confirmer has custody relation to waste");
+                }
+            }
+
+            if rule.distinct_roles {
+                let role = Self::get_participant(env.clone(), confirmer.clone())
+                    .expect("Confirmer not registered")
+                    .role;
+                for existing in confirmers.iter() {
+                    let existing_role = Self::get_participant(env.clone(), existing)
+                        .expect("Confirmer not registered")
+                        .role;
+                    if existing_role == role {
+                        panic!("Confirmer role already represented");
+                    }
+                }
+            }
+        }
+
+        confirmers.push_back(confirmer.clone());
+        env.storage()
+            .instance()
+            .set(&("waste_confirmers", waste_id), &confirmers);
+
+        if let Some(hash) = evidence {
+            Self::record_evidence(&env, waste_id, hash, EvidenceKind::Confirmation, &confirmer);
+        }
+
+        events::emit_confirmation_added(&env, waste_id, &confirmer, confirmers.len(), required);
+
+        if confirmers.len() >= required {
+            waste.confirm(confirmer.clone());
+            env.storage().instance().set(&("waste_v2", waste_id), &waste);
+            events::emit_waste_confirmed(&env, waste_id, &confirmer);
+        }
+
+        waste
+    }
+
+    /// Get every address that has confirmed a waste towards its quorum
+    pub fn get_waste_confirmers(env: Env, waste_id: u128) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&("waste_confirmers", waste_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Configure the confirmation quorum for a waste type and weight band (admin only)
+    /// The band runs from `min_weight` up to the next configured band
+    pub fn set_confirmation_quorum(
+        env: Env,
+        admin: Address,
+        waste_type: WasteType,
+        min_weight: u128,
+        required: u32,
+        distinct_roles: bool,
+    ) {
+        Self::require_admin(&env, &admin);
+
+        if required == 0 {
+            panic!("Quorum must require at least one confirmer");
+        }
+
+        // One confirmer per role: Recycler, Collector, Manufacturer
+        if distinct_roles && required > 3 {
+            panic!("Quorum exceeds number of distinct roles");
+        }
+
+        let rule = QuorumRule {
+            waste_type,
+            min_weight,
+            required,
+            distinct_roles,
+        };
+
+        // Keep bands sorted by weight so lookups can stop at the first miss
+        let key = ("quorum_rules", waste_type);
+        let rules: Vec<QuorumRule> = env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        let mut updated = Vec::new(&env);
+        let mut inserted = false;
+        for existing in rules.iter() {
+            if existing.min_weight == min_weight {
+                continue;
+            }
+            if !inserted && existing.min_weight > min_weight {
+                updated.push_back(rule.clone());
+                inserted = true;
+            }
+            updated.push_back(existing);
+        }
+        if !inserted {
+            updated.push_back(rule);
+        }
+        env.storage().instance().set(&key, &updated);
+    }
+
+    /// Remove the quorum configured for a waste type and weight band (admin only)
+    pub fn remove_confirmation_quorum(
+        env: Env,
+        admin: Address,
+        waste_type: WasteType,
+        min_weight: u128,
+    ) {
+        Self::require_admin(&env, &admin);
+
+        let key = ("quorum_rules", waste_type);
+        let mut rules: Vec<QuorumRule> = env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        let index = rules
+            .iter()
+            .position(|r| r.min_weight == min_weight)
+            .expect("Quorum not found");
+        rules.remove(index as u32);
+        env.storage().instance().set(&key, &rules);
+    }
+
+    /// Get all quorum bands configured for a waste type, lightest first
+    pub fn get_confirmation_quorums(env: Env, waste_type: WasteType) -> Vec<QuorumRule> {
+        env.storage()
+            .instance()
+            .get(&("quorum_rules", waste_type))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the number of confirmations a waste needs to count as confirmed
+    pub fn get_required_confirmations(env: Env, waste_id: u128) -> u32 {
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+        Self::quorum_for(&env, waste.waste_type, waste.weight)
+            .map(|r| r.required)
+            .unwrap_or(1)
+    }
+
+    /// Find the heaviest quorum band that applies to the given weight
+    fn quorum_for(env: &Env, waste_type: WasteType, weight: u128) -> Option<QuorumRule> {
+        let rules: Vec<QuorumRule> = env
+            .storage()
+            .instance()
+            .get(&("quorum_rules", waste_type))
+            .unwrap_or(Vec::new(env));
+
+        let mut applicable = None;
+        for rule in rules.iter() {
+            if rule.min_weight > weight {
+                break;
+            }
+            applicable = Some(rule);
+        }
+        applicable
+    }
+
+    /// Reset waste confirmation status
+    /// Only the waste owner can reset the confirmation
+    pub fn reset_waste_confirmation(
+        env: Env,
+        waste_id: u128,
+        owner: Address,
+    ) -> types::Waste {
+        owner.require_auth();
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if waste.current_owner != owner {
+            panic!("Only owner can reset confirmation");
+        }
+
+        if !waste.is_confirmed {
+            panic!("Waste is not confirmed");
+        }
+
+        waste.reset_confirmation();
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        env.storage()
+            .instance()
+            .remove(&("waste_confirmers", waste_id));
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("reset"), waste_id),
+            (owner, env.ledger().timestamp()),
+        );
+
+        waste
+    }
+
+    /// Deactivate a waste record (admin only)
+    /// Deactivated waste cannot be queried or reactivated
+    pub fn deactivate_waste(
+        env: Env,
+        waste_id: u128,
+        admin: Address,
+    ) -> types::Waste {
+        Self::require_admin(&env, &admin);
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste not found");
+
+        if !waste.is_active {
+            panic!("Waste already deactivated");
+        }
+
+        Self::transition_waste(&env, &mut waste, WasteStatus::Deactivated);
+        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        Self::unindex_waste(&env, waste_id);
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("deactive"), waste_id),
+            (admin, env.ledger().timestamp()),
+        );
+
+        waste
+    }
+
+    /// Mark delivered waste as processed (owning manufacturer only)
+    /// Records the recycling timestamp on the waste
+    pub fn mark_processed(env: Env, waste_id: u128, manufacturer: Address) -> Waste {
+        manufacturer.require_auth();
+
+        let mut waste = Self::require_manufacturer_owned(&env, waste_id, &manufacturer);
+
+        Self::transition_waste(&env, &mut waste, WasteStatus::Processed);
+        waste.mark_recycled(env.ledger().timestamp());
+        Self::set_waste_v2(&env, &waste);
+
+        // Avoided emissions are credited to whoever first handed the waste on
+        let recycler = Self::get_transfer_history_v2(env.clone(), waste_id)
+            .first()
+            .map(|transfer| transfer.from)
+            .unwrap_or(manufacturer);
+        let co2e = Self::get_emission_factor(env.clone(), waste.waste_type).avoided_for(waste.weight);
+        Self::update_impact(&env, &recycler, Some(waste_id), |impact| {
+            impact.record_avoided(waste.weight, co2e)
+        });
+
+        waste
+    }
+
+    /// Reject delivered waste that does not meet requirements (owning manufacturer only)
+    pub fn reject_waste(env: Env, waste_id: u128, manufacturer: Address) -> Waste {
+        manufacturer.require_auth();
+
+        let mut waste = Self::require_manufacturer_owned(&env, waste_id, &manufacturer);
+
+        Self::transition_waste(&env, &mut waste, WasteStatus::Rejected);
+        Self::set_waste_v2(&env, &waste);
+
+        waste
+    }
+
+    /// Load a waste and check it is owned by the given registered manufacturer
+    fn require_manufacturer_owned(env: &Env, waste_id: u128, manufacturer: &Address) -> Waste {
+        if !Self::can_manufacture(env.clone(), manufacturer.clone()) {
+            panic!("Only manufacturers can perform this action");
+        }
+
+        let waste = Self::get_waste_v2_internal(env, waste_id).expect("Waste not found");
+
+        if waste.current_owner != *manufacturer {
+            panic!("Caller does not own waste");
+        }
+
+        waste
+    }
+
+    /// Record a product batch made from processed waste (manufacturer only)
+    /// Each input is a (waste_id, weight) pair drawn from a processed waste
+    /// owned by the manufacturer; fully consumed wastes become Recycled
+    pub fn create_product_batch(
+        env: Env,
+        manufacturer: Address,
+        product: Symbol,
+        total_weight: u128,
+        inputs: Vec<(u128, u128)>,
+    ) -> ProductBatch {
+        manufacturer.require_auth();
+
+        if inputs.is_empty() {
+            panic!("Batch requires at least one input");
+        }
+
+        let mut batch_inputs = Vec::new(&env);
+        for (waste_id, weight) in inputs.iter() {
+            if weight == 0 {
+                panic!("Input weight must be greater than zero");
+            }
+
+            let mut waste = Self::require_manufacturer_owned(&env, waste_id, &manufacturer);
+
+            if waste.status != WasteStatus::Processed {
+                panic!("Waste must be processed before use");
+            }
+
+            let consumed: u128 = env
+                .storage()
+                .instance()
+                .get(&("waste_consumed", waste_id))
+                .unwrap_or(0);
+            let consumed = consumed.checked_add(weight).expect("Overflow in consumed weight");
+
+            if consumed > waste.weight {
+                panic!("Input exceeds remaining waste weight");
+            }
+
+            env.storage()
+                .instance()
+                .set(&("waste_consumed", waste_id), &consumed);
+
+            if consumed == waste.weight {
+                Self::transition_waste(&env, &mut waste, WasteStatus::Recycled);
+                Self::set_waste_v2(&env, &waste);
+            }
+
+            batch_inputs.push_back(BatchInput { waste_id, weight });
+        }
+
+        let batch_id: u64 = env
+            .storage()
+            .instance()
+            .get(&("batch_count",))
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&("batch_count",), &batch_id);
+
+        let batch = ProductBatch::new(
+            batch_id,
+            manufacturer.clone(),
+            product,
+            total_weight,
+            batch_inputs,
+            env.ledger().timestamp(),
+        );
+
+        if batch.recycled_weight > total_weight {
+            panic!("Recycled inputs exceed batch weight");
+        }
+
+        env.storage()
+            .instance()
+            .set(&("product_batch", batch_id), &batch);
+
+        let key = ("manufacturer_batches", manufacturer.clone());
+        let mut batches: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        batches.push_back(batch_id);
+        env.storage().instance().set(&key, &batches);
+
+        events::emit_product_batch_created(
+            &env,
+            batch_id,
+            &manufacturer,
+            batch.recycled_weight,
+            total_weight,
+        );
+
+        batch
+    }
+
+    /// Get a product batch by ID
+    pub fn get_product_batch(env: Env, batch_id: u64) -> Option<ProductBatch> {
+        env.storage().instance().get(&("product_batch", batch_id))
+    }
+
+    /// Get the recycled content of a product batch as a percentage of its weight
+    pub fn get_recycled_content(env: Env, batch_id: u64) -> u32 {
+        Self::get_product_batch(env, batch_id)
+            .expect("Batch not found")
+            .recycled_content_percentage()
+    }
+
+    /// Get the IDs of all product batches recorded by a manufacturer
+    pub fn get_manufacturer_batches(env: Env, manufacturer: Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&("manufacturer_batches", manufacturer))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Trace a product batch back to every original recycler and location
+    /// Follows split lineage to the originally registered waste and returns
+    /// the custody chain for each recycled input
+    pub fn trace_product(env: Env, batch_id: u64) -> Vec<ProvenanceRecord> {
+        let batch = Self::get_product_batch(env.clone(), batch_id).expect("Batch not found");
+
+        let mut records = Vec::new(&env);
+        for input in batch.inputs.iter() {
+            let mut origin_id = input.waste_id;
+            while let Some(parent_id) = Self::get_waste_parent(env.clone(), origin_id) {
+                origin_id = parent_id;
+            }
+
+            let origin = Self::get_waste_v2_internal(&env, origin_id).expect("Waste not found");
+            let hops = Self::get_transfer_history_v2(env.clone(), input.waste_id);
+            let recycler = match hops.first() {
+                Some(first_hop) => first_hop.from,
+                None => origin.current_owner.clone(),
+            };
+
+            records.push_back(ProvenanceRecord {
+                waste_id: input.waste_id,
+                origin_waste_id: origin_id,
+                recycler,
+                latitude: origin.latitude,
+                longitude: origin.longitude,
+                weight: input.weight,
+                hops,
+            });
+        }
+
+        records
+    }
+
+    /// Get the IDs of all wastes in a given lifecycle status
+    pub fn get_wastes_by_status(env: Env, status: WasteStatus) -> Vec<u128> {
+        let mut waste_ids = Vec::new(&env);
+        let total_wastes = Self::get_waste_count(&env);
+
+        for waste_id in 1..=total_wastes as u128 {
+            if let Some(waste) = Self::get_waste_v2_internal(&env, waste_id) {
+                if waste.status == status {
+                    waste_ids.push_back(waste_id);
+                }
+            }
+        }
+
+        waste_ids
+    }
+
+    /// Break down active wastes of a waste type by sub-category
+    /// Returns one entry per sub-type that applies to the waste type
+    pub fn get_sub_type_stats(env: Env, waste_type: WasteType) -> Vec<SubTypeStats> {
+        let mut results = Vec::new(&env);
+        for value in 1..=WasteSubType::MAX_VALUE {
+            if let Some(sub_type) = WasteSubType::from_u32(value) {
+                if sub_type != WasteSubType::Unspecified && sub_type.applies_to(waste_type) {
+                    results.push_back(SubTypeStats {
+                        sub_type,
+                        waste_count: 0,
+                        total_weight: 0,
+                    });
+                }
+            }
+        }
+
+        let total_wastes = Self::get_waste_count(&env);
+        for waste_id in 1..=total_wastes as u128 {
+            let Some(waste) = Self::get_waste_v2_internal(&env, waste_id) else {
+                continue;
+            };
+            if !waste.is_active || waste.waste_type != waste_type {
+                continue;
+            }
+
+            for i in 0..results.len() {
+                let mut entry = results.get(i).unwrap();
+                if entry.sub_type == waste.sub_type {
+                    entry.waste_count += 1;
+                    entry.total_weight += waste.weight;
+                    results.set(i, entry);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get the IDs of wastes held by a participant in a given lifecycle status
+    pub fn get_participant_wastes_by_status(
+        env: Env,
+        participant: Address,
+        status: WasteStatus,
+    ) -> Vec<u128> {
+        let owned: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", participant))
+            .unwrap_or(Vec::new(&env));
+
+        let mut waste_ids = Vec::new(&env);
+        for waste_id in owned.iter() {
+            if let Some(waste) = Self::get_waste_v2_internal(&env, waste_id) {
+                if waste.status == status {
+                    waste_ids.push_back(waste_id);
+                }
+            }
+        }
+
+        waste_ids
+    }
+
+    /// Mint recycling credits from processed or confirmed waste (owner only)
+    /// Credits are denominated in grams of the waste's type and can be
+    /// minted only once per waste
+    pub fn mint_credits(env: Env, waste_id: u128, owner: Address) -> u128 {
+        owner.require_auth();
+
+        let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+
+        if waste.current_owner != owner {
+            panic!("Caller does not own waste");
+        }
+
+        if !waste.is_active {
+            panic!("Cannot credit deactivated waste");
+        }
 
-        if !waste.is_active {
-            panic!("Cannot confirm deactivated waste");
+        let processed = matches!(waste.status, WasteStatus::Processed | WasteStatus::Recycled);
+        if !processed && !waste.is_confirmed {
+            panic!("Waste must be processed or confirmed");
         }
 
-        if waste.current_owner == confirmer {
-            panic!("Owner cannot confirm own waste");
+        if Self::is_waste_credited(env.clone(), waste_id) {
+            panic!("Credits already minted for waste");
         }
 
-        if waste.is_confirmed {
-            panic!("Waste already confirmed");
+        if Self::get_open_dispute(env.clone(), waste_id).is_some() {
+            panic!("Waste is under dispute");
         }
 
-        waste.confirm(confirmer.clone());
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        if waste.weight == 0 {
+            panic!("Waste has no weight to credit");
+        }
 
-        events::emit_waste_confirmed(&env, waste_id, &confirmer);
+        env.storage().instance().set(&("waste_credited", waste_id), &true);
+        env.storage().instance().set(&("credit_minter", waste_id), &owner);
+        Self::add_credits(&env, &owner, waste.waste_type, waste.weight);
 
-        waste
+        events::emit_credits_minted(&env, waste_id, &owner, waste.waste_type, waste.weight);
+
+        waste.weight
     }
 
-    /// Reset waste confirmation status
-    /// Only the waste owner can reset the confirmation
-    pub fn reset_waste_confirmation(
-        env: Env,
-        waste_id: u128,
-        owner: Address,
-    ) -> types::Waste {
-        owner.require_auth();
+    /// Check if credits have already been minted for a waste
+    pub fn is_waste_credited(env: Env, waste_id: u128) -> bool {
+        env.storage()
+            .instance()
+            .get(&("waste_credited", waste_id))
+            .unwrap_or(false)
+    }
 
-        let mut waste: types::Waste = env
-            .storage()
+    /// Get an address's credit balance for a waste type in grams
+    pub fn get_credit_balance(env: Env, owner: Address, waste_type: WasteType) -> u128 {
+        env.storage()
             .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+            .get(&("credits", owner, waste_type))
+            .unwrap_or(0)
+    }
 
-        if waste.current_owner != owner {
-            panic!("Only owner can reset confirmation");
-        }
+    /// Transfer recycling credits to another address
+    pub fn transfer_credits(
+        env: Env,
+        from: Address,
+        to: Address,
+        waste_type: WasteType,
+        grams: u128,
+    ) {
+        from.require_auth();
 
-        if !waste.is_confirmed {
-            panic!("Waste is not confirmed");
+        if grams == 0 {
+            panic!("Credit amount must be greater than zero");
         }
 
-        waste.reset_confirmation();
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        if from == to {
+            panic!("Sender and recipient cannot be the same");
+        }
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("reset"), waste_id),
-            (owner, env.ledger().timestamp()),
-        );
+        Self::remove_credits(&env, &from, waste_type, grams);
+        Self::add_credits(&env, &to, waste_type, grams);
 
-        waste
+        events::emit_credits_transferred(&env, &from, &to, waste_type, grams);
     }
 
-    /// Deactivate a waste record (admin only)
-    /// Deactivated waste cannot be queried or reactivated
-    pub fn deactivate_waste(
+    /// Permanently retire recycling credits and issue a certificate
+    pub fn retire_credits(
         env: Env,
-        waste_id: u128,
-        admin: Address,
-    ) -> types::Waste {
-        Self::require_admin(&env, &admin);
+        owner: Address,
+        waste_type: WasteType,
+        grams: u128,
+    ) -> RetirementCertificate {
+        owner.require_auth();
 
-        let mut waste: types::Waste = env
+        if grams == 0 {
+            panic!("Credit amount must be greater than zero");
+        }
+
+        Self::remove_credits(&env, &owner, waste_type, grams);
+
+        let certificate_id: u64 = env
             .storage()
             .instance()
-            .get(&("waste_v2", waste_id))
-            .expect("Waste not found");
+            .get(&("certificate_count",))
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&("certificate_count",), &certificate_id);
 
-        if !waste.is_active {
-            panic!("Waste already deactivated");
-        }
+        let certificate = RetirementCertificate {
+            certificate_id,
+            owner: owner.clone(),
+            waste_type,
+            grams,
+            retired_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .instance()
+            .set(&("certificate", certificate_id), &certificate);
 
-        waste.deactivate();
-        env.storage().instance().set(&("waste_v2", waste_id), &waste);
+        let retired = Self::get_total_retired(env.clone(), waste_type)
+            .checked_add(grams)
+            .expect("Overflow in retired credits");
+        env.storage()
+            .instance()
+            .set(&("retired_credits", waste_type), &retired);
 
-        env.events().publish(
-            (soroban_sdk::symbol_short!("deactive"), waste_id),
-            (admin, env.ledger().timestamp()),
-        );
+        events::emit_credits_retired(&env, certificate_id, &owner, waste_type, grams);
 
-        waste
+        certificate
+    }
+
+    /// Get a retirement certificate by ID
+    pub fn get_retirement_certificate(
+        env: Env,
+        certificate_id: u64,
+    ) -> Option<RetirementCertificate> {
+        env.storage().instance().get(&("certificate", certificate_id))
+    }
+
+    /// Get the total credits retired for a waste type in grams
+    pub fn get_total_retired(env: Env, waste_type: WasteType) -> u128 {
+        env.storage()
+            .instance()
+            .get(&("retired_credits", waste_type))
+            .unwrap_or(0)
+    }
+
+    /// Credit an address's balance (internal helper)
+    fn add_credits(env: &Env, owner: &Address, waste_type: WasteType, grams: u128) {
+        let balance = Self::get_credit_balance(env.clone(), owner.clone(), waste_type)
+            .checked_add(grams)
+            .expect("Overflow in credit balance");
+        env.storage()
+            .instance()
+            .set(&("credits", owner.clone(), waste_type), &balance);
+    }
+
+    /// Debit an address's balance (internal helper)
+    fn remove_credits(env: &Env, owner: &Address, waste_type: WasteType, grams: u128) {
+        let balance = Self::get_credit_balance(env.clone(), owner.clone(), waste_type);
+        if balance < grams {
+            panic!("Insufficient credit balance");
+        }
+        env.storage()
+            .instance()
+            .set(&("credits", owner.clone(), waste_type), &(balance - grams));
     }
 
     /// Batch submit multiple materials for recycling
@@ -1261,6 +4326,7 @@ impl ScavengerContract {
         // Process each material
         for item in materials.iter() {
             let (waste_type, weight, description) = item;
+            Self::require_plausible_weight(&env, waste_type, weight as u128);
             let waste_id = Self::next_waste_id(&env);
 
             let material = Material::new(
@@ -1366,26 +4432,17 @@ impl ScavengerContract {
         let mut material: Material =
             Self::get_waste_internal(&env, material_id).expect("Material not found");
 
+        if material.verified {
+            panic!("Material already verified");
+        }
+
         material.verify();
         Self::set_waste(&env, material_id, &material);
-
-        // Calculate tokens earned
-        let tokens_earned = material.calculate_reward_points();
-
-        // Update submitter stats
-        let mut stats: RecyclingStats = env
-            .storage()
-            .instance()
-            .get(&("stats", material.submitter.clone()))
-            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
-
-        stats.record_verification(&material);
         env.storage()
             .instance()
-            .set(&("stats", material.submitter.clone()), &stats);
+            .set(&("material_verifier", material_id), &verifier);
 
-        // Update submitter's participant stats with tokens earned
-        Self::update_participant_stats(&env, &material.submitter, 0, tokens_earned);
+        Self::reward_verified_material(&env, &material);
 
         material
     }
@@ -1418,26 +4475,17 @@ impl ScavengerContract {
 
         for material_id in material_ids.iter() {
             if let Some(mut material) = Self::get_waste_internal(&env, material_id) {
+                if material.verified {
+                    panic!("Material already verified");
+                }
+
                 material.verify();
                 Self::set_waste(&env, material_id, &material);
-
-                // Calculate tokens earned
-                let tokens_earned = material.calculate_reward_points();
-
-                // Update submitter stats
-                let mut stats: RecyclingStats = env
-                    .storage()
-                    .instance()
-                    .get(&("stats", material.submitter.clone()))
-                    .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
-
-                stats.record_verification(&material);
                 env.storage()
                     .instance()
-                    .set(&("stats", material.submitter.clone()), &stats);
+                    .set(&("material_verifier", material_id), &verifier);
 
-                // Update submitter's participant stats with tokens earned
-                Self::update_participant_stats(&env, &material.submitter, 0, tokens_earned);
+                Self::reward_verified_material(&env, &material);
 
                 results.push_back(material);
             }
@@ -1446,121 +4494,310 @@ impl ScavengerContract {
         results
     }
 
-    /// Get recycling statistics for a participant
-    pub fn get_stats(env: Env, participant: Address) -> Option<RecyclingStats> {
-        env.storage().instance().get(&("stats", participant))
+    // ========== Dispute Functions ==========
+
+    /// Grant the arbitrator role to an address (admin only)
+    pub fn add_arbitrator(env: Env, admin: Address, arbitrator: Address) {
+        Self::require_admin(&env, &admin);
+        env.storage()
+            .instance()
+            .set(&("arbitrator", arbitrator), &true);
     }
 
-    /// Get supply chain statistics (total wastes, total weight, total tokens earned)
-    /// Returns a tuple of (total_wastes_count, total_weight_grams, total_tokens_earned)
-    pub fn get_supply_chain_stats(env: Env) -> (u64, u64, u128) {
-        let total_wastes = Self::get_waste_count(&env);
-        let total_weight = Self::get_total_active_waste_weight(&env);
-        let total_tokens = Self::get_total_tokens(&env);
-        
-        (total_wastes, total_weight, total_tokens)
+    /// Revoke the arbitrator role from an address (admin only)
+    pub fn remove_arbitrator(env: Env, admin: Address, arbitrator: Address) {
+        Self::require_admin(&env, &admin);
+        env.storage().instance().remove(&("arbitrator", arbitrator));
     }
 
-    /// Get the active incentive with the highest reward for a specific manufacturer and waste type
-    /// Returns None if no active incentive is found
-    pub fn get_active_incentive_for_manufacturer(
+    /// Check if an address holds the arbitrator role
+    pub fn is_arbitrator(env: Env, address: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&("arbitrator", address))
+            .unwrap_or(false)
+    }
+
+    /// Open a dispute against a waste confirmation or material verification
+    /// The bond is escrowed in the marketplace token until resolution
+    pub fn open_dispute(
         env: Env,
-        manufacturer: Address,
-        waste_type: WasteType,
-    ) -> Option<Incentive> {
-        // Get all incentives for this manufacturer
-        let incentive_ids = Self::get_incentives_by_rewarder(env.clone(), manufacturer.clone());
-        
-        let mut best_incentive: Option<Incentive> = None;
-        let mut highest_reward: u64 = 0;
-        
-        // Iterate through all incentives and find the best active one
-        for incentive_id in incentive_ids.iter() {
-            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
-                // Check if incentive matches criteria: active and correct waste type
-                if incentive.active && incentive.waste_type == waste_type {
-                    // Keep track of the incentive with highest reward
-                    if incentive.reward_points > highest_reward {
-                        highest_reward = incentive.reward_points;
-                        best_incentive = Some(incentive);
-                    }
+        disputer: Address,
+        waste_id: u128,
+        target: DisputeTarget,
+        bond: u128,
+        evidence: Bytes,
+    ) -> Dispute {
+        disputer.require_auth();
+        Self::require_registered(&env, &disputer);
+
+        if bond == 0 {
+            panic!("Dispute bond must be greater than zero");
+        }
+
+        if Self::get_open_dispute(env.clone(), waste_id).is_some() {
+            panic!("Waste already under dispute");
+        }
+
+        let accused = match target {
+            DisputeTarget::Confirmation => {
+                let waste = Self::get_waste_v2_internal(&env, waste_id).expect("Waste not found");
+                if !waste.is_confirmed {
+                    panic!("Waste is not confirmed");
                 }
+                waste.confirmer
             }
+            DisputeTarget::Verification => {
+                let material_id = u64::try_from(waste_id).expect("Material not found");
+                let material =
+                    Self::get_waste_internal(&env, material_id).expect("Material not found");
+                if !material.verified {
+                    panic!("Material is not verified");
+                }
+                env.storage()
+                    .instance()
+                    .get(&("material_verifier", material_id))
+                    .expect("Verifier not recorded")
+            }
+        };
+
+        if accused == disputer {
+            panic!("Cannot dispute own decision");
         }
-        
-        best_incentive
+
+        Self::lock_bond(&env, &disputer, bond);
+        Self::record_evidence(&env, waste_id, evidence.clone(), EvidenceKind::Dispute, &disputer);
+
+        let count: u64 = env
+            .storage()
+            .instance()
+            .get(&("dispute_count",))
+            .unwrap_or(0);
+        let dispute_id = count + 1;
+        env.storage().instance().set(&("dispute_count",), &dispute_id);
+
+        let dispute = Dispute {
+            dispute_id,
+            waste_id,
+            target,
+            disputer: disputer.clone(),
+            accused,
+            bond,
+            evidence,
+            status: DisputeStatus::Open,
+            arbitrator: None,
+            opened_at: env.ledger().timestamp(),
+            resolved_at: 0,
+        };
+
+        env.storage().instance().set(&("dispute", dispute_id), &dispute);
+        env.storage().instance().set(&("open_dispute", waste_id), &dispute_id);
+
+        events::emit_dispute_opened(&env, dispute_id, waste_id, target, &disputer, bond);
+
+        dispute
     }
 
-    /// Create a new incentive
-    pub fn create_incentive(
-        env: Env,
-        rewarder: Address,
-        waste_type: WasteType,
-        reward_points: u64,
-        total_budget: u64,
-    ) -> Incentive {
-        rewarder.require_auth();
+    /// Resolve an open dispute (arbitrators only)
+    /// Overturning reverses the confirmation or verification, claws back the
+    /// unpaid verification reward and returns the bond; upholding slashes the
+    /// bond to the accused. Reputation moves towards whoever was right.
+    pub fn resolve_dispute(env: Env, arbitrator: Address, dispute_id: u64, overturn: bool) -> Dispute {
+        arbitrator.require_auth();
 
-        // Verify rewarder is a manufacturer
-        if !Self::is_participant_registered(env.clone(), rewarder.clone()) {
-            panic!("Rewarder not registered");
+        if !Self::is_arbitrator(env.clone(), arbitrator.clone()) {
+            panic!("Only arbitrators can resolve disputes");
         }
 
-        let participant =
-            Self::get_participant(env.clone(), rewarder.clone()).expect("Rewarder not found");
+        let mut dispute = Self::get_dispute(env.clone(), dispute_id).expect("Dispute not found");
 
-        if !participant.role.can_manufacture() {
-            panic!("Only manufacturers can create incentives");
+        if dispute.status.is_resolved() {
+            panic!("Dispute already resolved");
         }
 
-        // Get next incentive ID
-        let incentive_id = Self::next_incentive_id(&env);
+        if arbitrator == dispute.disputer || arbitrator == dispute.accused {
+            panic!("Arbitrator cannot be a party to the dispute");
+        }
 
-        // Create incentive
-        let incentive = Incentive::new(
-            incentive_id,
-            rewarder.clone(),
-            waste_type,
-            reward_points,
-            total_budget,
-            env.ledger().timestamp(),
-        );
+        if overturn {
+            match dispute.target {
+                DisputeTarget::Confirmation => {
+                    let mut waste = Self::get_waste_v2_internal(&env, dispute.waste_id)
+                        .expect("Waste not found");
+                    waste.reset_confirmation();
+                    Self::set_waste_v2(&env, &waste);
+                    env.storage()
+                        .instance()
+                        .remove(&("waste_confirmers", dispute.waste_id));
+
+                    if Self::is_waste_credited(env.clone(), dispute.waste_id) {
+                        Self::clawback_credits(&env, &waste);
+                    }
+                }
+                DisputeTarget::Verification => {
+                    let material_id = dispute.waste_id as u64;
+                    let mut material =
+                        Self::get_waste_internal(&env, material_id).expect("Material not found");
+                    material.verified = false;
+                    Self::set_waste(&env, material_id, &material);
+                    env.storage()
+                        .instance()
+                        .remove(&("material_verifier", material_id));
+
+                    let rewardee: Address = env
+                        .storage()
+                        .instance()
+                        .get(&("material_rewardee", material_id))
+                        .unwrap_or(material.submitter.clone());
+                    let points = Self::applied_reward_points(&env, &material);
+                    Self::clawback_tokens(&env, &rewardee, points as u128);
+                    env.storage()
+                        .instance()
+                        .remove(&("material_rate", material_id));
+                    Self::clawback_co2e(&env, &material, &rewardee);
+
+                    let stats_key = ("stats", rewardee);
+                    if let Some(mut stats) =
+                        env.storage().instance().get::<_, RecyclingStats>(&stats_key)
+                    {
+                        stats.remove_verification(points);
+                        env.storage().instance().set(&stats_key, &stats);
+                    }
 
-        // Store incentive
-        Self::set_incentive(&env, incentive_id, &incentive);
+                    // Incentive tokens already paid out are recovered from earned points
+                    if let Some((claimant, claimed)) = env
+                        .storage()
+                        .instance()
+                        .get::<_, (Address, u128)>(&("incentive_claim_reward", material_id))
+                    {
+                        Self::clawback_tokens(&env, &claimant, claimed);
+                    }
+                }
+            }
 
-        // Add to rewarder's incentive list
-        let key = ("rewarder_incentives", rewarder.clone());
-        let mut rewarder_incentives: Vec<u64> =
-            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
-        rewarder_incentives.push_back(incentive_id);
-        env.storage().instance().set(&key, &rewarder_incentives);
+            Self::release_bond(&env, &dispute.disputer, dispute.bond);
+            Self::adjust_reputation(&env, &dispute.disputer, DISPUTE_REPUTATION_STEP);
+            Self::adjust_reputation(&env, &dispute.accused, -DISPUTE_REPUTATION_STEP);
+        } else {
+            Self::release_bond(&env, &dispute.accused, dispute.bond);
+            Self::adjust_reputation(&env, &dispute.disputer, -DISPUTE_REPUTATION_STEP);
+        }
 
-        // Add to general incentives list for this waste type
-        let key = ("general_incentives", waste_type);
-        let mut general_incentives: Vec<u64> =
-            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
-        general_incentives.push_back(incentive_id);
-        env.storage().instance().set(&key, &general_incentives);
+        dispute.resolve(arbitrator.clone(), overturn, env.ledger().timestamp());
+        env.storage().instance().set(&("dispute", dispute_id), &dispute);
+        env.storage()
+            .instance()
+            .remove(&("open_dispute", dispute.waste_id));
 
-        incentive
+        events::emit_dispute_resolved(&env, dispute_id, &arbitrator, dispute.status);
+
+        dispute
     }
 
-    /// Deactivate an incentive (only by creator)
-    pub fn deactivate_incentive(env: Env, incentive_id: u64, rewarder: Address) -> Incentive {
-        rewarder.require_auth();
+    /// Get a dispute by ID
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Option<Dispute> {
+        env.storage().instance().get(&("dispute", dispute_id))
+    }
 
-        let mut incentive = Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+    /// Get the open dispute for a waste or material, if any
+    pub fn get_open_dispute(env: Env, waste_id: u128) -> Option<u64> {
+        env.storage().instance().get(&("open_dispute", waste_id))
+    }
 
-        // Verify caller is the creator
-        if incentive.rewarder != rewarder {
-            panic!("Only incentive creator can deactivate");
+    /// Get a participant's reputation score
+    pub fn get_reputation(env: Env, address: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&("reputation", address))
+            .unwrap_or(0)
+    }
+
+    /// Escrow a bond from a participant's marketplace tokens
+    fn lock_bond(env: &Env, address: &Address, amount: u128) {
+        let amount = i128::try_from(amount).expect("Bond too large");
+        let token = token::Client::new(env, &Self::market_token(env));
+
+        if token.balance(address) < amount {
+            panic!("Insufficient tokens for bond");
         }
 
-        incentive.deactivate();
-        Self::set_incentive(&env, incentive_id, &incentive);
+        token.transfer(address, &env.current_contract_address(), &amount);
+    }
 
-        incentive
+    /// Pay an escrowed bond out to a participant
+    fn release_bond(env: &Env, address: &Address, amount: u128) {
+        let amount = i128::try_from(amount).expect("Bond too large");
+        let token = token::Client::new(env, &Self::market_token(env));
+        token.transfer(&env.current_contract_address(), address, &amount);
+    }
+
+    /// Burn the credits minted for a waste whose confirmation was overturned
+    /// Only credits still held by the minter can be recovered
+    fn clawback_credits(env: &Env, waste: &Waste) {
+        let minter: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&("credit_minter", waste.waste_id));
+        if let Some(minter) = minter {
+            let balance = Self::get_credit_balance(env.clone(), minter.clone(), waste.waste_type);
+            Self::remove_credits(env, &minter, waste.waste_type, waste.weight.min(balance));
+        }
+    }
+
+    /// Remove rewards that were credited but should not have been
+    /// Only the unspent part of the balance can be recovered
+    fn clawback_tokens(env: &Env, address: &Address, amount: u128) {
+        let key = (address.clone(),);
+        if let Some(mut participant) = env.storage().instance().get::<_, Participant>(&key) {
+            let recovered = amount.min(participant.total_tokens_earned);
+            participant.total_tokens_earned -= recovered;
+            env.storage().instance().set(&key, &participant);
+
+            let total = Self::get_total_tokens(env);
+            env.storage()
+                .instance()
+                .set(&TOTAL_TOKENS, &total.saturating_sub(recovered));
+        }
+    }
+
+    /// Reverse the emissions credited for a material's verification
+    fn clawback_co2e(env: &Env, material: &Material, rewardee: &Address) {
+        let key = ("material_co2e", material.id);
+        let co2e: u128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().remove(&key);
+
+        let stats_key = ("stats", rewardee.clone());
+        if let Some(mut stats) = env.storage().instance().get::<_, RecyclingStats>(&stats_key) {
+            stats.remove_co2e_avoided(co2e);
+            env.storage().instance().set(&stats_key, &stats);
+        }
+
+        let weight = material.weight as u128;
+        Self::update_impact(env, rewardee, None, |impact| {
+            impact.remove_avoided(weight, co2e)
+        });
+    }
+
+    /// Apply a reputation change to a participant
+    fn adjust_reputation(env: &Env, address: &Address, delta: i128) {
+        let key = ("reputation", address.clone());
+        let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(current + delta));
+    }
+
+    /// Get recycling statistics for a participant
+    pub fn get_stats(env: Env, participant: Address) -> Option<RecyclingStats> {
+        env.storage().instance().get(&("stats", participant))
+    }
+
+    /// Get supply chain statistics (total wastes, total weight, total tokens earned)
+    /// Returns a tuple of (total_wastes_count, total_weight_grams, total_tokens_earned)
+    pub fn get_supply_chain_stats(env: Env) -> (u64, u64, u128) {
+        let total_wastes = Self::get_waste_count(&env);
+        let total_weight = Self::get_total_active_waste_weight(&env);
+        let total_tokens = Self::get_total_tokens(&env);
+        
+        (total_wastes, total_weight, total_tokens)
     }
 
 }
@@ -0,0 +1,99 @@
+//! Pooled incentive arithmetic
+//!
+//! Both the stellar and the scavenger contract compile this file against
+//! their own `PoolContribution` and `PoolPayout` types, so a contribution or
+//! payout is split the same way whichever contract holds the pool. Storage,
+//! token transfers and events stay with the callers.
+
+use soroban_sdk::{Address, Vec};
+
+use crate::types::{PoolContribution, PoolPayout};
+
+/// Add `amount` to `funder`'s contribution, or add the funder to the pool
+/// `share_bps` replaces the funder's share and must be zero for pro-rata
+/// pools; in share-based pools all shares together may not exceed 100%
+/// Returns the funder's updated contribution
+pub fn contribute(
+    contributions: &mut Vec<PoolContribution>,
+    payout: PoolPayout,
+    funder: &Address,
+    amount: i128,
+    share_bps: u32,
+) -> PoolContribution {
+    let mut total_shares: u32 = 0;
+    let mut index = None;
+    for (i, contribution) in contributions.iter().enumerate() {
+        if contribution.funder == *funder {
+            index = Some(i as u32);
+        } else {
+            total_shares += contribution.share_bps;
+        }
+    }
+
+    match payout {
+        PoolPayout::ProRata => assert!(share_bps == 0, "Shares only apply to share-based pools"),
+        PoolPayout::Shares => {
+            assert!(share_bps > 0, "Share must be greater than zero");
+            assert!(total_shares + share_bps <= 10_000, "Shares exceed 100%");
+        }
+    }
+
+    match index {
+        Some(i) => {
+            let mut existing = contributions.get(i).unwrap();
+            existing.contributed += amount;
+            existing.remaining += amount;
+            existing.share_bps = share_bps;
+            contributions.set(i, existing.clone());
+            existing
+        }
+        None => {
+            let contribution = PoolContribution {
+                funder: funder.clone(),
+                contributed: amount,
+                remaining: amount,
+                share_bps,
+            };
+            contributions.push_back(contribution.clone());
+            contribution
+        }
+    }
+}
+
+/// Charge a payout to a pool's funders by the pool's payout rule
+/// Rounding dust, and any share a funder can no longer cover, comes from
+/// whoever still has funds left
+pub fn draw(contributions: &mut Vec<PoolContribution>, payout: PoolPayout, amount: i128) {
+    let mut total_weight: i128 = 0;
+    for contribution in contributions.iter() {
+        if contribution.remaining > 0 {
+            total_weight += contribution.payout_weight(payout);
+        }
+    }
+
+    let mut left = amount;
+    if total_weight > 0 {
+        for i in 0..contributions.len() {
+            let mut contribution = contributions.get(i).unwrap();
+            if contribution.remaining == 0 {
+                continue;
+            }
+            let portion = (amount * contribution.payout_weight(payout) / total_weight)
+                .min(contribution.remaining);
+            contribution.remaining -= portion;
+            left -= portion;
+            contributions.set(i, contribution);
+        }
+    }
+
+    for i in 0..contributions.len() {
+        if left == 0 {
+            break;
+        }
+        let mut contribution = contributions.get(i).unwrap();
+        let portion = left.min(contribution.remaining);
+        contribution.remaining -= portion;
+        left -= portion;
+        contributions.set(i, contribution);
+    }
+}
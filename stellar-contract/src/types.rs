@@ -1,5 +1,5 @@
 
-use soroban_sdk::{contracttype, Address, String, Symbol};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 /// Represents a transfer record in the recycling system
 /// This struct is fully compatible with Soroban storage and implements
@@ -195,91 +195,146 @@ impl TransferRecord {
     }
 }
 
-/// Represents an incentive offered by a manufacturer to encourage recycling
+/// Represents a manufacturer incentive program for recycling specific waste types
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Incentive {
     /// Unique identifier for the incentive
     pub id: u64,
-    /// Address of the manufacturer offering the incentive
-    pub rewarder: Address,
-    /// Type of waste this incentive targets
+    /// Type of waste this incentive applies to
     pub waste_type: WasteType,
     /// Reward points per kilogram
-    pub reward_points: u64,
+    pub reward: u128,
+    /// Maximum waste amount eligible for this incentive (in grams)
+    pub max_waste_amount: u128,
     /// Total points budget allocated for this incentive
-    pub total_budget: u64,
+    pub total_budget: u128,
     /// Remaining points budget available
-    pub remaining_budget: u64,
-    /// Whether the incentive is currently active
-    pub active: bool,
+    pub remaining_budget: u128,
+    /// Address of the manufacturer offering the incentive
+    pub rewarder: Address,
+    /// Whether this incentive is currently active
+    pub is_active: bool,
     /// Timestamp when the incentive was created
     pub created_at: u64,
+    /// Whether only device-attested weight earns this incentive
+    pub requires_attestation: bool,
+    /// Sub-categories this incentive is restricted to (empty accepts any)
+    pub sub_types: Vec<WasteSubType>,
 }
 
 impl Incentive {
-    /// Creates a new Incentive instance
-    pub fn new(
-        id: u64,
-        rewarder: Address,
-        waste_type: WasteType,
-        reward_points: u64,
-        total_budget: u64,
-        created_at: u64,
-    ) -> Self {
-        Self {
-            id,
-            rewarder,
-            waste_type,
-            reward_points,
-            total_budget,
-            remaining_budget: total_budget,
-            active: true,
-            created_at,
+    /// Calculates the reward for a weight in grams, capped at `max_waste_amount`
+    pub fn reward_for(&self, weight_grams: u128) -> u128 {
+        weight_grams
+            .min(self.max_waste_amount)
+            .checked_mul(self.reward)
+            .expect("Overflow in reward calculation")
+            / 1000
+    }
+
+    /// Points of the budget already paid out
+    pub fn budget_used(&self) -> u128 {
+        self.total_budget - self.remaining_budget
+    }
+
+    /// Sets a new total budget, keeping what was already paid out
+    /// Returns true if the new budget leaves nothing to pay
+    pub fn rebudget(&mut self, total_budget: u128) -> bool {
+        let used = self.budget_used();
+        self.total_budget = total_budget;
+        self.remaining_budget = total_budget.saturating_sub(used);
+        if self.remaining_budget == 0 {
+            self.is_active = false;
+            return true;
         }
+        false
     }
 
-    /// Deactivates the incentive
-    pub fn deactivate(&mut self) {
-        self.active = false;
+    /// Draws a reward from the remaining budget (callers check it fits first)
+    /// Returns true if the draw exhausted the budget, which deactivates the incentive
+    pub fn draw(&mut self, reward: u128) -> bool {
+        self.remaining_budget = self.remaining_budget.saturating_sub(reward);
+        if self.remaining_budget == 0 {
+            self.is_active = false;
+            return true;
+        }
+        false
     }
+}
 
-    /// Calculates reward for a given weight in grams
-    pub fn calculate_reward(&self, weight_grams: u64) -> u64 {
-        // Convert grams to kg and multiply by reward points
-        (weight_grams / 1000) * self.reward_points
-    }
+/// Where and until when an incentive pays out
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IncentiveScope {
+    /// Timestamp from which the incentive stops paying (0 never expires)
+    pub expires_at: u64,
+    /// Centre of the region suppliers must be registered within (scaled by 1e6)
+    pub latitude: i128,
+    pub longitude: i128,
+    /// Radius of the region in metres (0 accepts any location)
+    pub radius: u64,
+}
 
-    /// Attempts to claim a reward, returns the amount claimed
-    /// Returns None if insufficient budget
-    pub fn claim_reward(&mut self, weight_grams: u64) -> Option<u64> {
-        if !self.active {
-            return None;
-        }
+impl IncentiveScope {
+    /// Whether the incentive has expired at `timestamp`
+    pub fn is_expired_at(&self, timestamp: u64) -> bool {
+        self.expires_at != 0 && timestamp >= self.expires_at
+    }
 
-        let reward = self.calculate_reward(weight_grams);
-        if reward > self.remaining_budget {
-            return None;
-        }
+    /// Whether a location falls within the incentive's region
+    pub fn covers(&self, latitude: i128, longitude: i128) -> bool {
+        self.radius == 0
+            || crate::geo::distance(self.latitude, self.longitude, latitude, longitude)
+                <= self.radius
+    }
+}
 
-        self.remaining_budget -= reward;
+/// What an incentive would pay for a delivery, used to rank incentives
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncentiveQuote {
+    /// Incentive being quoted
+    pub incentive_id: u64,
+    /// Manufacturer offering the incentive
+    pub rewarder: Address,
+    /// Reward points per kilogram
+    pub reward: u128,
+    /// Points the delivery would earn once `max_waste_amount` is applied
+    pub payout: u128,
+}
 
-        // Auto-deactivate if budget exhausted
-        if self.remaining_budget == 0 {
-            self.active = false;
-        }
+/// How a pooled incentive splits each payout between its funders
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolPayout {
+    /// In proportion to each funder's unspent contribution
+    ProRata = 0,
+    /// In proportion to each funder's configured share
+    Shares = 1,
+}
 
-        Some(reward)
-    }
+/// A manufacturer's stake in a pooled incentive
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolContribution {
+    /// Manufacturer who contributed
+    pub funder: Address,
+    /// Total tokens contributed
+    pub contributed: i128,
+    /// Contributed tokens not yet paid out
+    pub remaining: i128,
+    /// Configured share of payouts in basis points (share-based pools only)
+    pub share_bps: u32,
+}
 
-    /// Checks if the incentive has sufficient budget for a reward
-    pub fn has_sufficient_budget(&self, weight_grams: u64) -> bool {
-        if !self.active {
-            return false;
+impl PoolContribution {
+    /// Weight of this contribution when splitting a payout
+    pub fn payout_weight(&self, payout: PoolPayout) -> i128 {
+        match payout {
+            PoolPayout::ProRata => self.remaining,
+            PoolPayout::Shares => self.share_bps as i128,
         }
-        let reward = self.calculate_reward(weight_grams);
-        reward <= self.remaining_budget
-
     }
 }
 
@@ -342,70 +397,403 @@ impl ParticipantRole {
     }
 }
 
+/// How precisely a participant's location is stored on-chain
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocationPrivacy {
+    /// Coordinates are stored as given
+    Exact = 0,
+    /// Coordinates are snapped to the centre of their geospatial grid cell
+    Coarse = 1,
+    /// Only a salted hash of the coordinates is stored
+    Committed = 2,
+}
+
+impl LocationPrivacy {
+    /// Validates if a u32 value corresponds to a valid LocationPrivacy
+    pub fn is_valid(value: u32) -> bool {
+        value <= 2
+    }
+
+    /// Converts a u32 to a LocationPrivacy
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(LocationPrivacy::Exact),
+            1 => Some(LocationPrivacy::Coarse),
+            2 => Some(LocationPrivacy::Committed),
+            _ => None,
+        }
+    }
+
+    /// Converts the LocationPrivacy to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the privacy mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationPrivacy::Exact => "EXACT",
+            LocationPrivacy::Coarse => "COARSE",
+            LocationPrivacy::Committed => "COMMITTED",
+        }
+    }
+
+    /// Whether locations stored under this mode are precise
+    pub fn is_exact(&self) -> bool {
+        matches!(self, LocationPrivacy::Exact)
+    }
+}
+
 /// Represents the type of waste material in the recycling ecosystem
+/// Wraps the id of a category in the on-chain waste catalogue; the five
+/// built-in categories are available as associated constants and further
+/// categories are added by the admin at runtime
+///
+/// Note: this replaced an integer enum, which changed the stored encoding
+/// from `U32` to a one-element `Vec`; see docs/WASTETYPE_IMPLEMENTATION.txt
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum WasteType {
+pub struct WasteType(pub u32);
+
+// Built-in categories keep their original variant-style names so existing
+// call sites such as `WasteType::Paper` and match arms keep working
+#[allow(non_upper_case_globals)]
+impl WasteType {
     /// Paper waste - newspapers, cardboard, office paper
-    Paper = 0,
+    pub const Paper: WasteType = WasteType(0);
     /// PET plastic - polyethylene terephthalate bottles and containers
-    PetPlastic = 1,
+    pub const PetPlastic: WasteType = WasteType(1);
     /// General plastic waste - various plastic types
-    Plastic = 2,
+    pub const Plastic: WasteType = WasteType(2);
     /// Metal waste - aluminum, steel, copper
-    Metal = 3,
+    pub const Metal: WasteType = WasteType(3);
     /// Glass waste - bottles, jars, containers
-    Glass = 4,
+    pub const Glass: WasteType = WasteType(4);
 }
 
 impl WasteType {
-    /// Validates if the value is a valid WasteType variant
+    /// Number of built-in categories; catalogue ids added at runtime start here
+    pub const BUILTIN_COUNT: u32 = 5;
+
+    /// Validates if the value is a built-in WasteType
     pub fn is_valid(value: u32) -> bool {
-        matches!(value, 0..=4)
+        value < Self::BUILTIN_COUNT
     }
 
-    /// Converts a u32 to a WasteType
-    /// Returns None if the value is invalid
+    /// Converts a u32 to a built-in WasteType
+    /// Returns None if the value is not a built-in category
     pub fn from_u32(value: u32) -> Option<Self> {
-        match value {
-            0 => Some(WasteType::Paper),
-            1 => Some(WasteType::PetPlastic),
-            2 => Some(WasteType::Plastic),
-            3 => Some(WasteType::Metal),
-            4 => Some(WasteType::Glass),
-            _ => None,
+        if Self::is_valid(value) {
+            Some(WasteType(value))
+        } else {
+            None
         }
     }
 
-    /// Converts the WasteType to u32
+    /// Converts the WasteType to its catalogue id
     pub fn to_u32(&self) -> u32 {
-        *self as u32
+        self.0
+    }
+
+    /// Checks if this is one of the built-in categories
+    pub fn is_builtin(&self) -> bool {
+        Self::is_valid(self.0)
     }
 
     /// Returns the string representation of the waste type
+    /// Runtime categories carry their display name in the catalogue instead
     pub fn as_str(&self) -> &'static str {
-        match self {
+        match *self {
             WasteType::Paper => "PAPER",
             WasteType::PetPlastic => "PETPLASTIC",
             WasteType::Plastic => "PLASTIC",
             WasteType::Metal => "METAL",
             WasteType::Glass => "GLASS",
+            _ => "CUSTOM",
         }
     }
 
+    /// Looks up this waste type's entry in the catalogue
+    pub fn category(&self, env: &Env) -> Option<WasteCategory> {
+        WasteCategory::load(env, *self)
+    }
+
+    /// Reward points multiplier from the catalogue (1 for unknown ids)
+    pub fn reward_multiplier(&self, env: &Env) -> u64 {
+        self.category(env).map(|c| c.reward_multiplier).unwrap_or(1)
+    }
+
     /// Checks if the waste type is recyclable plastic
-    pub fn is_plastic(&self) -> bool {
-        matches!(self, WasteType::PetPlastic | WasteType::Plastic)
+    pub fn is_plastic(&self, env: &Env) -> bool {
+        self.category(env).is_some_and(|c| c.is_plastic)
     }
 
     /// Checks if the waste type is biodegradable
-    pub fn is_biodegradable(&self) -> bool {
-        matches!(self, WasteType::Paper)
+    pub fn is_biodegradable(&self, env: &Env) -> bool {
+        self.category(env).is_some_and(|c| c.is_biodegradable)
     }
 
     /// Checks if the waste type is infinitely recyclable
-    pub fn is_infinitely_recyclable(&self) -> bool {
-        matches!(self, WasteType::Metal | WasteType::Glass)
+    pub fn is_infinitely_recyclable(&self, env: &Env) -> bool {
+        self.category(env).is_some_and(|c| c.is_infinitely_recyclable)
+    }
+}
+
+/// Default upper bound on a single submission's weight in grams (1,000 tonnes)
+pub const DEFAULT_MAX_CATEGORY_WEIGHT: u128 = 1_000_000_000;
+
+/// Entry in the on-chain waste catalogue
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasteCategory {
+    /// Catalogue id, used as the WasteType
+    pub id: u32,
+    /// Display name of the category
+    pub name: String,
+    /// Whether the category is a recyclable plastic
+    pub is_plastic: bool,
+    /// Whether the category is biodegradable
+    pub is_biodegradable: bool,
+    /// Whether the category can be recycled indefinitely
+    pub is_infinitely_recyclable: bool,
+    /// Reward points multiplier applied per kilogram
+    pub reward_multiplier: u64,
+    /// Largest plausible weight for a single submission in grams
+    pub max_weight: u128,
+    /// Whether new wastes and incentives may use the category
+    pub is_active: bool,
+}
+
+impl WasteCategory {
+    /// Returns the default catalogue entry for a built-in waste type
+    pub fn builtin(env: &Env, waste_type: WasteType) -> Option<Self> {
+        // (plastic, biodegradable, infinitely recyclable, reward multiplier)
+        let (is_plastic, is_biodegradable, is_infinitely_recyclable, reward_multiplier) =
+            match waste_type {
+                WasteType::Paper => (false, true, false, 1),
+                WasteType::PetPlastic => (true, false, false, 3),
+                WasteType::Plastic => (true, false, false, 2),
+                WasteType::Metal => (false, false, true, 5),
+                WasteType::Glass => (false, false, true, 2),
+                _ => return None,
+            };
+
+        Some(Self {
+            id: waste_type.to_u32(),
+            name: String::from_str(env, waste_type.as_str()),
+            is_plastic,
+            is_biodegradable,
+            is_infinitely_recyclable,
+            reward_multiplier,
+            max_weight: DEFAULT_MAX_CATEGORY_WEIGHT,
+            is_active: true,
+        })
+    }
+
+    /// Reads a category from the catalogue in contract storage
+    /// Built-in categories fall back to their defaults until updated
+    pub fn load(env: &Env, waste_type: WasteType) -> Option<Self> {
+        env.storage()
+            .instance()
+            .get(&("waste_category", waste_type.to_u32()))
+            .or_else(|| Self::builtin(env, waste_type))
+    }
+
+    /// Returns the waste type this category is identified by
+    pub fn waste_type(&self) -> WasteType {
+        WasteType(self.id)
+    }
+
+    /// Checks if a weight is plausible for a single submission
+    pub fn allows_weight(&self, weight: u128) -> bool {
+        weight <= self.max_weight
+    }
+}
+
+/// How fractional reward points are rounded
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round down to the nearest whole point
+    Floor = 0,
+    /// Round to the nearest whole point, halves rounding up
+    HalfUp = 1,
+    /// Round up to the nearest whole point
+    Ceil = 2,
+}
+
+impl RoundingMode {
+    /// Validates if the value corresponds to a valid RoundingMode
+    pub fn is_valid(value: u32) -> bool {
+        value <= 2
+    }
+
+    /// Converts a u32 to a RoundingMode
+    /// Returns None if the value is invalid
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(RoundingMode::Floor),
+            1 => Some(RoundingMode::HalfUp),
+            2 => Some(RoundingMode::Ceil),
+            _ => None,
+        }
+    }
+
+    /// Converts the RoundingMode to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the rounding mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundingMode::Floor => "FLOOR",
+            RoundingMode::HalfUp => "HALF_UP",
+            RoundingMode::Ceil => "CEIL",
+        }
+    }
+
+    /// Divides `value` by `divisor` using this rounding mode
+    pub fn divide(&self, value: u128, divisor: u128) -> u128 {
+        let quotient = value / divisor;
+        let remainder = value % divisor;
+        match self {
+            RoundingMode::Floor => quotient,
+            RoundingMode::HalfUp if remainder * 2 >= divisor => quotient + 1,
+            RoundingMode::HalfUp => quotient,
+            RoundingMode::Ceil if remainder > 0 => quotient + 1,
+            RoundingMode::Ceil => quotient,
+        }
+    }
+}
+
+/// Reward table entry for a waste type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardRate {
+    /// Waste type the rate applies to
+    pub waste_type: WasteType,
+    /// Reward points earned per kilogram
+    pub points_per_kg: u64,
+    /// Rounding applied to fractional points
+    pub rounding: RoundingMode,
+    /// Incremented every time the admin changes the rate (0 for the default)
+    pub version: u32,
+    /// Timestamp from which the rate applies
+    pub effective_from: u64,
+}
+
+impl RewardRate {
+    /// Points awarded per kilogram for each unit of catalogue multiplier
+    pub const POINTS_PER_MULTIPLIER: u64 = 10;
+
+    /// Default rate derived from a catalogue reward multiplier
+    pub fn standard(waste_type: WasteType, multiplier: u64) -> Self {
+        Self {
+            waste_type,
+            points_per_kg: multiplier * Self::POINTS_PER_MULTIPLIER,
+            rounding: RoundingMode::Floor,
+            version: 0,
+            effective_from: 0,
+        }
+    }
+
+    /// Calculates points for a weight in grams at gram-level precision
+    pub fn points_for(&self, weight: u64) -> u64 {
+        let scaled = (weight as u128) * (self.points_per_kg as u128);
+        let points = self.rounding.divide(scaled, 1000);
+        u64::try_from(points).expect("Overflow in reward calculation")
+    }
+}
+
+/// Emission factors used for carbon accounting of a waste type
+/// All emissions are in grams of CO2-equivalent
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionFactor {
+    /// Waste type the factor applies to
+    pub waste_type: WasteType,
+    /// Emissions avoided per kilogram recycled instead of landfilled
+    pub avoided_per_kg: u64,
+    /// Emissions of moving one tonne of this waste one kilometre
+    pub transport_per_tonne_km: u64,
+    /// Timestamp of the last admin change (0 for the default)
+    pub updated_at: u64,
+}
+
+impl EmissionFactor {
+    /// Road freight emissions per tonne-kilometre used until configured
+    pub const DEFAULT_TRANSPORT_PER_TONNE_KM: u64 = 100;
+
+    /// Default factors for the built-in waste types
+    /// Categories added at runtime avoid nothing until the admin configures them
+    pub fn default_for(waste_type: WasteType) -> Self {
+        let avoided_per_kg = match waste_type {
+            WasteType::Paper => 900,
+            WasteType::PetPlastic => 1_500,
+            WasteType::Plastic => 1_400,
+            WasteType::Metal => 4_000,
+            WasteType::Glass => 300,
+            _ => 0,
+        };
+
+        Self {
+            waste_type,
+            avoided_per_kg,
+            transport_per_tonne_km: Self::DEFAULT_TRANSPORT_PER_TONNE_KM,
+            updated_at: 0,
+        }
+    }
+
+    /// Emissions avoided by recycling `weight` grams
+    pub fn avoided_for(&self, weight: u128) -> u128 {
+        weight * self.avoided_per_kg as u128 / 1_000
+    }
+
+    /// Emissions of moving `weight` grams over `distance` metres
+    pub fn transport_for(&self, weight: u128, distance: u128) -> u128 {
+        weight * distance * self.transport_per_tonne_km as u128 / 1_000_000_000
+    }
+}
+
+/// Running carbon account for a waste, a participant or the whole system
+/// Emissions are in grams of CO2-equivalent
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CarbonImpact {
+    /// Weight recycled in grams
+    pub recycled_weight: u128,
+    /// Emissions avoided by recycling
+    pub co2e_avoided: u128,
+    /// Distance travelled between transfer hops in metres
+    pub transport_distance: u128,
+    /// Emissions of moving the waste between hops
+    pub transport_co2e: u128,
+}
+
+impl CarbonImpact {
+    /// Records recycled weight and the emissions it avoided
+    pub fn record_avoided(&mut self, weight: u128, co2e: u128) {
+        self.recycled_weight += weight;
+        self.co2e_avoided += co2e;
+    }
+
+    /// Reverses a previously recorded recycling
+    pub fn remove_avoided(&mut self, weight: u128, co2e: u128) {
+        self.recycled_weight = self.recycled_weight.saturating_sub(weight);
+        self.co2e_avoided = self.co2e_avoided.saturating_sub(co2e);
+    }
+
+    /// Records a transport hop
+    pub fn record_transport(&mut self, distance: u128, co2e: u128) {
+        self.transport_distance += distance;
+        self.transport_co2e += co2e;
+    }
+
+    /// Avoided emissions net of transport; negative when hauling outweighed the benefit
+    pub fn net_co2e(&self) -> i128 {
+        self.co2e_avoided as i128 - self.transport_co2e as i128
     }
 }
 
@@ -415,6 +803,142 @@ impl core::fmt::Display for WasteType {
     }
 }
 
+/// Finer-grained classification of a waste within its waste type
+/// Covers plastic resin identification codes 1-7, glass colours and metal alloys
+/// Numbered 0-12; resin sub-types share their resin code
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WasteSubType {
+    /// No sub-category recorded
+    Unspecified = 0,
+    /// #1 PET - polyethylene terephthalate
+    Pet = 1,
+    /// #2 HDPE - high-density polyethylene
+    Hdpe = 2,
+    /// #3 PVC - polyvinyl chloride
+    Pvc = 3,
+    /// #4 LDPE - low-density polyethylene
+    Ldpe = 4,
+    /// #5 PP - polypropylene
+    Pp = 5,
+    /// #6 PS - polystyrene
+    Ps = 6,
+    /// #7 Other resins
+    OtherResin = 7,
+    /// Clear (flint) glass
+    ClearGlass = 8,
+    /// Green glass
+    GreenGlass = 9,
+    /// Brown (amber) glass
+    BrownGlass = 10,
+    /// Aluminium
+    Aluminium = 11,
+    /// Steel
+    Steel = 12,
+}
+
+impl WasteSubType {
+    /// Highest sub-type discriminant
+    pub const MAX_VALUE: u32 = 12;
+
+    /// Validates if the value corresponds to a valid WasteSubType
+    pub fn is_valid(value: u32) -> bool {
+        value <= Self::MAX_VALUE
+    }
+
+    /// Converts a u32 to a WasteSubType
+    /// Returns None if the value is invalid
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(WasteSubType::Unspecified),
+            1 => Some(WasteSubType::Pet),
+            2 => Some(WasteSubType::Hdpe),
+            3 => Some(WasteSubType::Pvc),
+            4 => Some(WasteSubType::Ldpe),
+            5 => Some(WasteSubType::Pp),
+            6 => Some(WasteSubType::Ps),
+            7 => Some(WasteSubType::OtherResin),
+            8 => Some(WasteSubType::ClearGlass),
+            9 => Some(WasteSubType::GreenGlass),
+            10 => Some(WasteSubType::BrownGlass),
+            11 => Some(WasteSubType::Aluminium),
+            12 => Some(WasteSubType::Steel),
+            _ => None,
+        }
+    }
+
+    /// Converts the WasteSubType to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the sub-type
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WasteSubType::Unspecified => "UNSPECIFIED",
+            WasteSubType::Pet => "PET",
+            WasteSubType::Hdpe => "HDPE",
+            WasteSubType::Pvc => "PVC",
+            WasteSubType::Ldpe => "LDPE",
+            WasteSubType::Pp => "PP",
+            WasteSubType::Ps => "PS",
+            WasteSubType::OtherResin => "OTHER_RESIN",
+            WasteSubType::ClearGlass => "CLEAR_GLASS",
+            WasteSubType::GreenGlass => "GREEN_GLASS",
+            WasteSubType::BrownGlass => "BROWN_GLASS",
+            WasteSubType::Aluminium => "ALUMINIUM",
+            WasteSubType::Steel => "STEEL",
+        }
+    }
+
+    /// Returns the resin identification code for plastic sub-types
+    pub fn resin_code(&self) -> Option<u32> {
+        if (1..=7).contains(&self.to_u32()) {
+            Some(self.to_u32())
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the sub-type refines the given waste type
+    /// PET (#1) belongs to PetPlastic and the remaining resin codes to Plastic;
+    /// Unspecified applies to every waste type
+    pub fn applies_to(&self, waste_type: WasteType) -> bool {
+        match self {
+            WasteSubType::Unspecified => true,
+            WasteSubType::Pet => waste_type == WasteType::PetPlastic,
+            WasteSubType::Hdpe
+            | WasteSubType::Pvc
+            | WasteSubType::Ldpe
+            | WasteSubType::Pp
+            | WasteSubType::Ps
+            | WasteSubType::OtherResin => waste_type == WasteType::Plastic,
+            WasteSubType::ClearGlass | WasteSubType::GreenGlass | WasteSubType::BrownGlass => {
+                waste_type == WasteType::Glass
+            }
+            WasteSubType::Aluminium | WasteSubType::Steel => waste_type == WasteType::Metal,
+        }
+    }
+}
+
+impl core::fmt::Display for WasteSubType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Aggregate of active wastes tagged with a sub-type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubTypeStats {
+    /// Sub-type the figures refer to
+    pub sub_type: WasteSubType,
+    /// Number of active wastes tagged with the sub-type
+    pub waste_count: u64,
+    /// Combined weight of those wastes in grams
+    pub total_weight: u128,
+}
+
 /// Represents a recyclable material submission in the system
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -467,18 +991,99 @@ impl Material {
     }
 
     /// Calculates reward points based on waste type and weight
-    /// Different waste types have different point multipliers
-    pub fn calculate_reward_points(&self) -> u64 {
-        let multiplier = match self.waste_type {
-            WasteType::Paper => 1,
-            WasteType::PetPlastic => 3,
-            WasteType::Plastic => 2,
-            WasteType::Metal => 5,
-            WasteType::Glass => 2,
-        };
+    /// Uses the catalogue's reward multiplier for the waste type
+    pub fn calculate_reward_points(&self, env: &Env) -> u64 {
+        self.reward_points(&self.default_reward_rate(env))
+    }
+
+    /// Calculates reward points using a reward table entry
+    pub fn reward_points(&self, rate: &RewardRate) -> u64 {
+        rate.points_for(self.weight)
+    }
+
+    /// Default reward rate for the material's waste type, taken from the
+    /// catalogue multiplier
+    pub fn default_reward_rate(&self, env: &Env) -> RewardRate {
+        RewardRate::standard(self.waste_type, self.waste_type.reward_multiplier(env))
+    }
+}
+
+/// Represents the lifecycle stage of a waste item
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WasteStatus {
+    /// Waste has been registered by a recycler
+    Registered = 0,
+    /// Waste has been picked up by a collector
+    Collected = 1,
+    /// Waste has been delivered to a manufacturer
+    Delivered = 2,
+    /// Waste has been processed by the manufacturer
+    Processed = 3,
+    /// Processed waste has been turned into new products
+    Recycled = 4,
+    /// Waste was rejected by the receiving manufacturer
+    Rejected = 5,
+    /// Waste was removed from circulation
+    Deactivated = 6,
+}
+
+impl WasteStatus {
+    /// Validates if the value is a valid WasteStatus variant
+    pub fn is_valid(value: u32) -> bool {
+        matches!(value, 0..=6)
+    }
+
+    /// Converts a u32 to a WasteStatus
+    /// Returns None if the value is invalid
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(WasteStatus::Registered),
+            1 => Some(WasteStatus::Collected),
+            2 => Some(WasteStatus::Delivered),
+            3 => Some(WasteStatus::Processed),
+            4 => Some(WasteStatus::Recycled),
+            5 => Some(WasteStatus::Rejected),
+            6 => Some(WasteStatus::Deactivated),
+            _ => None,
+        }
+    }
+
+    /// Converts the WasteStatus to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WasteStatus::Registered => "REGISTERED",
+            WasteStatus::Collected => "COLLECTED",
+            WasteStatus::Delivered => "DELIVERED",
+            WasteStatus::Processed => "PROCESSED",
+            WasteStatus::Recycled => "RECYCLED",
+            WasteStatus::Rejected => "REJECTED",
+            WasteStatus::Deactivated => "DEACTIVATED",
+        }
+    }
 
-        // Points = (weight in kg) * multiplier * 10
-        (self.weight / 1000) * multiplier * 10
+    /// Checks if the waste can still move along the supply chain
+    pub fn is_in_transit(&self) -> bool {
+        matches!(self, WasteStatus::Registered | WasteStatus::Collected)
+    }
+
+    /// Checks if a move from this status to `next` is allowed
+    /// Any status except Deactivated may be deactivated
+    pub fn can_transition_to(&self, next: WasteStatus) -> bool {
+        matches!(
+            (self, next),
+            (WasteStatus::Registered, WasteStatus::Collected)
+                | (WasteStatus::Registered, WasteStatus::Delivered)
+                | (WasteStatus::Collected, WasteStatus::Delivered)
+                | (WasteStatus::Delivered, WasteStatus::Processed)
+                | (WasteStatus::Delivered, WasteStatus::Rejected)
+                | (WasteStatus::Processed, WasteStatus::Recycled)
+        ) || (*self != WasteStatus::Deactivated && next == WasteStatus::Deactivated)
     }
 }
 
@@ -507,6 +1112,10 @@ pub struct Waste {
     pub is_confirmed: bool,
     /// Address of the confirmer/verifier
     pub confirmer: Address,
+    /// Current lifecycle stage of the waste
+    pub status: WasteStatus,
+    /// Sub-category (resin code, glass colour or metal alloy), if recorded
+    pub sub_type: WasteSubType,
 }
 
 impl Waste {
@@ -534,18 +1143,18 @@ impl Waste {
             is_active,
             is_confirmed,
             confirmer,
+            status: if is_active {
+                WasteStatus::Registered
+            } else {
+                WasteStatus::Deactivated
+            },
+            sub_type: WasteSubType::Unspecified,
         }
     }
 
     /// Validates that the waste has valid coordinates
     pub fn has_valid_coordinates(&self) -> bool {
-        let max_lat = 90_000_000i128;
-        let max_lon = 180_000_000i128;
-        
-        self.latitude >= -max_lat 
-            && self.latitude <= max_lat
-            && self.longitude >= -max_lon
-            && self.longitude <= max_lon
+        crate::geo::is_valid_coordinates(self.latitude, self.longitude)
     }
 
     /// Checks if the waste has been recycled
@@ -575,55 +1184,726 @@ impl Waste {
         self.confirmer = self.current_owner.clone();
     }
 
-    /// Deactivates the waste
-    pub fn deactivate(&mut self) {
-        self.is_active = false;
+    /// Deactivates the waste
+    pub fn deactivate(&mut self) {
+        self.is_active = false;
+        self.status = WasteStatus::Deactivated;
+    }
+
+    /// Moves the waste to a new lifecycle status
+    /// Returns true if updated, false if the transition is not allowed
+    pub fn transition_to(&mut self, next: WasteStatus) -> bool {
+        if !self.status.can_transition_to(next) {
+            return false;
+        }
+        if next == WasteStatus::Deactivated {
+            self.is_active = false;
+        }
+        self.status = next;
+        true
+    }
+
+    /// Transfers ownership to a new owner
+    pub fn transfer_to(&mut self, new_owner: Address) {
+        self.current_owner = new_owner;
+    }
+
+    /// Updates the location of the waste
+    pub fn update_location(&mut self, latitude: i128, longitude: i128) {
+        self.latitude = latitude;
+        self.longitude = longitude;
+    }
+
+    /// Creates a child lot carrying a portion of this waste's weight
+    /// The child inherits type, owner, location and confirmation state
+    pub fn split_child(&self, child_id: u128, weight: u128) -> Self {
+        Self {
+            waste_id: child_id,
+            waste_type: self.waste_type,
+            weight,
+            current_owner: self.current_owner.clone(),
+            latitude: self.latitude,
+            longitude: self.longitude,
+            recycled_timestamp: self.recycled_timestamp,
+            is_active: true,
+            is_confirmed: self.is_confirmed,
+            confirmer: self.confirmer.clone(),
+            status: self.status,
+            sub_type: self.sub_type,
+        }
+    }
+}
+
+/// Transfer record for waste movement across the supply chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasteTransfer {
+    pub waste_id: u128,
+    pub from: Address,
+    pub to: Address,
+    pub transferred_at: u64,
+    pub latitude: i128,
+    pub longitude: i128,
+    pub note: Symbol,
+    pub evidence: Option<Bytes>,
+    pub measured_weight: u128,
+    pub discrepancy: i128,
+    pub flagged: bool,
+}
+
+impl WasteTransfer {
+    pub fn new(
+        waste_id: u128,
+        from: Address,
+        to: Address,
+        transferred_at: u64,
+        latitude: i128,
+        longitude: i128,
+        note: Symbol,
+    ) -> Self {
+        Self {
+            waste_id,
+            from,
+            to,
+            transferred_at,
+            latitude,
+            longitude,
+            note,
+            evidence: None,
+            measured_weight: 0,
+            discrepancy: 0,
+            flagged: false,
+        }
+    }
+
+    /// Checks if the receiver has recorded a handover weight
+    pub fn is_measured(&self) -> bool {
+        self.measured_weight > 0
+    }
+
+    /// Records the receiver's measured weight against the prior hop's weight
+    /// Flags the transfer when the difference exceeds the tolerance in basis points
+    pub fn record_measurement(&mut self, prior_weight: u128, measured_weight: u128, tolerance_bps: u32) {
+        self.measured_weight = measured_weight;
+        self.discrepancy = measured_weight as i128 - prior_weight as i128;
+
+        let allowed = prior_weight.saturating_mul(tolerance_bps as u128) / 10_000;
+        self.flagged = self.discrepancy.unsigned_abs() > allowed;
+    }
+
+    /// Weight both parties can stand behind after the handover
+    /// A flagged measurement falls back to the lower of the two readings
+    pub fn agreed_weight(&self, prior_weight: u128) -> u128 {
+        if self.flagged {
+            prior_weight.min(self.measured_weight)
+        } else {
+            self.measured_weight
+        }
+    }
+}
+
+/// Stage of the supply chain at which evidence was attached
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvidenceKind {
+    /// Attached when the waste was registered
+    Registration = 0,
+    /// Attached when the waste changed hands
+    Transfer = 1,
+    /// Attached when the waste was confirmed
+    Confirmation = 2,
+    /// Appended after the fact
+    Supplement = 3,
+    /// Attached when a dispute was opened
+    Dispute = 4,
+}
+
+/// Content hash of off-chain evidence such as photos, weigh tickets or IPFS CIDs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Evidence {
+    /// Content hash or CID bytes
+    pub hash: Bytes,
+    /// Stage the evidence was attached at
+    pub kind: EvidenceKind,
+    /// Address that attached the evidence
+    pub submitted_by: Address,
+    /// Timestamp when the evidence was attached
+    pub submitted_at: u64,
+}
+
+/// A quantity of processed waste consumed into a product batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchInput {
+    /// ID of the consumed waste
+    pub waste_id: u128,
+    /// Weight consumed from the waste in grams
+    pub weight: u128,
+}
+
+/// A finished product batch made by a manufacturer from recycled inputs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProductBatch {
+    /// Unique identifier for the batch
+    pub batch_id: u64,
+    /// Manufacturer that produced the batch
+    pub manufacturer: Address,
+    /// Short product label
+    pub product: Symbol,
+    /// Total weight of the finished batch in grams
+    pub total_weight: u128,
+    /// Combined weight of recycled inputs in grams
+    pub recycled_weight: u128,
+    /// Wastes consumed into the batch
+    pub inputs: Vec<BatchInput>,
+    /// Timestamp when the batch was recorded
+    pub created_at: u64,
+}
+
+impl ProductBatch {
+    /// Creates a new ProductBatch, summing the recycled input weight
+    pub fn new(
+        batch_id: u64,
+        manufacturer: Address,
+        product: Symbol,
+        total_weight: u128,
+        inputs: Vec<BatchInput>,
+        created_at: u64,
+    ) -> Self {
+        let mut recycled_weight: u128 = 0;
+        for input in inputs.iter() {
+            recycled_weight = recycled_weight
+                .checked_add(input.weight)
+                .expect("Overflow in recycled weight");
+        }
+
+        Self {
+            batch_id,
+            manufacturer,
+            product,
+            total_weight,
+            recycled_weight,
+            inputs,
+            created_at,
+        }
+    }
+
+    /// Calculates the recycled content of the batch (percentage)
+    pub fn recycled_content_percentage(&self) -> u32 {
+        (self.recycled_weight * 100)
+            .checked_div(self.total_weight)
+            .unwrap_or(0) as u32
+    }
+}
+
+/// Origin of one recycled input in a product batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProvenanceRecord {
+    /// ID of the waste consumed into the batch
+    pub waste_id: u128,
+    /// ID of the originally registered waste (differs after a split)
+    pub origin_waste_id: u128,
+    /// Recycler who originally registered the waste
+    pub recycler: Address,
+    /// Latitude where the waste was registered (scaled by 1e6)
+    pub latitude: i128,
+    /// Longitude where the waste was registered (scaled by 1e6)
+    pub longitude: i128,
+    /// Weight consumed into the batch in grams
+    pub weight: u128,
+    /// Custody chain from the recycler to the manufacturer
+    pub hops: Vec<WasteTransfer>,
+}
+
+/// Proof that recycling credits were permanently retired
+/// Retired credits are burned so the same tonnage cannot be claimed twice
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetirementCertificate {
+    /// Unique identifier for the certificate
+    pub certificate_id: u64,
+    /// Address that retired the credits
+    pub owner: Address,
+    /// Waste type the credits were denominated in
+    pub waste_type: WasteType,
+    /// Amount of credits retired in grams
+    pub grams: u128,
+    /// Timestamp when the credits were retired
+    pub retired_at: u64,
+}
+
+/// A smart scale whose ed25519 key is trusted to attest weights
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScaleDevice {
+    /// ed25519 public key of the device
+    pub public_key: BytesN<32>,
+    /// Participant the device is bound to, or None for a location-bound device
+    pub operator: Option<Address>,
+    /// Latitude of the installed device (scaled by 1e6)
+    pub latitude: i128,
+    /// Longitude of the installed device (scaled by 1e6)
+    pub longitude: i128,
+    /// Whether the device may still sign receipts
+    pub is_active: bool,
+    /// Timestamp when the device was registered
+    pub registered_at: u64,
+}
+
+/// Domain tag prefixed to every signed weigh receipt
+pub const RECEIPT_DOMAIN: &[u8; 16] = b"scavngr-receipt1";
+
+/// A weight reading signed by a registered scale device
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeighReceipt {
+    /// Public key of the signing device
+    pub device: BytesN<32>,
+    /// Waste type that was weighed
+    pub waste_type: WasteType,
+    /// Measured weight in grams
+    pub grams: u128,
+    /// Device timestamp of the reading
+    pub timestamp: u64,
+    /// Per-device nonce preventing receipt replay
+    pub nonce: u64,
+    /// ed25519 signature over the payload
+    pub signature: BytesN<64>,
+}
+
+impl WeighReceipt {
+    /// Builds the byte payload signed by the device:
+    /// `RECEIPT_DOMAIN`, the XDR of the verifying contract's address, then
+    /// waste type (u32), grams (u128), timestamp (u64) and nonce (u64), all big-endian
+    pub fn payload(&self, env: &Env, contract: &Address) -> Bytes {
+        let mut payload = Bytes::from_array(env, RECEIPT_DOMAIN);
+        payload.append(&contract.clone().to_xdr(env));
+        payload.extend_from_array(&self.waste_type.to_u32().to_be_bytes());
+        payload.extend_from_array(&self.grams.to_be_bytes());
+        payload.extend_from_array(&self.timestamp.to_be_bytes());
+        payload.extend_from_array(&self.nonce.to_be_bytes());
+        payload
+    }
+}
+
+/// Proof attached to a waste or material submission
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubmissionProof {
+    /// Hash of off-chain evidence such as photos or documents
+    Evidence(Bytes),
+    /// Weight reading signed by a registered scale
+    Receipt(WeighReceipt),
+    /// Both an evidence hash and a signed weight reading
+    Full(Bytes, WeighReceipt),
+}
+
+impl SubmissionProof {
+    /// The evidence hash, if the proof carries one
+    pub fn evidence(&self) -> Option<Bytes> {
+        match self {
+            SubmissionProof::Evidence(hash) | SubmissionProof::Full(hash, _) => Some(hash.clone()),
+            SubmissionProof::Receipt(_) => None,
+        }
+    }
+
+    /// The signed weigh receipt, if the proof carries one
+    pub fn receipt(&self) -> Option<WeighReceipt> {
+        match self {
+            SubmissionProof::Receipt(receipt) | SubmissionProof::Full(_, receipt) => {
+                Some(receipt.clone())
+            }
+            SubmissionProof::Evidence(_) => None,
+        }
+    }
+}
+
+/// Number of independent confirmations required for waste of a given type
+/// at or above a weight threshold
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuorumRule {
+    /// Waste type the rule applies to
+    pub waste_type: WasteType,
+    /// Lower bound of the weight band in grams (inclusive)
+    pub min_weight: u128,
+    /// Number of confirmations needed before the waste counts as confirmed
+    pub required: u32,
+    /// Whether every confirmer must hold a different participant role
+    pub distinct_roles: bool,
+}
+
+/// What a dispute challenges
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeTarget {
+    /// The confirmation of a waste via `confirm_waste_details`
+    Confirmation = 0,
+    /// The verification of a material via `verify_material`
+    Verification = 1,
+}
+
+/// Lifecycle of a dispute
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    /// Awaiting an arbitrator's decision
+    Open = 0,
+    /// The challenged confirmation or verification stands
+    Upheld = 1,
+    /// The challenged confirmation or verification was reversed
+    Overturned = 2,
+}
+
+impl DisputeStatus {
+    /// Validates if a u32 value corresponds to a valid DisputeStatus
+    pub fn is_valid(value: u32) -> bool {
+        value <= 2
+    }
+
+    /// Converts a u32 to a DisputeStatus
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(DisputeStatus::Open),
+            1 => Some(DisputeStatus::Upheld),
+            2 => Some(DisputeStatus::Overturned),
+            _ => None,
+        }
+    }
+
+    /// Converts the DisputeStatus to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the dispute status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DisputeStatus::Open => "OPEN",
+            DisputeStatus::Upheld => "UPHELD",
+            DisputeStatus::Overturned => "OVERTURNED",
+        }
+    }
+
+    /// Checks if the dispute has been decided
+    pub fn is_resolved(&self) -> bool {
+        !matches!(self, DisputeStatus::Open)
+    }
+}
+
+/// A bonded challenge against a waste confirmation or material verification
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    /// Unique identifier for the dispute
+    pub dispute_id: u64,
+    /// Waste or material being challenged
+    pub waste_id: u128,
+    /// Whether the confirmation or the verification is challenged
+    pub target: DisputeTarget,
+    /// Participant who opened the dispute
+    pub disputer: Address,
+    /// Confirmer or verifier whose decision is challenged
+    pub accused: Address,
+    /// Earned tokens locked by the disputer
+    pub bond: u128,
+    /// Content hash of the disputer's evidence
+    pub evidence: Bytes,
+    /// Current state of the dispute
+    pub status: DisputeStatus,
+    /// Arbitrator who resolved the dispute, if resolved
+    pub arbitrator: Option<Address>,
+    /// Timestamp when the dispute was opened
+    pub opened_at: u64,
+    /// Timestamp when the dispute was resolved (0 while open)
+    pub resolved_at: u64,
+}
+
+impl Dispute {
+    /// Records an arbitrator's decision on the dispute
+    pub fn resolve(&mut self, arbitrator: Address, overturn: bool, timestamp: u64) {
+        self.status = if overturn {
+            DisputeStatus::Overturned
+        } else {
+            DisputeStatus::Upheld
+        };
+        self.arbitrator = Some(arbitrator);
+        self.resolved_at = timestamp;
+    }
+}
+
+/// Lifecycle of a marketplace listing
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListingStatus {
+    /// Accepting offers
+    Open = 0,
+    /// An offer was accepted and the waste handed over
+    Sold = 1,
+    /// Withdrawn by the seller
+    Cancelled = 2,
+    /// Closed after its expiry passed without a sale
+    Expired = 3,
+}
+
+impl ListingStatus {
+    /// Validates if a u32 value corresponds to a valid ListingStatus
+    pub fn is_valid(value: u32) -> bool {
+        value <= 3
+    }
+
+    /// Converts a u32 to a ListingStatus
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(ListingStatus::Open),
+            1 => Some(ListingStatus::Sold),
+            2 => Some(ListingStatus::Cancelled),
+            3 => Some(ListingStatus::Expired),
+            _ => None,
+        }
+    }
+
+    /// Converts the ListingStatus to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the listing status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListingStatus::Open => "OPEN",
+            ListingStatus::Sold => "SOLD",
+            ListingStatus::Cancelled => "CANCELLED",
+            ListingStatus::Expired => "EXPIRED",
+        }
+    }
+}
+
+/// Lifecycle of an offer on a listing
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OfferStatus {
+    /// Payment is escrowed and the offer can be accepted
+    Pending = 0,
+    /// The seller accepted the offer and was paid
+    Accepted = 1,
+    /// The buyer withdrew the offer
+    Withdrawn = 2,
+    /// The listing closed without this offer and the payment was returned
+    Refunded = 3,
+}
+
+impl OfferStatus {
+    /// Validates if a u32 value corresponds to a valid OfferStatus
+    pub fn is_valid(value: u32) -> bool {
+        value <= 3
+    }
+
+    /// Converts a u32 to an OfferStatus
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(OfferStatus::Pending),
+            1 => Some(OfferStatus::Accepted),
+            2 => Some(OfferStatus::Withdrawn),
+            3 => Some(OfferStatus::Refunded),
+            _ => None,
+        }
+    }
+
+    /// Converts the OfferStatus to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the string representation of the offer status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OfferStatus::Pending => "PENDING",
+            OfferStatus::Accepted => "ACCEPTED",
+            OfferStatus::Withdrawn => "WITHDRAWN",
+            OfferStatus::Refunded => "REFUNDED",
+        }
+    }
+}
+
+/// A waste offered for sale on the marketplace
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    /// Unique identifier for the listing
+    pub listing_id: u64,
+    /// Waste being sold
+    pub waste_id: u128,
+    /// Owner of the waste when it was listed
+    pub seller: Address,
+    /// Type of the listed waste
+    pub waste_type: WasteType,
+    /// Asking price in the marketplace token
+    pub price: i128,
+    /// Where the waste can be picked up (scaled by 1e6)
+    pub latitude: i128,
+    pub longitude: i128,
+    /// Current state of the listing
+    pub status: ListingStatus,
+    /// Timestamp when the listing was created
+    pub created_at: u64,
+    /// Timestamp after which no offer can be accepted
+    pub expires_at: u64,
+}
+
+impl Listing {
+    /// Whether offers can be placed and accepted at `timestamp`
+    pub fn is_open_at(&self, timestamp: u64) -> bool {
+        self.status == ListingStatus::Open && timestamp < self.expires_at
+    }
+}
+
+/// A buyer's escrowed bid on a listing
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Offer {
+    /// Unique identifier for the offer
+    pub offer_id: u64,
+    /// Listing the offer was placed on
+    pub listing_id: u64,
+    /// Collector or manufacturer making the offer
+    pub buyer: Address,
+    /// Amount escrowed in the marketplace token
+    pub amount: i128,
+    /// Current state of the offer
+    pub status: OfferStatus,
+    /// Timestamp when the offer was placed
+    pub created_at: u64,
+    /// Timestamp after which the offer can no longer be accepted
+    pub expires_at: u64,
+}
+
+impl Offer {
+    /// Whether the seller can accept the offer at `timestamp`
+    pub fn is_live_at(&self, timestamp: u64) -> bool {
+        self.status == OfferStatus::Pending && timestamp < self.expires_at
+    }
+}
+
+/// Lifecycle of a manufacturer's standing buy order
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BuyOrderStatus {
+    /// Accepting fills
+    Open = 0,
+    /// The full quantity has been delivered
+    Filled = 1,
+    /// Withdrawn by the buyer
+    Cancelled = 2,
+    /// Closed after its expiry passed
+    Expired = 3,
+}
+
+impl BuyOrderStatus {
+    /// Validates if a u32 value corresponds to a valid BuyOrderStatus
+    pub fn is_valid(value: u32) -> bool {
+        value <= 3
+    }
+
+    /// Converts a u32 to a BuyOrderStatus
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(BuyOrderStatus::Open),
+            1 => Some(BuyOrderStatus::Filled),
+            2 => Some(BuyOrderStatus::Cancelled),
+            3 => Some(BuyOrderStatus::Expired),
+            _ => None,
+        }
+    }
+
+    /// Converts the BuyOrderStatus to u32
+    pub fn to_u32(&self) -> u32 {
+        *self as u32
     }
 
-    /// Transfers ownership to a new owner
-    pub fn transfer_to(&mut self, new_owner: Address) {
-        self.current_owner = new_owner;
+    /// Returns the string representation of the buy order status
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuyOrderStatus::Open => "OPEN",
+            BuyOrderStatus::Filled => "FILLED",
+            BuyOrderStatus::Cancelled => "CANCELLED",
+            BuyOrderStatus::Expired => "EXPIRED",
+        }
     }
+}
 
-    /// Updates the location of the waste
-    pub fn update_location(&mut self, latitude: i128, longitude: i128) {
-        self.latitude = latitude;
-        self.longitude = longitude;
-    }
+/// Terms a manufacturer posts a buy order with
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyOrderTerms {
+    /// Type of waste wanted
+    pub waste_type: WasteType,
+    /// Total weight wanted in grams
+    pub quantity: u128,
+    /// Price per kilogram in the marketplace token
+    pub price_per_kg: i128,
+    /// Centre of the delivery region (scaled by 1e6)
+    pub latitude: i128,
+    pub longitude: i128,
+    /// Radius of the delivery region in metres (0 accepts waste from anywhere)
+    pub radius: u64,
+    /// Seconds the order stays open after posting
+    pub duration: u64,
 }
 
-/// Transfer record for waste movement across the supply chain.
+/// Standing demand for a waste type, filled by owners of confirmed wastes
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct WasteTransfer {
-    pub waste_id: u128,
-    pub from: Address,
-    pub to: Address,
-    pub transferred_at: u64,
+pub struct BuyOrder {
+    /// Unique identifier for the order
+    pub order_id: u64,
+    /// Manufacturer buying the waste
+    pub buyer: Address,
+    /// Type of waste wanted
+    pub waste_type: WasteType,
+    /// Weight still wanted in grams
+    pub quantity_remaining: u128,
+    /// Price per kilogram in the marketplace token
+    pub price_per_kg: i128,
+    /// Centre of the delivery region (scaled by 1e6)
     pub latitude: i128,
     pub longitude: i128,
-    pub note: Symbol,
+    /// Radius of the delivery region in metres (0 accepts waste from anywhere)
+    pub radius: u64,
+    /// Payment still held in escrow for the remaining quantity
+    pub escrow: i128,
+    /// Current state of the order
+    pub status: BuyOrderStatus,
+    /// Timestamp when the order was posted
+    pub created_at: u64,
+    /// Timestamp after which the order can no longer be filled
+    pub expires_at: u64,
 }
 
-impl WasteTransfer {
-    pub fn new(
-        waste_id: u128,
-        from: Address,
-        to: Address,
-        transferred_at: u64,
-        latitude: i128,
-        longitude: i128,
-        note: Symbol,
-    ) -> Self {
-        Self {
-            waste_id,
-            from,
-            to,
-            transferred_at,
-            latitude,
-            longitude,
-            note,
-        }
+impl BuyOrder {
+    /// Whether the order can be filled at `timestamp`
+    pub fn is_open_at(&self, timestamp: u64) -> bool {
+        self.status == BuyOrderStatus::Open && timestamp < self.expires_at
+    }
+
+    /// Payment owed for `weight` grams at the order price
+    /// Part-kilogram amounts round up, in the seller's favour
+    pub fn cost_for(&self, weight: u128) -> i128 {
+        let value = i128::try_from(weight)
+            .ok()
+            .and_then(|weight| weight.checked_mul(self.price_per_kg))
+            .expect("Overflow in order value");
+        value / 1000 + i128::from(value % 1000 != 0)
+    }
+
+    /// Whether a waste at the given location falls within the order's region
+    pub fn covers(&self, latitude: i128, longitude: i128) -> bool {
+        self.radius == 0
+            || crate::geo::distance(self.latitude, self.longitude, latitude, longitude)
+                <= self.radius
     }
 }
 
@@ -710,6 +1990,12 @@ impl WasteBuilder {
             is_active: self.is_active,
             is_confirmed: self.is_confirmed,
             confirmer,
+            status: if self.is_active {
+                WasteStatus::Registered
+            } else {
+                WasteStatus::Deactivated
+            },
+            sub_type: WasteSubType::Unspecified,
         }
     }
 }
@@ -735,6 +2021,10 @@ pub struct RecyclingStats {
     pub plastic_count: u64,
     pub metal_count: u64,
     pub glass_count: u64,
+    /// Number of materials in categories added to the catalogue at runtime
+    pub other_count: u64,
+    /// Emissions avoided by verified materials in grams of CO2-equivalent
+    pub co2e_avoided: u128,
 }
 
 impl RecyclingStats {
@@ -751,6 +2041,8 @@ impl RecyclingStats {
             plastic_count: 0,
             metal_count: 0,
             glass_count: 0,
+            other_count: 0,
+            co2e_avoided: 0,
         }
     }
 
@@ -766,14 +2058,40 @@ impl RecyclingStats {
             WasteType::Plastic => self.plastic_count += 1,
             WasteType::Metal => self.metal_count += 1,
             WasteType::Glass => self.glass_count += 1,
+            _ => self.other_count += 1,
         }
     }
 
-    /// Records a material verification
-    pub fn record_verification(&mut self, material: &Material) {
+    /// Records a material verification at the reward rate that applied
+    pub fn record_verification(&mut self, material: &Material, rate: &RewardRate) {
         if material.verified {
             self.verified_submissions += 1;
-            self.total_points += material.calculate_reward_points();
+            self.total_points += material.reward_points(rate);
+        }
+    }
+
+    /// Reverses a verification that was overturned
+    pub fn remove_verification(&mut self, points: u64) {
+        self.verified_submissions = self.verified_submissions.saturating_sub(1);
+        self.total_points = self.total_points.saturating_sub(points);
+    }
+
+    /// Records the emissions avoided by a verified material
+    pub fn record_co2e_avoided(&mut self, co2e: u128) {
+        self.co2e_avoided += co2e;
+    }
+
+    /// Reverses emissions credited to a verification that was overturned
+    pub fn remove_co2e_avoided(&mut self, co2e: u128) {
+        self.co2e_avoided = self.co2e_avoided.saturating_sub(co2e);
+    }
+
+    /// Average emissions avoided per kilogram submitted
+    pub fn co2e_per_kg(&self) -> u128 {
+        if self.total_weight == 0 {
+            0
+        } else {
+            self.co2e_avoided * 1_000 / self.total_weight as u128
         }
     }
 
@@ -868,7 +2186,9 @@ mod recycling_stats_tests {
         let mut material = Material::new(1, WasteType::Metal, 5000, participant, 0, description);
 
         material.verify();
-        stats.record_verification(&material);
+        let contract_id = env.register_contract(None, crate::ScavengerContract);
+        let rate = env.as_contract(&contract_id, || material.default_reward_rate(&env));
+        stats.record_verification(&material, &rate);
 
         assert_eq!(stats.verified_submissions, 1);
         assert_eq!(stats.total_points, 250); // 5kg * 5 * 10
@@ -1031,6 +2351,7 @@ mod material_tests {
     #[test]
     fn test_calculate_reward_points() {
         let env = soroban_sdk::Env::default();
+        let contract_id = env.register_contract(None, crate::ScavengerContract);
         let submitter = Address::generate(&env);
         let description = String::from_str(&env, "Test");
 
@@ -1043,7 +2364,6 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(paper.calculate_reward_points(), 50);
 
         // PetPlastic: 5kg * 3 * 10 = 150 points
         let pet = Material::new(
@@ -1054,7 +2374,6 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(pet.calculate_reward_points(), 150);
 
         // Plastic: 5kg * 2 * 10 = 100 points
         let plastic = Material::new(
@@ -1065,7 +2384,6 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(plastic.calculate_reward_points(), 100);
 
         // Metal: 5kg * 5 * 10 = 250 points
         let metal = Material::new(
@@ -1076,11 +2394,18 @@ mod material_tests {
             0,
             description.clone(),
         );
-        assert_eq!(metal.calculate_reward_points(), 250);
 
         // Glass: 5kg * 2 * 10 = 100 points
         let glass = Material::new(5, WasteType::Glass, 5000, submitter, 0, description);
-        assert_eq!(glass.calculate_reward_points(), 100);
+
+        // Multipliers come from the built-in catalogue entries
+        env.as_contract(&contract_id, || {
+            assert_eq!(paper.calculate_reward_points(&env), 50);
+            assert_eq!(pet.calculate_reward_points(&env), 150);
+            assert_eq!(plastic.calculate_reward_points(&env), 100);
+            assert_eq!(metal.calculate_reward_points(&env), 250);
+            assert_eq!(glass.calculate_reward_points(&env), 100);
+        });
     }
 
     #[test]
@@ -1112,11 +2437,11 @@ mod waste_type_tests {
 
     #[test]
     fn test_waste_type_values() {
-        assert_eq!(WasteType::Paper as u32, 0);
-        assert_eq!(WasteType::PetPlastic as u32, 1);
-        assert_eq!(WasteType::Plastic as u32, 2);
-        assert_eq!(WasteType::Metal as u32, 3);
-        assert_eq!(WasteType::Glass as u32, 4);
+        assert_eq!(WasteType::Paper.to_u32(), 0);
+        assert_eq!(WasteType::PetPlastic.to_u32(), 1);
+        assert_eq!(WasteType::Plastic.to_u32(), 2);
+        assert_eq!(WasteType::Metal.to_u32(), 3);
+        assert_eq!(WasteType::Glass.to_u32(), 4);
     }
 
     #[test]
@@ -1171,29 +2496,44 @@ mod waste_type_tests {
 
     #[test]
     fn test_waste_type_is_plastic() {
-        assert!(!WasteType::Paper.is_plastic());
-        assert!(WasteType::PetPlastic.is_plastic());
-        assert!(WasteType::Plastic.is_plastic());
-        assert!(!WasteType::Metal.is_plastic());
-        assert!(!WasteType::Glass.is_plastic());
+        let env = soroban_sdk::Env::default();
+        let contract_id = env.register_contract(None, crate::ScavengerContract);
+
+        env.as_contract(&contract_id, || {
+            assert!(!WasteType::Paper.is_plastic(&env));
+            assert!(WasteType::PetPlastic.is_plastic(&env));
+            assert!(WasteType::Plastic.is_plastic(&env));
+            assert!(!WasteType::Metal.is_plastic(&env));
+            assert!(!WasteType::Glass.is_plastic(&env));
+        });
     }
 
     #[test]
     fn test_waste_type_is_biodegradable() {
-        assert!(WasteType::Paper.is_biodegradable());
-        assert!(!WasteType::PetPlastic.is_biodegradable());
-        assert!(!WasteType::Plastic.is_biodegradable());
-        assert!(!WasteType::Metal.is_biodegradable());
-        assert!(!WasteType::Glass.is_biodegradable());
+        let env = soroban_sdk::Env::default();
+        let contract_id = env.register_contract(None, crate::ScavengerContract);
+
+        env.as_contract(&contract_id, || {
+            assert!(WasteType::Paper.is_biodegradable(&env));
+            assert!(!WasteType::PetPlastic.is_biodegradable(&env));
+            assert!(!WasteType::Plastic.is_biodegradable(&env));
+            assert!(!WasteType::Metal.is_biodegradable(&env));
+            assert!(!WasteType::Glass.is_biodegradable(&env));
+        });
     }
 
     #[test]
     fn test_waste_type_is_infinitely_recyclable() {
-        assert!(!WasteType::Paper.is_infinitely_recyclable());
-        assert!(!WasteType::PetPlastic.is_infinitely_recyclable());
-        assert!(!WasteType::Plastic.is_infinitely_recyclable());
-        assert!(WasteType::Metal.is_infinitely_recyclable());
-        assert!(WasteType::Glass.is_infinitely_recyclable());
+        let env = soroban_sdk::Env::default();
+        let contract_id = env.register_contract(None, crate::ScavengerContract);
+
+        env.as_contract(&contract_id, || {
+            assert!(!WasteType::Paper.is_infinitely_recyclable(&env));
+            assert!(!WasteType::PetPlastic.is_infinitely_recyclable(&env));
+            assert!(!WasteType::Plastic.is_infinitely_recyclable(&env));
+            assert!(WasteType::Metal.is_infinitely_recyclable(&env));
+            assert!(WasteType::Glass.is_infinitely_recyclable(&env));
+        });
     }
 
     #[test]
@@ -1225,6 +2565,477 @@ mod waste_type_tests {
             assert_eq!(WasteType::from_u32(i as u32), Some(*waste_type));
         }
     }
+    #[test]
+    fn test_runtime_waste_type() {
+        let env = soroban_sdk::Env::default();
+        let contract_id = env.register_contract(None, crate::ScavengerContract);
+        let ewaste = WasteType(WasteType::BUILTIN_COUNT);
+        assert!(!ewaste.is_builtin());
+        assert_eq!(ewaste.as_str(), "CUSTOM");
+
+        env.as_contract(&contract_id, || {
+            // Unknown ids have no catalogue entry to read flags from
+            assert_eq!(ewaste.reward_multiplier(&env), 1);
+            assert!(!ewaste.is_plastic(&env));
+
+            // Once catalogued, the flags follow the stored entry
+            let category = WasteCategory {
+                id: ewaste.to_u32(),
+                name: String::from_str(&env, "EWASTE"),
+                is_plastic: true,
+                is_biodegradable: false,
+                is_infinitely_recyclable: false,
+                reward_multiplier: 4,
+                max_weight: DEFAULT_MAX_CATEGORY_WEIGHT,
+                is_active: true,
+            };
+            env.storage()
+                .instance()
+                .set(&("waste_category", ewaste.to_u32()), &category);
+
+            assert_eq!(ewaste.reward_multiplier(&env), 4);
+            assert!(ewaste.is_plastic(&env));
+            assert!(!ewaste.is_biodegradable(&env));
+        });
+    }
+
+    #[test]
+    fn test_builtin_category() {
+        let env = soroban_sdk::Env::default();
+        let category = WasteCategory::builtin(&env, WasteType::PetPlastic).unwrap();
+
+        assert_eq!(category.waste_type(), WasteType::PetPlastic);
+        assert_eq!(category.name, String::from_str(&env, "PETPLASTIC"));
+        assert!(category.is_plastic);
+        assert_eq!(category.reward_multiplier, 3);
+        assert!(category.allows_weight(DEFAULT_MAX_CATEGORY_WEIGHT));
+        assert!(!category.allows_weight(DEFAULT_MAX_CATEGORY_WEIGHT + 1));
+        assert_eq!(WasteCategory::builtin(&env, WasteType(7)), None);
+    }
+}
+
+#[cfg(test)]
+mod product_batch_tests {
+    use super::*;
+    use soroban_sdk::{symbol_short, testutils::Address as _, vec};
+
+    #[test]
+    fn test_product_batch_recycled_content() {
+        let env = soroban_sdk::Env::default();
+        let manufacturer = Address::generate(&env);
+        let inputs = vec![
+            &env,
+            BatchInput { waste_id: 1, weight: 3_000 },
+            BatchInput { waste_id: 2, weight: 1_500 },
+        ];
+
+        let batch = ProductBatch::new(1, manufacturer, symbol_short!("bottles"), 10_000, inputs, 0);
+
+        assert_eq!(batch.recycled_weight, 4_500);
+        assert_eq!(batch.recycled_content_percentage(), 45);
+    }
+
+    #[test]
+    fn test_product_batch_zero_weight() {
+        let env = soroban_sdk::Env::default();
+        let manufacturer = Address::generate(&env);
+
+        let batch = ProductBatch::new(1, manufacturer, symbol_short!("empty"), 0, Vec::new(&env), 0);
+
+        assert_eq!(batch.recycled_content_percentage(), 0);
+    }
+}
+
+#[cfg(test)]
+mod waste_status_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_waste_status_from_u32() {
+        assert_eq!(WasteStatus::from_u32(0), Some(WasteStatus::Registered));
+        assert_eq!(WasteStatus::from_u32(3), Some(WasteStatus::Processed));
+        assert_eq!(WasteStatus::from_u32(6), Some(WasteStatus::Deactivated));
+        assert_eq!(WasteStatus::from_u32(7), None);
+        assert!(WasteStatus::is_valid(6));
+        assert!(!WasteStatus::is_valid(7));
+    }
+
+    #[test]
+    fn test_waste_status_transitions() {
+        assert!(WasteStatus::Registered.can_transition_to(WasteStatus::Collected));
+        assert!(WasteStatus::Registered.can_transition_to(WasteStatus::Delivered));
+        assert!(WasteStatus::Collected.can_transition_to(WasteStatus::Delivered));
+        assert!(WasteStatus::Delivered.can_transition_to(WasteStatus::Processed));
+        assert!(WasteStatus::Delivered.can_transition_to(WasteStatus::Rejected));
+        assert!(WasteStatus::Processed.can_transition_to(WasteStatus::Recycled));
+
+        assert!(!WasteStatus::Registered.can_transition_to(WasteStatus::Processed));
+        assert!(!WasteStatus::Collected.can_transition_to(WasteStatus::Registered));
+        assert!(!WasteStatus::Processed.can_transition_to(WasteStatus::Delivered));
+        assert!(!WasteStatus::Rejected.can_transition_to(WasteStatus::Processed));
+    }
+
+    #[test]
+    fn test_waste_status_deactivation() {
+        assert!(WasteStatus::Registered.can_transition_to(WasteStatus::Deactivated));
+        assert!(WasteStatus::Recycled.can_transition_to(WasteStatus::Deactivated));
+        assert!(!WasteStatus::Deactivated.can_transition_to(WasteStatus::Deactivated));
+        assert!(!WasteStatus::Deactivated.can_transition_to(WasteStatus::Registered));
+    }
+
+    #[test]
+    fn test_waste_transition_to() {
+        let env = soroban_sdk::Env::default();
+        let owner = Address::generate(&env);
+        let mut waste = WasteBuilder::new(1, WasteType::Glass, 1000, owner).build();
+
+        assert_eq!(waste.status, WasteStatus::Registered);
+        assert!(!waste.transition_to(WasteStatus::Processed));
+        assert_eq!(waste.status, WasteStatus::Registered);
+
+        assert!(waste.transition_to(WasteStatus::Delivered));
+        assert!(waste.transition_to(WasteStatus::Processed));
+        assert!(waste.is_active);
+
+        assert!(waste.transition_to(WasteStatus::Deactivated));
+        assert!(!waste.is_active);
+    }
+}
+
+#[cfg(test)]
+mod weigh_receipt_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_payload_layout() {
+        let env = Env::default();
+        let receipt = WeighReceipt {
+            device: BytesN::from_array(&env, &[1; 32]),
+            waste_type: WasteType::Metal,
+            grams: 2_500,
+            timestamp: 7,
+            nonce: 9,
+            signature: BytesN::from_array(&env, &[0; 64]),
+        };
+
+        let contract = Address::generate(&env);
+        let payload = receipt.payload(&env, &contract);
+
+        // Domain tag and contract address come first
+        let contract_xdr = contract.to_xdr(&env);
+        let header = RECEIPT_DOMAIN.len() as u32 + contract_xdr.len();
+        assert_eq!(payload.slice(..16), Bytes::from_array(&env, RECEIPT_DOMAIN));
+        assert_eq!(payload.slice(16..header), contract_xdr);
+
+        assert_eq!(payload.len(), header + 4 + 16 + 8 + 8);
+        assert_eq!(payload.get(header + 3), Some(WasteType::Metal.to_u32() as u8));
+        assert_eq!(payload.get(header + 18), Some((2_500u128 >> 8) as u8));
+        assert_eq!(payload.get(header + 19), Some((2_500u128 & 0xff) as u8));
+        assert_eq!(payload.get(header + 35), Some(9));
+    }
+}
+
+#[cfg(test)]
+mod weight_measurement_tests {
+    use super::*;
+    use soroban_sdk::{symbol_short, testutils::Address as _, Env};
+
+    fn transfer(env: &Env) -> WasteTransfer {
+        WasteTransfer::new(
+            1,
+            Address::generate(env),
+            Address::generate(env),
+            0,
+            0,
+            0,
+            symbol_short!("transfer"),
+        )
+    }
+
+    #[test]
+    fn test_measurement_within_tolerance() {
+        let env = Env::default();
+        let mut record = transfer(&env);
+        assert!(!record.is_measured());
+
+        record.record_measurement(10_000, 9_600, 500);
+
+        assert!(record.is_measured());
+        assert_eq!(record.discrepancy, -400);
+        assert!(!record.flagged);
+        assert_eq!(record.agreed_weight(10_000), 9_600);
+    }
+
+    #[test]
+    fn test_measurement_over_tolerance() {
+        let env = Env::default();
+        let mut record = transfer(&env);
+
+        record.record_measurement(10_000, 12_000, 500);
+
+        assert_eq!(record.discrepancy, 2_000);
+        assert!(record.flagged);
+        assert_eq!(record.agreed_weight(10_000), 10_000);
+    }
+}
+
+#[cfg(test)]
+mod dispute_tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_dispute_status_conversions() {
+        assert!(DisputeStatus::is_valid(2));
+        assert!(!DisputeStatus::is_valid(3));
+        assert_eq!(DisputeStatus::from_u32(1), Some(DisputeStatus::Upheld));
+        assert_eq!(DisputeStatus::from_u32(3), None);
+        assert_eq!(DisputeStatus::Overturned.to_u32(), 2);
+        assert_eq!(DisputeStatus::Open.as_str(), "OPEN");
+    }
+
+    #[test]
+    fn test_dispute_resolve() {
+        let env = Env::default();
+        let arbitrator = Address::generate(&env);
+        let mut dispute = Dispute {
+            dispute_id: 1,
+            waste_id: 1,
+            target: DisputeTarget::Confirmation,
+            disputer: Address::generate(&env),
+            accused: Address::generate(&env),
+            bond: 100,
+            evidence: Bytes::from_array(&env, &[1; 32]),
+            status: DisputeStatus::Open,
+            arbitrator: None,
+            opened_at: 10,
+            resolved_at: 0,
+        };
+
+        assert!(!dispute.status.is_resolved());
+        dispute.resolve(arbitrator.clone(), true, 20);
+
+        assert_eq!(dispute.status, DisputeStatus::Overturned);
+        assert!(dispute.status.is_resolved());
+        assert_eq!(dispute.arbitrator, Some(arbitrator));
+        assert_eq!(dispute.resolved_at, 20);
+    }
+}
+
+#[cfg(test)]
+mod waste_sub_type_tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_type_conversions() {
+        assert!(WasteSubType::is_valid(0));
+        assert!(WasteSubType::is_valid(12));
+        assert!(!WasteSubType::is_valid(13));
+        assert_eq!(WasteSubType::from_u32(0), Some(WasteSubType::Unspecified));
+        assert_eq!(WasteSubType::from_u32(5), Some(WasteSubType::Pp));
+        assert_eq!(WasteSubType::from_u32(13), None);
+        assert_eq!(WasteSubType::Hdpe.to_u32(), 2);
+        assert_eq!(WasteSubType::GreenGlass.as_str(), "GREEN_GLASS");
+    }
+
+    #[test]
+    fn test_resin_codes() {
+        assert_eq!(WasteSubType::Pet.resin_code(), Some(1));
+        assert_eq!(WasteSubType::OtherResin.resin_code(), Some(7));
+        assert_eq!(WasteSubType::Steel.resin_code(), None);
+        assert_eq!(WasteSubType::Unspecified.resin_code(), None);
+    }
+
+    #[test]
+    fn test_sub_type_applies_to() {
+        assert!(WasteSubType::Pet.applies_to(WasteType::PetPlastic));
+        assert!(!WasteSubType::Pet.applies_to(WasteType::Plastic));
+        assert!(WasteSubType::Hdpe.applies_to(WasteType::Plastic));
+        assert!(WasteSubType::BrownGlass.applies_to(WasteType::Glass));
+        assert!(WasteSubType::Aluminium.applies_to(WasteType::Metal));
+        assert!(!WasteSubType::Aluminium.applies_to(WasteType::Paper));
+        assert!(!WasteSubType::Pp.applies_to(WasteType(7)));
+        assert!(WasteSubType::Unspecified.applies_to(WasteType(7)));
+    }
+}
+
+#[cfg(test)]
+mod reward_rate_tests {
+    use super::*;
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!(RoundingMode::Floor.divide(1_499, 1000), 1);
+        assert_eq!(RoundingMode::HalfUp.divide(1_499, 1000), 1);
+        assert_eq!(RoundingMode::HalfUp.divide(1_500, 1000), 2);
+        assert_eq!(RoundingMode::Ceil.divide(1_001, 1000), 2);
+        assert_eq!(RoundingMode::Ceil.divide(1_000, 1000), 1);
+        assert_eq!(RoundingMode::from_u32(1), Some(RoundingMode::HalfUp));
+        assert_eq!(RoundingMode::from_u32(3), None);
+        assert_eq!(RoundingMode::Ceil.as_str(), "CEIL");
+    }
+
+    #[test]
+    fn test_standard_rate_gram_precision() {
+        let rate = RewardRate::standard(WasteType::Metal, 5);
+
+        assert_eq!(rate.points_per_kg, 50);
+        assert_eq!(rate.points_for(5_000), 250);
+        // 1.55kg * 50 = 77.5 points
+        assert_eq!(rate.points_for(1_550), 77);
+    }
+
+    #[test]
+    fn test_rate_rounding() {
+        let mut rate = RewardRate::standard(WasteType::Paper, 1);
+        rate.rounding = RoundingMode::HalfUp;
+        // 1.25kg * 10 = 12.5 points
+        assert_eq!(rate.points_for(1_250), 13);
+
+        rate.rounding = RoundingMode::Ceil;
+        assert_eq!(rate.points_for(1_201), 13);
+    }
+}
+
+#[cfg(test)]
+mod carbon_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_emission_factors() {
+        let metal = EmissionFactor::default_for(WasteType::Metal);
+        // 2.5kg of metal at 4kg CO2e/kg
+        assert_eq!(metal.avoided_for(2_500), 10_000);
+        assert_eq!(EmissionFactor::default_for(WasteType(7)).avoided_for(2_500), 0);
+    }
+
+    #[test]
+    fn test_transport_emissions() {
+        let factor = EmissionFactor::default_for(WasteType::Paper);
+        // 500kg over 20km = 10 tonne-km
+        assert_eq!(factor.transport_for(500_000, 20_000), 1_000);
+        assert_eq!(factor.transport_for(1_000, 100), 0);
+    }
+
+    #[test]
+    fn test_carbon_impact_net() {
+        let mut impact = CarbonImpact::default();
+        impact.record_avoided(1_000, 300);
+        impact.record_transport(50_000, 500);
+        assert_eq!(impact.net_co2e(), -200);
+
+        impact.remove_avoided(1_000, 300);
+        assert_eq!(impact.recycled_weight, 0);
+        assert_eq!(impact.net_co2e(), -500);
+    }
+}
+
+#[cfg(test)]
+mod buy_order_tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn order(env: &Env, radius: u64) -> BuyOrder {
+        BuyOrder {
+            order_id: 1,
+            buyer: Address::generate(env),
+            waste_type: WasteType::Glass,
+            quantity_remaining: 2_000_000,
+            price_per_kg: 40,
+            latitude: 0,
+            longitude: 0,
+            radius,
+            escrow: 80_000,
+            status: BuyOrderStatus::Open,
+            created_at: 0,
+            expires_at: 100,
+        }
+    }
+
+    #[test]
+    fn test_cost_for() {
+        let env = Env::default();
+        let order = order(&env, 0);
+        assert_eq!(order.cost_for(2_000_000), 80_000);
+        assert_eq!(order.cost_for(1_550), 62);
+    }
+
+    #[test]
+    fn test_covers() {
+        let env = Env::default();
+        assert!(order(&env, 0).covers(80_000_000, 170_000_000));
+        // 0.4 degrees of latitude is about 44km
+        assert!(order(&env, 50_000).covers(400_000, 0));
+        assert!(!order(&env, 50_000).covers(500_000, 0));
+    }
+
+    #[test]
+    fn test_is_open_at() {
+        let env = Env::default();
+        let mut order = order(&env, 0);
+        assert!(order.is_open_at(99));
+        assert!(!order.is_open_at(100));
+        order.status = BuyOrderStatus::Filled;
+        assert!(!order.is_open_at(0));
+    }
+}
+
+#[cfg(test)]
+mod incentive_tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn incentive(env: &Env) -> Incentive {
+        Incentive {
+            id: 1,
+            waste_type: WasteType::Metal,
+            reward: 100,
+            max_waste_amount: 10_000,
+            total_budget: 1_500,
+            remaining_budget: 1_500,
+            rewarder: Address::generate(env),
+            is_active: true,
+            created_at: 0,
+            requires_attestation: false,
+            sub_types: Vec::new(env),
+        }
+    }
+
+    #[test]
+    fn test_reward_for_caps_weight() {
+        let env = Env::default();
+        let incentive = incentive(&env);
+        assert_eq!(incentive.reward_for(5_000), 500);
+        assert_eq!(incentive.reward_for(50_000), 1_000);
+        assert_eq!(incentive.reward_for(999), 99);
+    }
+
+    #[test]
+    fn test_draw_exhausts_budget() {
+        let env = Env::default();
+        let mut incentive = incentive(&env);
+        assert!(!incentive.draw(1_000));
+        assert!(incentive.is_active);
+        assert!(incentive.draw(500));
+        assert!(!incentive.is_active);
+        assert_eq!(incentive.budget_used(), 1_500);
+    }
+
+    #[test]
+    fn test_rebudget_keeps_used_amount() {
+        let env = Env::default();
+        let mut incentive = incentive(&env);
+        incentive.draw(1_000);
+
+        assert!(!incentive.rebudget(3_000));
+        assert_eq!(incentive.remaining_budget, 2_000);
+
+        assert!(incentive.rebudget(800));
+        assert_eq!(incentive.remaining_budget, 0);
+        assert!(!incentive.is_active);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,243 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+use stellar_scavngr_contract::{
+    IncentiveScope, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteSubType,
+    WasteType,
+};
+
+// Central Lagos, scaled by 1e6
+const LAT: i128 = 6_524_000;
+const LON: i128 = 3_379_000;
+
+struct Market<'a> {
+    client: ScavengerContractClient<'a>,
+    minter: token::StellarAssetClient<'a>,
+    first: Address,
+    second: Address,
+    collector: Address,
+    recycler: Address,
+}
+
+impl Market<'_> {
+    /// Create a Paper incentive and escrow its whole budget
+    fn funded(&self, rewarder: &Address, reward: u128, max_waste_amount: u128, budget: u128) -> u64 {
+        self.funded_for(WasteType::Paper, rewarder, reward, max_waste_amount, budget)
+    }
+
+    /// Create an incentive for a waste type and escrow its whole budget
+    fn funded_for(
+        &self,
+        waste_type: WasteType,
+        rewarder: &Address,
+        reward: u128,
+        max_waste_amount: u128,
+        budget: u128,
+    ) -> u64 {
+        let id = self
+            .client
+            .create_incentive(&waste_type, &reward, &max_waste_amount, &budget, rewarder)
+            .id;
+        self.minter.mint(rewarder, &(budget as i128));
+        self.client.top_up_incentive(&id, &(budget as i128));
+        id
+    }
+}
+
+fn setup(env: &Env) -> Market<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let first = Address::generate(env);
+    let second = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&first, &ParticipantRole::Manufacturer, &symbol_short!("first"), &0, &0);
+    client.register_participant(&second, &ParticipantRole::Manufacturer, &symbol_short!("second"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    client.set_market_token(&admin, &token_id);
+
+    Market {
+        client,
+        minter: token::StellarAssetClient::new(env, &token_id),
+        first,
+        second,
+        collector,
+        recycler,
+    }
+}
+
+#[test]
+fn test_ranked_by_effective_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    // The highest rate only pays for the first kilogram
+    let capped = m.funded(&m.first, 100, 1_000, 10_000);
+    let steady = m.funded(&m.second, 50, 10_000, 10_000);
+    let modest = m.funded(&m.first, 30, 10_000, 10_000);
+
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &5_000, &0, &0, &None);
+    assert_eq!(quotes.len(), 3);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, steady);
+    assert_eq!(quotes.get(0).unwrap().payout, 250);
+    assert_eq!(quotes.get(0).unwrap().rewarder, m.second);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, modest);
+    assert_eq!(quotes.get(1).unwrap().payout, 150);
+    assert_eq!(quotes.get(2).unwrap().incentive_id, capped);
+    assert_eq!(quotes.get(2).unwrap().payout, 100);
+}
+
+#[test]
+fn test_ties_keep_oldest_first() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let older = m.funded(&m.second, 40, 10_000, 10_000);
+    let newer = m.funded(&m.first, 40, 10_000, 10_000);
+
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &2_000, &0, &0, &None);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, older);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, newer);
+}
+
+#[test]
+fn test_skips_incentives_that_cannot_pay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let payable = m.funded(&m.first, 10, 10_000, 10_000);
+
+    // Budget too small for the delivery
+    m.funded(&m.second, 90, 10_000, 100);
+    // Budget set but nothing escrowed
+    m.client.create_incentive(&WasteType::Paper, &80, &10_000, &10_000, &m.second);
+    // Closed
+    let closed = m.funded(&m.second, 70, 10_000, 10_000);
+    m.client.close_incentive(&closed);
+
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &5_000, &0, &0, &None);
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, payable);
+
+    // Restricted to a sub-type the delivery does not name
+    let targeted = m.funded_for(WasteType::Plastic, &m.second, 60, 10_000, 10_000);
+    m.client.set_incentive_sub_types(&targeted, &vec![&env, WasteSubType::Hdpe]);
+    assert_eq!(m.client.get_best_incentives(&WasteType::Plastic, &5_000, &0, &0, &None).len(), 0);
+}
+
+#[test]
+fn test_material_quotes_respect_attestation_and_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let open = m.funded(&m.first, 20, 10_000, 10_000);
+    let attested_only = m.funded(&m.second, 50, 10_000, 10_000);
+    m.client.set_incentive_attestation(&attested_only, &true);
+
+    let desc = String::from_str(&env, "bale");
+    let material = m.client.submit_material(&WasteType::Paper, &5_000, &m.collector, &desc, &None);
+
+    let quotes = m.client.get_best_incentives_for_material(&material.id);
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, open);
+    assert_eq!(quotes.get(0).unwrap().payout, 100);
+
+    // Once claimed there is nothing left to route
+    m.client.verify_material(&material.id, &m.recycler);
+    m.client.transfer_waste(&material.id, &m.collector, &m.first, &desc);
+    m.client.claim_incentive_reward(&open, &material.id, &m.collector);
+    assert_eq!(m.client.get_best_incentives_for_material(&material.id).len(), 0);
+}
+
+#[test]
+fn test_expiry_region_and_own_incentives_filter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let open = m.funded(&m.first, 10, 10_000, 10_000);
+    let expiring = m.funded(&m.second, 90, 10_000, 10_000);
+    m.client.set_incentive_scope(&expiring, &IncentiveScope { expires_at: 1_000, ..Default::default() });
+    // Only pays within 50km of Lagos
+    let lagos = m.funded(&m.second, 80, 10_000, 10_000);
+    m.client.set_incentive_scope(
+        &lagos,
+        &IncentiveScope { expires_at: 0, latitude: LAT, longitude: LON, radius: 50_000 },
+    );
+
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &5_000, &0, &0, &None);
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, expiring);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, open);
+
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &5_000, &LAT, &LON, &None);
+    assert_eq!(quotes.len(), 3);
+    assert_eq!(quotes.get(1).unwrap().incentive_id, lagos);
+
+    // A manufacturer is not offered its own incentives
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &5_000, &LAT, &LON, &Some(m.second.clone()));
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, open);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let quotes = m.client.get_best_incentives(&WasteType::Paper, &5_000, &LAT, &LON, &None);
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes.get(0).unwrap().incentive_id, lagos);
+}
+
+#[test]
+#[should_panic(expected = "Incentive expired")]
+fn test_expired_incentive_cannot_be_claimed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let incentive = m.funded(&m.first, 20, 10_000, 10_000);
+    m.client.set_incentive_scope(&incentive, &IncentiveScope { expires_at: 1_000, ..Default::default() });
+
+    let desc = String::from_str(&env, "bale");
+    let material = m.client.submit_material(&WasteType::Paper, &5_000, &m.collector, &desc, &None);
+    m.client.verify_material(&material.id, &m.recycler);
+    m.client.transfer_waste(&material.id, &m.collector, &m.first, &desc);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    m.client.claim_incentive_reward(&incentive, &material.id, &m.collector);
+}
+
+#[test]
+#[should_panic(expected = "Supplier outside incentive region")]
+fn test_out_of_region_supplier_cannot_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let incentive = m.funded(&m.first, 20, 10_000, 10_000);
+    m.client.set_incentive_scope(
+        &incentive,
+        &IncentiveScope { expires_at: 0, latitude: LAT, longitude: LON, radius: 50_000 },
+    );
+
+    // The collector is registered at (0, 0), far outside the region
+    let desc = String::from_str(&env, "bale");
+    let material = m.client.submit_material(&WasteType::Paper, &5_000, &m.collector, &desc, &None);
+    assert_eq!(m.client.get_best_incentives_for_material(&material.id).len(), 0);
+
+    m.client.verify_material(&material.id, &m.recycler);
+    m.client.transfer_waste(&material.id, &m.collector, &m.first, &desc);
+    m.client.claim_incentive_reward(&incentive, &material.id, &m.collector);
+}
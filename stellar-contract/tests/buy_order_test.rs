@@ -0,0 +1,228 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env,
+};
+use stellar_scavngr_contract::{
+    BuyOrderStatus, BuyOrderTerms, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+// Central Lagos, scaled by 1e6
+const LAT: i128 = 6_524_000;
+const LON: i128 = 3_379_000;
+
+/// Glass buy order terms at 40/kg, open for an hour
+fn glass_terms(quantity: u128, latitude: i128, longitude: i128, radius: u64) -> BuyOrderTerms {
+    BuyOrderTerms {
+        waste_type: WasteType::Glass,
+        quantity,
+        price_per_kg: 40,
+        latitude,
+        longitude,
+        radius,
+        duration: 3_600,
+    }
+}
+
+struct Book<'a> {
+    env: &'a Env,
+    client: ScavengerContractClient<'a>,
+    token: token::Client<'a>,
+    recycler: Address,
+    collector: Address,
+    manufacturer: Address,
+}
+
+impl Book<'_> {
+    /// Register a waste with the recycler and have the collector confirm it
+    fn confirmed_waste(&self, weight: u128, latitude: i128) -> u128 {
+        let waste_id = self.client.recycle_waste(&WasteType::Glass, &weight, &self.recycler, &latitude, &LON, &None);
+        self.client.confirm_waste_details(&waste_id, &self.collector, &None);
+        waste_id
+    }
+
+    /// Post the standing order from the request: 2t of Glass at 40/kg within 50km
+    fn post(&self) -> u64 {
+        self.client
+            .post_buy_order(&self.manufacturer, &glass_terms(2_000_000, LAT, LON, 50_000))
+            .order_id
+    }
+}
+
+fn setup(env: &Env) -> Book<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &LAT, &LON);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &LAT, &LON);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &LAT, &LON);
+
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    token::StellarAssetClient::new(env, &token_id).mint(&manufacturer, &100_000);
+    client.set_market_token(&admin, &token_id);
+
+    Book {
+        env,
+        client,
+        token: token::Client::new(env, &token_id),
+        recycler,
+        collector,
+        manufacturer,
+    }
+}
+
+#[test]
+fn test_post_buy_order_escrows_value() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book.post();
+    let order = book.client.get_buy_order(&order_id).unwrap();
+    assert_eq!(order.escrow, 80_000);
+    assert_eq!(order.status, BuyOrderStatus::Open);
+    assert_eq!(book.token.balance(&book.manufacturer), 20_000);
+    assert_eq!(book.client.get_buy_orders_by_type(&WasteType::Glass), vec![book.env, order]);
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+fn test_fill_pays_transfers_and_depletes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book.post();
+    let waste_id = book.confirmed_waste(500_000, LAT);
+
+    let transfer = book.client.fill_buy_order(&book.recycler, &order_id, &waste_id);
+    assert_eq!(transfer.to, book.manufacturer);
+    assert_eq!(book.client.get_waste_v2(&waste_id).unwrap().current_owner, book.manufacturer);
+    assert_eq!(book.token.balance(&book.recycler), 20_000);
+
+    let order = book.client.get_buy_order(&order_id).unwrap();
+    assert_eq!(order.quantity_remaining, 1_500_000);
+    assert_eq!(order.escrow, 60_000);
+    assert_eq!(order.status, BuyOrderStatus::Open);
+}
+
+#[test]
+fn test_final_fill_closes_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book
+        .client
+        .post_buy_order(&book.manufacturer, &glass_terms(1_000, 0, 0, 0))
+        .order_id;
+    let waste_id = book.confirmed_waste(1_000, LAT);
+    book.client.fill_buy_order(&book.recycler, &order_id, &waste_id);
+
+    let order = book.client.get_buy_order(&order_id).unwrap();
+    assert_eq!(order.status, BuyOrderStatus::Filled);
+    assert_eq!(order.escrow, 0);
+    assert_eq!(book.client.get_buy_orders_by_type(&WasteType::Glass).len(), 0);
+}
+
+#[test]
+fn test_part_kilogram_fill_rounds_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book
+        .client
+        .post_buy_order(&book.manufacturer, &glass_terms(1_000, 0, 0, 0))
+        .order_id;
+
+    // 10g at 40/kg is worth 0.4, which must not be paid as nothing
+    let waste_id = book.confirmed_waste(10, LAT);
+    book.client.fill_buy_order(&book.recycler, &order_id, &waste_id);
+    assert_eq!(book.token.balance(&book.recycler), 1);
+
+    // The remaining 990g is paid from what is left in escrow
+    let waste_id = book.confirmed_waste(990, LAT);
+    book.client.fill_buy_order(&book.recycler, &order_id, &waste_id);
+    assert_eq!(book.token.balance(&book.recycler), 40);
+    assert_eq!(book.client.get_buy_order(&order_id).unwrap().escrow, 0);
+}
+
+#[test]
+fn test_matching_orders_respect_region_and_quantity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book.post();
+    let near = book.confirmed_waste(1_000, LAT);
+    // A degree north is about 111km away
+    let far = book.confirmed_waste(1_000, LAT + 1_000_000);
+    let heavy = book.confirmed_waste(3_000_000, LAT);
+
+    let matches = book.client.get_matching_buy_orders(&near);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches.get(0).unwrap().order_id, order_id);
+    assert_eq!(book.client.get_matching_buy_orders(&far).len(), 0);
+    assert_eq!(book.client.get_matching_buy_orders(&heavy).len(), 0);
+}
+
+#[test]
+fn test_cancel_and_expire_refund_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let first = book.post();
+    let order = book.client.cancel_buy_order(&book.manufacturer, &first);
+    assert_eq!(order.status, BuyOrderStatus::Cancelled);
+    assert_eq!(book.token.balance(&book.manufacturer), 100_000);
+
+    let second = book.post();
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    let order = book.client.expire_buy_order(&second);
+    assert_eq!(order.status, BuyOrderStatus::Expired);
+    assert_eq!(book.token.balance(&book.manufacturer), 100_000);
+}
+
+#[test]
+#[should_panic(expected = "Waste is not confirmed")]
+fn test_fill_requires_confirmed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book.post();
+    let waste_id = book.client.recycle_waste(&WasteType::Glass, &1_000, &book.recycler, &LAT, &LON, &None);
+    book.client.fill_buy_order(&book.recycler, &order_id, &waste_id);
+}
+
+#[test]
+#[should_panic(expected = "Waste is outside the order region")]
+fn test_fill_rejects_waste_outside_region() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    let order_id = book.post();
+    let waste_id = book.confirmed_waste(1_000, LAT + 1_000_000);
+    book.client.fill_buy_order(&book.recycler, &order_id, &waste_id);
+}
+
+#[test]
+#[should_panic(expected = "Only manufacturers can post buy orders")]
+fn test_only_manufacturers_post_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let book = setup(&env);
+
+    book.client.post_buy_order(&book.collector, &glass_terms(1_000, 0, 0, 0));
+}
@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Events}, Address, Env, String,
+};
+use stellar_scavngr_contract::{
+    CarbonImpact, ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+// One degree of latitude is 111,195 metres
+const DEPOT_LAT: i128 = 1_000_000;
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, admin, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_default_emission_factor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _recycler, _collector, _manufacturer) = setup(&env);
+
+    let factor = client.get_emission_factor(&WasteType::Paper);
+    assert_eq!(factor.avoided_per_kg, 900);
+    assert_eq!(factor.transport_per_tonne_km, 100);
+    assert_eq!(factor.updated_at, 0);
+}
+
+#[test]
+fn test_waste_impact_over_its_journey() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, manufacturer) = setup(&env);
+
+    // 500kg of paper hauled to a depot a degree north and back again
+    let waste_id = client.recycle_waste(&WasteType::Paper, &500_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &DEPOT_LAT, &0, &None);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0, &None);
+
+    let impact = client.get_waste_impact(&waste_id);
+    assert_eq!(impact.transport_distance, 222_390);
+    assert_eq!(impact.transport_co2e, 11_118);
+    assert_eq!(impact.co2e_avoided, 0);
+
+    client.mark_processed(&waste_id, &manufacturer);
+
+    let impact = client.get_waste_impact(&waste_id);
+    assert_eq!(impact.recycled_weight, 500_000);
+    assert_eq!(impact.co2e_avoided, 450_000);
+    assert_eq!(impact.net_co2e(), 438_882);
+
+    // Avoided emissions go to the recycler, each leg to whoever received it
+    assert_eq!(client.get_participant_impact(&recycler).co2e_avoided, 450_000);
+    assert_eq!(client.get_participant_impact(&recycler).transport_co2e, 0);
+    assert_eq!(client.get_participant_impact(&collector).transport_co2e, 5_559);
+    assert_eq!(client.get_participant_impact(&manufacturer).transport_co2e, 5_559);
+    assert_eq!(client.get_global_impact(), impact);
+}
+
+#[test]
+fn test_verified_material_counts_towards_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let material = client.submit_material(
+        &WasteType::Metal,
+        &2_500,
+        &collector,
+        &String::from_str(&env, "cans"),
+        &None);
+    assert_eq!(client.get_global_impact(), CarbonImpact::default());
+
+    client.verify_material(&material.id, &recycler);
+
+    let stats = client.get_stats(&collector).unwrap();
+    assert_eq!(stats.co2e_avoided, 10_000);
+    assert_eq!(stats.co2e_per_kg(), 4_000);
+    assert_eq!(client.get_participant_impact(&collector).co2e_avoided, 10_000);
+    assert_eq!(client.get_global_impact().recycled_weight, 2_500);
+}
+
+#[test]
+fn test_set_emission_factor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector, manufacturer) = setup(&env);
+
+    let factor = client.set_emission_factor(&admin, &WasteType::Glass, &500, &0);
+    assert_eq!(client.get_emission_factor(&WasteType::Glass), factor);
+    assert!(!env.events().all().is_empty());
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &DEPOT_LAT, &0, &None);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0, &None);
+    client.mark_processed(&waste_id, &manufacturer);
+
+    let impact = client.get_waste_impact(&waste_id);
+    assert_eq!(impact.transport_co2e, 0);
+    assert_eq!(impact.co2e_avoided, 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_emission_factor_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, _manufacturer) = setup(&env);
+
+    client.set_emission_factor(&recycler, &WasteType::Glass, &500, &0);
+}
+
+#[test]
+#[should_panic(expected = "Unknown waste type")]
+fn test_set_emission_factor_unknown_type() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _recycler, _collector, _manufacturer) = setup(&env);
+
+    client.set_emission_factor(&admin, &WasteType(9), &500, &0);
+}
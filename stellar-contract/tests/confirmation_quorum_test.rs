@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+struct Actors {
+    admin: Address,
+    recycler: Address,
+    collector: Address,
+    second_collector: Address,
+    manufacturer: Address,
+}
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Actors) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let actors = Actors {
+        admin: Address::generate(env),
+        recycler: Address::generate(env),
+        collector: Address::generate(env),
+        second_collector: Address::generate(env),
+        manufacturer: Address::generate(env),
+    };
+
+    client.initialize_admin(&actors.admin);
+    client.register_participant(&actors.recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&actors.collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&actors.second_collector, &ParticipantRole::Collector, &symbol_short!("col2"), &0, &0);
+    client.register_participant(&actors.manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, actors)
+}
+
+#[test]
+fn test_single_confirmation_without_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &50_000, &actors.recycler, &0, &0, &None);
+    assert_eq!(client.get_required_confirmations(&waste_id), 1);
+
+    let waste = client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    assert!(waste.is_confirmed);
+    assert_eq!(client.get_waste_confirmers(&waste_id), vec![&env, actors.collector]);
+}
+
+#[test]
+fn test_quorum_required_for_heavy_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Metal, &10_000, &2, &true);
+
+    let light = client.recycle_waste(&WasteType::Metal, &5_000, &actors.recycler, &0, &0, &None);
+    let heavy = client.recycle_waste(&WasteType::Metal, &50_000, &actors.recycler, &0, &0, &None);
+    assert_eq!(client.get_required_confirmations(&light), 1);
+    assert_eq!(client.get_required_confirmations(&heavy), 2);
+
+    let pending = client.confirm_waste_details(&heavy, &actors.collector, &None);
+    assert!(!pending.is_confirmed);
+
+    let confirmed = client.confirm_waste_details(&heavy, &actors.manufacturer, &None);
+    assert!(confirmed.is_confirmed);
+    assert_eq!(confirmed.confirmer, actors.manufacturer);
+    assert_eq!(
+        client.get_waste_confirmers(&heavy),
+        vec![&env, actors.collector, actors.manufacturer]
+    );
+}
+
+#[test]
+fn test_quorum_bands() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Glass, &100_000, &3, &false);
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Glass, &10_000, &2, &false);
+
+    let rules = client.get_confirmation_quorums(&WasteType::Glass);
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules.get(0).unwrap().min_weight, 10_000);
+
+    let medium = client.recycle_waste(&WasteType::Glass, &50_000, &actors.recycler, &0, &0, &None);
+    let large = client.recycle_waste(&WasteType::Glass, &150_000, &actors.recycler, &0, &0, &None);
+    assert_eq!(client.get_required_confirmations(&medium), 2);
+    assert_eq!(client.get_required_confirmations(&large), 3);
+
+    // Other waste types are unaffected
+    let paper = client.recycle_waste(&WasteType::Paper, &150_000, &actors.recycler, &0, &0, &None);
+    assert_eq!(client.get_required_confirmations(&paper), 1);
+
+    client.remove_confirmation_quorum(&actors.admin, &WasteType::Glass, &100_000);
+    assert_eq!(client.get_required_confirmations(&large), 2);
+}
+
+#[test]
+fn test_reset_clears_confirmers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Metal, &0, &2, &false);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.confirm_waste_details(&waste_id, &actors.second_collector, &None);
+
+    client.reset_waste_confirmation(&waste_id, &actors.recycler);
+    assert_eq!(client.get_waste_confirmers(&waste_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Confirmer role already represented")]
+fn test_quorum_distinct_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Metal, &0, &2, &true);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.confirm_waste_details(&waste_id, &actors.second_collector, &None);
+}
+
+#[test]
+#[should_panic(expected = "Confirmer already confirmed waste")]
+fn test_quorum_duplicate_confirmer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Metal, &0, &2, &false);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+}
+
+#[test]
+#[should_panic(expected = "Confirmer has custody relation to waste")]
+fn test_quorum_rejects_previous_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Metal, &0, &2, &false);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &actors.recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &actors.recycler, &actors.collector, &0, &0, &None);
+
+    client.confirm_waste_details(&waste_id, &actors.recycler, &None);
+}
+
+#[test]
+#[should_panic(expected = "Quorum exceeds number of distinct roles")]
+fn test_quorum_too_many_distinct_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, actors) = setup(&env);
+
+    client.set_confirmation_quorum(&actors.admin, &WasteType::Metal, &0, &4, &true);
+}
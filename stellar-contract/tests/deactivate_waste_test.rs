@@ -205,6 +205,7 @@ fn test_deactivated_waste_cannot_be_transferred() {
         &recipient,
         &45_000_000,
         &-93_000_000,
+        &None,
     );
 }
 
@@ -236,5 +237,5 @@ fn test_deactivated_waste_cannot_be_confirmed() {
     client.deactivate_waste(&waste.waste_id, &admin);
 
     // Try to confirm deactivated waste (should panic)
-    client.confirm_waste_details(&waste.waste_id, &confirmer);
+    client.confirm_waste_details(&waste.waste_id, &confirmer, &None);
 }
@@ -0,0 +1,308 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, token, Address, Bytes, Env, String};
+use stellar_scavngr_contract::{
+    DisputeStatus, DisputeTarget, ParticipantRole, ScavengerContract, ScavengerContractClient,
+    WasteType,
+};
+
+struct Actors {
+    admin: Address,
+    recycler: Address,
+    collector: Address,
+    disputer: Address,
+    arbitrator: Address,
+    manufacturer: Address,
+}
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, token::Client<'_>, Actors) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let actors = Actors {
+        admin: Address::generate(env),
+        recycler: Address::generate(env),
+        collector: Address::generate(env),
+        disputer: Address::generate(env),
+        arbitrator: Address::generate(env),
+        manufacturer: Address::generate(env),
+    };
+
+    client.initialize_admin(&actors.admin);
+    client.add_arbitrator(&actors.admin, &actors.arbitrator);
+    client.register_participant(&actors.recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&actors.collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&actors.disputer, &ParticipantRole::Collector, &symbol_short!("dis"), &0, &0);
+    client.register_participant(&actors.manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    // Give the disputer 500 market tokens to bond with
+    let token_id = env.register_stellar_asset_contract(actors.admin.clone());
+    token::StellarAssetClient::new(env, &token_id).mint(&actors.disputer, &500);
+    client.set_market_token(&actors.admin, &token_id);
+
+    (client, token::Client::new(env, &token_id), actors)
+}
+
+fn evidence(env: &Env) -> Bytes {
+    Bytes::from_array(env, &[9; 32])
+}
+
+fn earned(client: &ScavengerContractClient, address: &Address) -> u128 {
+    client.get_participant(address).unwrap().total_tokens_earned
+}
+
+#[test]
+fn test_open_dispute_locks_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+
+    let dispute = client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &200, &evidence(&env));
+
+    assert_eq!(dispute.dispute_id, 1);
+    assert_eq!(dispute.accused, actors.collector);
+    assert_eq!(dispute.status, DisputeStatus::Open);
+    assert_eq!(token.balance(&actors.disputer), 300);
+    assert_eq!(token.balance(&client.address), 200);
+    assert_eq!(client.get_open_dispute(&waste_id), Some(1));
+    assert_eq!(client.get_waste_evidence(&waste_id).len(), 1);
+}
+
+#[test]
+fn test_overturn_confirmation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &200, &evidence(&env));
+
+    let dispute = client.resolve_dispute(&actors.arbitrator, &1, &true);
+
+    assert_eq!(dispute.status, DisputeStatus::Overturned);
+    assert_eq!(dispute.arbitrator, Some(actors.arbitrator));
+    assert!(!client.get_waste_v2(&waste_id).unwrap().is_confirmed);
+    assert_eq!(token.balance(&actors.disputer), 500);
+    assert_eq!(client.get_reputation(&actors.disputer), 10);
+    assert_eq!(client.get_reputation(&actors.collector), -10);
+    assert_eq!(client.get_open_dispute(&waste_id), None);
+}
+
+#[test]
+fn test_uphold_slashes_bond() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &200, &evidence(&env));
+
+    let dispute = client.resolve_dispute(&actors.arbitrator, &1, &false);
+
+    assert_eq!(dispute.status, DisputeStatus::Upheld);
+    assert!(client.get_waste_v2(&waste_id).unwrap().is_confirmed);
+    assert_eq!(token.balance(&actors.disputer), 300);
+    assert_eq!(token.balance(&actors.collector), 200);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_reputation(&actors.disputer), -10);
+    assert_eq!(client.get_reputation(&actors.collector), 0);
+}
+
+#[test]
+fn test_overturn_verification_claws_back_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &5_000,
+        &actors.collector,
+        &String::from_str(&env, "paper"),
+        &None);
+    client.verify_material(&material.id, &actors.recycler);
+    assert_eq!(earned(&client, &actors.collector), 50);
+
+    client.open_dispute(
+        &actors.disputer,
+        &(material.id as u128),
+        &DisputeTarget::Verification,
+        &100,
+        &evidence(&env),
+    );
+    client.resolve_dispute(&actors.arbitrator, &1, &true);
+
+    assert!(!client.get_material(&material.id).unwrap().verified);
+    assert_eq!(earned(&client, &actors.collector), 0);
+    let stats = client.get_stats(&actors.collector).unwrap();
+    assert_eq!(stats.verified_submissions, 0);
+    assert_eq!(stats.total_points, 0);
+    assert_eq!(stats.co2e_avoided, 0);
+    assert_eq!(client.get_participant_impact(&actors.collector).co2e_avoided, 0);
+    assert_eq!(client.get_reputation(&actors.recycler), -10);
+}
+
+#[test]
+fn test_overturn_verification_claws_back_incentive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, actors) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &20, &10_000, &1_000, &actors.manufacturer);
+    token::StellarAssetClient::new(&env, &token.address).mint(&actors.manufacturer, &1_000);
+    client.top_up_incentive(&incentive.id, &1_000);
+
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &5_000,
+        &actors.collector,
+        &String::from_str(&env, "paper"),
+        &None);
+    client.verify_material(&material.id, &actors.recycler);
+    client.transfer_waste(&material.id, &actors.collector, &actors.manufacturer, &String::from_str(&env, "delivery"));
+    client.claim_incentive_reward(&incentive.id, &material.id, &actors.collector);
+    assert_eq!(earned(&client, &actors.collector), 150);
+
+    client.open_dispute(
+        &actors.disputer,
+        &(material.id as u128),
+        &DisputeTarget::Verification,
+        &100,
+        &evidence(&env),
+    );
+    client.resolve_dispute(&actors.arbitrator, &1, &true);
+
+    assert_eq!(earned(&client, &actors.collector), 0);
+}
+
+#[test]
+#[should_panic(expected = "Material is under dispute")]
+fn test_disputed_material_cannot_claim_incentive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, actors) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &20, &10_000, &1_000, &actors.manufacturer);
+    token::StellarAssetClient::new(&env, &token.address).mint(&actors.manufacturer, &1_000);
+    client.top_up_incentive(&incentive.id, &1_000);
+
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &5_000,
+        &actors.collector,
+        &String::from_str(&env, "paper"),
+        &None);
+    client.verify_material(&material.id, &actors.recycler);
+    client.open_dispute(
+        &actors.disputer,
+        &(material.id as u128),
+        &DisputeTarget::Verification,
+        &100,
+        &evidence(&env),
+    );
+
+    client.claim_incentive_reward(&incentive.id, &material.id, &actors.collector);
+}
+
+#[test]
+fn test_overturn_confirmation_burns_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.mint_credits(&waste_id, &actors.recycler);
+    assert_eq!(client.get_credit_balance(&actors.recycler, &WasteType::Plastic), 1_000);
+
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &200, &evidence(&env));
+    client.resolve_dispute(&actors.arbitrator, &1, &true);
+
+    assert_eq!(client.get_credit_balance(&actors.recycler, &WasteType::Plastic), 0);
+    // The waste cannot be credited a second time
+    assert!(client.is_waste_credited(&waste_id));
+}
+
+#[test]
+#[should_panic(expected = "Waste is under dispute")]
+fn test_disputed_waste_cannot_be_credited() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &200, &evidence(&env));
+
+    client.mint_credits(&waste_id, &actors.recycler);
+}
+
+#[test]
+#[should_panic(expected = "Waste already under dispute")]
+fn test_duplicate_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &100, &evidence(&env));
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &100, &evidence(&env));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient tokens for bond")]
+fn test_bond_exceeds_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &501, &evidence(&env));
+}
+
+#[test]
+#[should_panic(expected = "Waste is not confirmed")]
+fn test_dispute_unconfirmed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &100, &evidence(&env));
+}
+
+#[test]
+#[should_panic(expected = "Only arbitrators can resolve disputes")]
+fn test_resolve_requires_arbitrator() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &100, &evidence(&env));
+
+    client.resolve_dispute(&actors.admin, &1, &true);
+}
+
+#[test]
+#[should_panic(expected = "Dispute already resolved")]
+fn test_resolve_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, actors) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &actors.recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &actors.collector, &None);
+    client.open_dispute(&actors.disputer, &waste_id, &DisputeTarget::Confirmation, &100, &evidence(&env));
+
+    client.resolve_dispute(&actors.arbitrator, &1, &false);
+    client.resolve_dispute(&actors.arbitrator, &1, &true);
+}
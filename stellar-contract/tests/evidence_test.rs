@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Bytes, Env};
+use stellar_scavngr_contract::{
+    EvidenceKind, ParticipantRole, ScavengerContract, ScavengerContractClient, SubmissionProof,
+    WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, recycler, collector, manufacturer)
+}
+
+fn hash(env: &Env, seed: u8) -> Bytes {
+    Bytes::from_array(env, &[seed; 32])
+}
+
+#[test]
+fn test_registration_evidence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &Some(SubmissionProof::Evidence(hash(&env, 1))));
+
+    let evidence = client.get_waste_evidence(&waste_id);
+    assert_eq!(evidence.len(), 1);
+
+    let record = evidence.get(0).unwrap();
+    assert_eq!(record.hash, hash(&env, 1));
+    assert_eq!(record.kind, EvidenceKind::Registration);
+    assert_eq!(record.submitted_by, recycler);
+}
+
+#[test]
+fn test_transfer_evidence_on_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1_000, &recycler, &0, &0, &None);
+    let transfer = client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &Some(hash(&env, 2)));
+
+    assert_eq!(transfer.evidence, Some(hash(&env, 2)));
+    assert_eq!(client.get_transfer_history_v2(&waste_id).get(0).unwrap().evidence, Some(hash(&env, 2)));
+
+    let evidence = client.get_waste_evidence(&waste_id);
+    assert_eq!(evidence.len(), 1);
+    assert_eq!(evidence.get(0).unwrap().kind, EvidenceKind::Transfer);
+}
+
+#[test]
+fn test_confirmation_and_supplement_evidence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &Some(hash(&env, 3)));
+    client.add_waste_evidence(&waste_id, &collector, &hash(&env, 4));
+    client.add_waste_evidence(&waste_id, &recycler, &hash(&env, 5));
+
+    let evidence = client.get_waste_evidence(&waste_id);
+    assert_eq!(evidence.len(), 3);
+    assert_eq!(evidence.get(0).unwrap().kind, EvidenceKind::Confirmation);
+    assert_eq!(evidence.get(1).unwrap().kind, EvidenceKind::Supplement);
+    assert_eq!(evidence.get(2).unwrap().submitted_by, recycler);
+}
+
+#[test]
+fn test_material_evidence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &2_000,
+        &recycler,
+        &soroban_sdk::String::from_str(&env, "cardboard"),
+        &Some(SubmissionProof::Evidence(hash(&env, 6))));
+    client.add_waste_evidence(&(material.id as u128), &recycler, &hash(&env, 7));
+
+    assert_eq!(client.get_waste_evidence(&(material.id as u128)).len(), 2);
+}
+
+#[test]
+fn test_no_evidence_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &None);
+    assert_eq!(client.get_waste_evidence(&waste_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Not authorized to add evidence")]
+fn test_add_evidence_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &None);
+    client.add_waste_evidence(&waste_id, &collector, &hash(&env, 1));
+}
+
+#[test]
+#[should_panic(expected = "Evidence hash cannot be empty")]
+fn test_empty_evidence_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &Some(SubmissionProof::Evidence(Bytes::new(&env))));
+}
+
+#[test]
+#[should_panic(expected = "Evidence hash too large")]
+fn test_oversized_evidence_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &None);
+    client.add_waste_evidence(&waste_id, &recycler, &Bytes::from_array(&env, &[0u8; 129]));
+}
+
+#[test]
+#[should_panic(expected = "Evidence limit reached")]
+fn test_evidence_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &None);
+    for seed in 0..17u8 {
+        client.add_waste_evidence(&waste_id, &recycler, &hash(&env, seed));
+    }
+}
@@ -1,8 +1,18 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{symbol_short, testutils::Address as _, token, Address, Env};
 use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
 
+/// Escrow `amount` marketplace tokens behind an incentive, adding them to its budget
+fn fund_incentive(env: &Env, client: &ScavengerContractClient, rewarder: &Address, incentive_id: u64, amount: i128) {
+    let admin = Address::generate(env);
+    client.initialize_admin(&admin);
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    token::StellarAssetClient::new(env, &token_id).mint(rewarder, &amount);
+    client.set_market_token(&admin, &token_id);
+    client.top_up_incentive(&incentive_id, &amount);
+}
+
 // ========== Basic Functionality Tests ==========
 
 #[test]
@@ -13,12 +23,12 @@ fn test_get_incentives_returns_active_only() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create multiple incentives for Plastic
-    let incentive1 = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &10000);
-    let incentive2 = client.create_incentive(&manufacturer, &WasteType::Plastic, &30, &5000);
-    let incentive3 = client.create_incentive(&manufacturer, &WasteType::Plastic, &70, &15000);
+    let incentive1 = client.create_incentive(&WasteType::Plastic, &50, &10_000, &10000, &manufacturer);
+    let incentive2 = client.create_incentive(&WasteType::Plastic, &30, &10_000, &5000, &manufacturer);
+    let incentive3 = client.create_incentive(&WasteType::Plastic, &70, &10_000, &15000, &manufacturer);
 
     // Deactivate one incentive
     client.deactivate_incentive(&incentive2.id, &manufacturer);
@@ -31,7 +41,7 @@ fn test_get_incentives_returns_active_only() {
     
     // Verify all returned incentives are active
     for incentive in incentives.iter() {
-        assert!(incentive.active);
+        assert!(incentive.is_active);
     }
     
     // Verify the deactivated one is not in the list
@@ -47,13 +57,13 @@ fn test_get_incentives_filters_by_waste_type() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives for different waste types
-    client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &10000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &60, &12000);
-    client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &8000);
-    client.create_incentive(&manufacturer, &WasteType::Glass, &30, &6000);
+    client.create_incentive(&WasteType::Plastic, &50, &10_000, &10000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &60, &10_000, &12000, &manufacturer);
+    client.create_incentive(&WasteType::Plastic, &40, &10_000, &8000, &manufacturer);
+    client.create_incentive(&WasteType::Glass, &30, &10_000, &6000, &manufacturer);
 
     // Get incentives for Plastic only
     let plastic_incentives = client.get_incentives(&WasteType::Plastic);
@@ -74,25 +84,25 @@ fn test_get_incentives_sorted_by_reward_descending() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives with different reward amounts (in random order)
-    client.create_incentive(&manufacturer, &WasteType::Paper, &30, &5000);
-    client.create_incentive(&manufacturer, &WasteType::Paper, &70, &10000);
-    client.create_incentive(&manufacturer, &WasteType::Paper, &50, &8000);
-    client.create_incentive(&manufacturer, &WasteType::Paper, &90, &15000);
-    client.create_incentive(&manufacturer, &WasteType::Paper, &20, &3000);
+    client.create_incentive(&WasteType::Paper, &30, &10_000, &5000, &manufacturer);
+    client.create_incentive(&WasteType::Paper, &70, &10_000, &10000, &manufacturer);
+    client.create_incentive(&WasteType::Paper, &50, &10_000, &8000, &manufacturer);
+    client.create_incentive(&WasteType::Paper, &90, &10_000, &15000, &manufacturer);
+    client.create_incentive(&WasteType::Paper, &20, &10_000, &3000, &manufacturer);
 
     // Get incentives
     let incentives = client.get_incentives(&WasteType::Paper);
 
     // Should be sorted in descending order by reward_points
     assert_eq!(incentives.len(), 5);
-    assert_eq!(incentives.get(0).unwrap().reward_points, 90);
-    assert_eq!(incentives.get(1).unwrap().reward_points, 70);
-    assert_eq!(incentives.get(2).unwrap().reward_points, 50);
-    assert_eq!(incentives.get(3).unwrap().reward_points, 30);
-    assert_eq!(incentives.get(4).unwrap().reward_points, 20);
+    assert_eq!(incentives.get(0).unwrap().reward, 90);
+    assert_eq!(incentives.get(1).unwrap().reward, 70);
+    assert_eq!(incentives.get(2).unwrap().reward, 50);
+    assert_eq!(incentives.get(3).unwrap().reward, 30);
+    assert_eq!(incentives.get(4).unwrap().reward, 20);
 }
 
 // ========== Edge Cases Tests ==========
@@ -119,11 +129,11 @@ fn test_get_incentives_empty_when_all_deactivated() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives
-    let incentive1 = client.create_incentive(&manufacturer, &WasteType::Glass, &40, &8000);
-    let incentive2 = client.create_incentive(&manufacturer, &WasteType::Glass, &50, &10000);
+    let incentive1 = client.create_incentive(&WasteType::Glass, &40, &10_000, &8000, &manufacturer);
+    let incentive2 = client.create_incentive(&WasteType::Glass, &50, &10_000, &10000, &manufacturer);
 
     // Deactivate all
     client.deactivate_incentive(&incentive1.id, &manufacturer);
@@ -144,10 +154,10 @@ fn test_get_incentives_single_incentive() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create single incentive
-    let created = client.create_incentive(&manufacturer, &WasteType::PetPlastic, &60, &12000);
+    let created = client.create_incentive(&WasteType::PetPlastic, &60, &10_000, &12000, &manufacturer);
 
     // Get incentives
     let incentives = client.get_incentives(&WasteType::PetPlastic);
@@ -155,7 +165,7 @@ fn test_get_incentives_single_incentive() {
     // Should return single incentive
     assert_eq!(incentives.len(), 1);
     assert_eq!(incentives.get(0).unwrap().id, created.id);
-    assert_eq!(incentives.get(0).unwrap().reward_points, 60);
+    assert_eq!(incentives.get(0).unwrap().reward, 60);
 }
 
 // ========== Sorting Tests ==========
@@ -168,23 +178,23 @@ fn test_get_incentives_sorting_with_equal_rewards() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives with some equal reward amounts
-    client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &50, &8000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &70, &12000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &30, &6000);
+    client.create_incentive(&WasteType::Metal, &50, &10_000, &10000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &50, &10_000, &8000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &70, &10_000, &12000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &30, &10_000, &6000, &manufacturer);
 
     // Get incentives
     let incentives = client.get_incentives(&WasteType::Metal);
 
     // Should be sorted correctly
     assert_eq!(incentives.len(), 4);
-    assert_eq!(incentives.get(0).unwrap().reward_points, 70);
-    assert_eq!(incentives.get(1).unwrap().reward_points, 50);
-    assert_eq!(incentives.get(2).unwrap().reward_points, 50);
-    assert_eq!(incentives.get(3).unwrap().reward_points, 30);
+    assert_eq!(incentives.get(0).unwrap().reward, 70);
+    assert_eq!(incentives.get(1).unwrap().reward, 50);
+    assert_eq!(incentives.get(2).unwrap().reward, 50);
+    assert_eq!(incentives.get(3).unwrap().reward, 30);
 }
 
 #[test]
@@ -195,21 +205,21 @@ fn test_get_incentives_already_sorted() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives already in descending order
-    client.create_incentive(&manufacturer, &WasteType::Paper, &90, &15000);
-    client.create_incentive(&manufacturer, &WasteType::Paper, &70, &12000);
-    client.create_incentive(&manufacturer, &WasteType::Paper, &50, &10000);
+    client.create_incentive(&WasteType::Paper, &90, &10_000, &15000, &manufacturer);
+    client.create_incentive(&WasteType::Paper, &70, &10_000, &12000, &manufacturer);
+    client.create_incentive(&WasteType::Paper, &50, &10_000, &10000, &manufacturer);
 
     // Get incentives
     let incentives = client.get_incentives(&WasteType::Paper);
 
     // Should maintain correct order
     assert_eq!(incentives.len(), 3);
-    assert_eq!(incentives.get(0).unwrap().reward_points, 90);
-    assert_eq!(incentives.get(1).unwrap().reward_points, 70);
-    assert_eq!(incentives.get(2).unwrap().reward_points, 50);
+    assert_eq!(incentives.get(0).unwrap().reward, 90);
+    assert_eq!(incentives.get(1).unwrap().reward, 70);
+    assert_eq!(incentives.get(2).unwrap().reward, 50);
 }
 
 // ========== Multiple Waste Types Tests ==========
@@ -222,12 +232,12 @@ fn test_get_incentives_independent_per_waste_type() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives for different waste types
-    client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &10000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &60, &12000);
-    client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &8000);
+    client.create_incentive(&WasteType::Plastic, &50, &10_000, &10000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &60, &10_000, &12000, &manufacturer);
+    client.create_incentive(&WasteType::Plastic, &40, &10_000, &8000, &manufacturer);
 
     // Get incentives for each type
     let plastic = client.get_incentives(&WasteType::Plastic);
@@ -250,10 +260,10 @@ fn test_get_incentives_returns_complete_data() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentive
-    let created = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &10000);
+    let created = client.create_incentive(&WasteType::Plastic, &50, &10_000, &10000, &manufacturer);
 
     // Get incentives
     let incentives = client.get_incentives(&WasteType::Plastic);
@@ -263,10 +273,10 @@ fn test_get_incentives_returns_complete_data() {
     assert_eq!(retrieved.id, created.id);
     assert_eq!(retrieved.rewarder, manufacturer);
     assert_eq!(retrieved.waste_type, WasteType::Plastic);
-    assert_eq!(retrieved.reward_points, 50);
+    assert_eq!(retrieved.reward, 50);
     assert_eq!(retrieved.total_budget, 10000);
     assert_eq!(retrieved.remaining_budget, 10000);
-    assert!(retrieved.active);
+    assert!(retrieved.is_active);
 }
 
 #[test]
@@ -280,17 +290,19 @@ fn test_get_incentives_reflects_budget_changes() {
     let collector = Address::generate(&env);
     let recycler = Address::generate(&env);
     
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
-    client.register_participant(&collector, &ParticipantRole::Collector);
-    client.register_participant(&recycler, &ParticipantRole::Recycler);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("user"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
 
     // Create incentive
-    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    let incentive = client.create_incentive(&WasteType::Metal, &100, &10_000, &0, &manufacturer);
+    fund_incentive(&env, &client, &manufacturer, incentive.id, 1000);
 
     // Submit and verify material to claim reward
     let desc = soroban_sdk::String::from_str(&env, "Test");
-    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc, &None);
     client.verify_material(&material.id, &recycler);
+    client.transfer_waste(&material.id, &collector, &manufacturer, &desc);
     
     // Claim reward (5kg * 100 = 500 points)
     client.claim_incentive_reward(&incentive.id, &material.id, &collector);
@@ -301,7 +313,7 @@ fn test_get_incentives_reflects_budget_changes() {
 
     // Should reflect reduced budget
     assert_eq!(retrieved.remaining_budget, 500);
-    assert!(retrieved.active);
+    assert!(retrieved.is_active);
 }
 
 #[test]
@@ -315,17 +327,19 @@ fn test_get_incentives_excludes_auto_deactivated() {
     let collector = Address::generate(&env);
     let recycler = Address::generate(&env);
     
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
-    client.register_participant(&collector, &ParticipantRole::Collector);
-    client.register_participant(&recycler, &ParticipantRole::Recycler);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("user"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("user"), &0, &0);
 
     // Create incentive with small budget
-    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &100, &500);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &0, &manufacturer);
+    fund_incentive(&env, &client, &manufacturer, incentive.id, 500);
 
     // Submit and verify material to exhaust budget
     let desc = soroban_sdk::String::from_str(&env, "Test");
-    let material = client.submit_material(&WasteType::Paper, &5000, &collector, &desc);
+    let material = client.submit_material(&WasteType::Paper, &5000, &collector, &desc, &None);
     client.verify_material(&material.id, &recycler);
+    client.transfer_waste(&material.id, &collector, &manufacturer, &desc);
     
     // Claim reward (5kg * 100 = 500 points, exhausts budget)
     client.claim_incentive_reward(&incentive.id, &material.id, &collector);
@@ -347,14 +361,14 @@ fn test_get_incentives_all_waste_types() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives for all waste types
-    client.create_incentive(&manufacturer, &WasteType::Paper, &30, &5000);
-    client.create_incentive(&manufacturer, &WasteType::PetPlastic, &50, &8000);
-    client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &7000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &70, &12000);
-    client.create_incentive(&manufacturer, &WasteType::Glass, &35, &6000);
+    client.create_incentive(&WasteType::Paper, &30, &10_000, &5000, &manufacturer);
+    client.create_incentive(&WasteType::PetPlastic, &50, &10_000, &8000, &manufacturer);
+    client.create_incentive(&WasteType::Plastic, &40, &10_000, &7000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &70, &10_000, &12000, &manufacturer);
+    client.create_incentive(&WasteType::Glass, &35, &10_000, &6000, &manufacturer);
 
     // Get incentives for each type
     assert_eq!(client.get_incentives(&WasteType::Paper).len(), 1);
@@ -374,13 +388,13 @@ fn test_get_incentives_multiple_manufacturers() {
     let manufacturer1 = Address::generate(&env);
     let manufacturer2 = Address::generate(&env);
     
-    client.register_participant(&manufacturer1, &ParticipantRole::Manufacturer);
-    client.register_participant(&manufacturer2, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer1, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
+    client.register_participant(&manufacturer2, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives from different manufacturers
-    client.create_incentive(&manufacturer1, &WasteType::Plastic, &50, &10000);
-    client.create_incentive(&manufacturer2, &WasteType::Plastic, &60, &12000);
-    client.create_incentive(&manufacturer1, &WasteType::Plastic, &40, &8000);
+    client.create_incentive(&WasteType::Plastic, &50, &10_000, &10000, &manufacturer1);
+    client.create_incentive(&WasteType::Plastic, &60, &10_000, &12000, &manufacturer2);
+    client.create_incentive(&WasteType::Plastic, &40, &10_000, &8000, &manufacturer1);
 
     // Get incentives
     let incentives = client.get_incentives(&WasteType::Plastic);
@@ -389,9 +403,9 @@ fn test_get_incentives_multiple_manufacturers() {
     assert_eq!(incentives.len(), 3);
     
     // Should be sorted by reward
-    assert_eq!(incentives.get(0).unwrap().reward_points, 60);
-    assert_eq!(incentives.get(1).unwrap().reward_points, 50);
-    assert_eq!(incentives.get(2).unwrap().reward_points, 40);
+    assert_eq!(incentives.get(0).unwrap().reward, 60);
+    assert_eq!(incentives.get(1).unwrap().reward, 50);
+    assert_eq!(incentives.get(2).unwrap().reward, 40);
 }
 
 #[test]
@@ -402,11 +416,11 @@ fn test_get_incentives_no_side_effects() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create incentives
-    client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10000);
-    client.create_incentive(&manufacturer, &WasteType::Metal, &70, &12000);
+    client.create_incentive(&WasteType::Metal, &50, &10_000, &10000, &manufacturer);
+    client.create_incentive(&WasteType::Metal, &70, &10_000, &12000, &manufacturer);
 
     // Get incentives multiple times
     let incentives1 = client.get_incentives(&WasteType::Metal);
@@ -431,11 +445,11 @@ fn test_get_incentives_large_list() {
     let client = ScavengerContractClient::new(&env, &contract_id);
     
     let manufacturer = Address::generate(&env);
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("user"), &0, &0);
 
     // Create many incentives
     for i in 1..=10 {
-        client.create_incentive(&manufacturer, &WasteType::Glass, &(i * 10), &(i * 1000));
+        client.create_incentive(&WasteType::Glass, &(i * 10), &10_000, &(i * 1000), &manufacturer);
     }
 
     // Get incentives
@@ -448,6 +462,6 @@ fn test_get_incentives_large_list() {
     for i in 0..9 {
         let curr = incentives.get(i).unwrap();
         let next = incentives.get(i + 1).unwrap();
-        assert!(curr.reward_points >= next.reward_points);
+        assert!(curr.reward >= next.reward);
     }
 }
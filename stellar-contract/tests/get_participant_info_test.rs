@@ -45,7 +45,7 @@ fn test_get_participant_info_with_stats() {
     client.register_participant(&user, &ParticipantRole::Collector);
 
     // Submit material to create stats
-    client.submit_material(&WasteType::Plastic, &5000, &user, &desc);
+    client.submit_material(&WasteType::Plastic, &5000, &user, &desc, &None);
 
     // Get participant info
     let info = client.get_participant_info(&user).unwrap();
@@ -115,9 +115,9 @@ fn test_get_participant_info_stats_reflect_submissions() {
     client.register_participant(&user, &ParticipantRole::Collector);
 
     // Submit multiple materials
-    client.submit_material(&WasteType::Paper, &1000, &user, &desc);
-    client.submit_material(&WasteType::Plastic, &2000, &user, &desc);
-    client.submit_material(&WasteType::Metal, &3000, &user, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &user, &desc, &None);
+    client.submit_material(&WasteType::Plastic, &2000, &user, &desc, &None);
+    client.submit_material(&WasteType::Metal, &3000, &user, &desc, &None);
 
     // Get participant info
     let info = client.get_participant_info(&user).unwrap();
@@ -146,7 +146,7 @@ fn test_get_participant_info_stats_reflect_verifications() {
     client.register_participant(&recycler, &ParticipantRole::Recycler);
 
     // Submit and verify material
-    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc);
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &desc, &None);
     client.verify_material(&material.id, &recycler);
 
     // Get participant info
@@ -171,14 +171,14 @@ fn test_get_participant_info_stats_current() {
     client.register_participant(&user, &ParticipantRole::Collector);
 
     // Submit material
-    client.submit_material(&WasteType::Paper, &1000, &user, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &user, &desc, &None);
 
     // Get info - should have 1 submission
     let info1 = client.get_participant_info(&user).unwrap();
     assert_eq!(info1.stats.total_submissions, 1);
 
     // Submit another material
-    client.submit_material(&WasteType::Plastic, &2000, &user, &desc);
+    client.submit_material(&WasteType::Plastic, &2000, &user, &desc, &None);
 
     // Get info again - should have 2 submissions
     let info2 = client.get_participant_info(&user).unwrap();
@@ -297,11 +297,11 @@ fn test_get_participant_info_with_all_waste_types() {
     client.register_participant(&user, &ParticipantRole::Collector);
 
     // Submit all waste types
-    client.submit_material(&WasteType::Paper, &1000, &user, &desc);
-    client.submit_material(&WasteType::PetPlastic, &2000, &user, &desc);
-    client.submit_material(&WasteType::Plastic, &3000, &user, &desc);
-    client.submit_material(&WasteType::Metal, &4000, &user, &desc);
-    client.submit_material(&WasteType::Glass, &5000, &user, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &user, &desc, &None);
+    client.submit_material(&WasteType::PetPlastic, &2000, &user, &desc, &None);
+    client.submit_material(&WasteType::Plastic, &3000, &user, &desc, &None);
+    client.submit_material(&WasteType::Metal, &4000, &user, &desc, &None);
+    client.submit_material(&WasteType::Glass, &5000, &user, &desc, &None);
 
     // Get participant info
     let info = client.get_participant_info(&user).unwrap();
@@ -348,7 +348,7 @@ fn test_get_participant_info_consistency_with_get_stats() {
 
     // Register and submit
     client.register_participant(&user, &ParticipantRole::Collector);
-    client.submit_material(&WasteType::Paper, &1000, &user, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &user, &desc, &None);
 
     // Get via both methods
     let stats = client.get_stats(&user);
@@ -373,7 +373,7 @@ fn test_get_participant_info_read_only() {
 
     // Register and submit
     client.register_participant(&user, &ParticipantRole::Collector);
-    client.submit_material(&WasteType::Metal, &5000, &user, &desc);
+    client.submit_material(&WasteType::Metal, &5000, &user, &desc, &None);
 
     // Get info
     let info_before = client.get_participant_info(&user).unwrap();
@@ -17,9 +17,9 @@ fn test_get_participant_wastes_returns_owned_ids() {
     client.register_participant(&user, &ParticipantRole::Recycler);
 
     // Submit multiple materials
-    let m1 = client.submit_material(&WasteType::Plastic, &1000, &user, &description);
-    let m2 = client.submit_material(&WasteType::Metal, &2000, &user, &description);
-    let m3 = client.submit_material(&WasteType::Glass, &3000, &user, &description);
+    let m1 = client.submit_material(&WasteType::Plastic, &1000, &user, &description, &None);
+    let m2 = client.submit_material(&WasteType::Metal, &2000, &user, &description, &None);
+    let m3 = client.submit_material(&WasteType::Glass, &3000, &user, &description, &None);
 
     // Get participant wastes
     let waste_ids = client.get_participant_wastes(&user);
@@ -81,13 +81,13 @@ fn test_get_participant_wastes_multiple_participants() {
     client.register_participant(&user2, &ParticipantRole::Collector);
 
     // User1 submits 2 wastes
-    let m1 = client.submit_material(&WasteType::Paper, &1000, &user1, &description);
-    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user1, &description);
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &user1, &description, &None);
+    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user1, &description, &None);
 
     // User2 submits 3 wastes
-    let m3 = client.submit_material(&WasteType::Metal, &3000, &user2, &description);
-    let m4 = client.submit_material(&WasteType::Glass, &4000, &user2, &description);
-    let m5 = client.submit_material(&WasteType::Paper, &5000, &user2, &description);
+    let m3 = client.submit_material(&WasteType::Metal, &3000, &user2, &description, &None);
+    let m4 = client.submit_material(&WasteType::Glass, &4000, &user2, &description, &None);
+    let m5 = client.submit_material(&WasteType::Paper, &5000, &user2, &description, &None);
 
     // Get wastes for each participant
     let user1_wastes = client.get_participant_wastes(&user1);
@@ -125,8 +125,8 @@ fn test_get_participant_wastes_updates_after_transfer() {
     client.register_participant(&receiver, &ParticipantRole::Collector);
 
     // Sender submits wastes
-    let m1 = client.submit_material(&WasteType::Plastic, &1000, &sender, &description);
-    let m2 = client.submit_material(&WasteType::Metal, &2000, &sender, &description);
+    let m1 = client.submit_material(&WasteType::Plastic, &1000, &sender, &description, &None);
+    let m2 = client.submit_material(&WasteType::Metal, &2000, &sender, &description, &None);
 
     // Verify sender owns both wastes
     let sender_wastes_before = client.get_participant_wastes(&sender);
@@ -174,9 +174,9 @@ fn test_get_participant_wastes_after_multiple_transfers() {
     client.register_participant(&user3, &ParticipantRole::Manufacturer);
 
     // User1 submits 3 wastes
-    let m1 = client.submit_material(&WasteType::Paper, &1000, &user1, &description);
-    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user1, &description);
-    let m3 = client.submit_material(&WasteType::Metal, &3000, &user1, &description);
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &user1, &description, &None);
+    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user1, &description, &None);
+    let m3 = client.submit_material(&WasteType::Metal, &3000, &user1, &description, &None);
 
     // Transfer m1: user1 -> user2
     client.transfer_waste(&m1.id, &user1, &user2, &note);
@@ -219,11 +219,11 @@ fn test_get_participant_wastes_all_waste_types() {
     client.register_participant(&user, &ParticipantRole::Recycler);
 
     // Submit one of each waste type
-    let m1 = client.submit_material(&WasteType::Paper, &1000, &user, &description);
-    let m2 = client.submit_material(&WasteType::PetPlastic, &2000, &user, &description);
-    let m3 = client.submit_material(&WasteType::Plastic, &3000, &user, &description);
-    let m4 = client.submit_material(&WasteType::Metal, &4000, &user, &description);
-    let m5 = client.submit_material(&WasteType::Glass, &5000, &user, &description);
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &user, &description, &None);
+    let m2 = client.submit_material(&WasteType::PetPlastic, &2000, &user, &description, &None);
+    let m3 = client.submit_material(&WasteType::Plastic, &3000, &user, &description, &None);
+    let m4 = client.submit_material(&WasteType::Metal, &4000, &user, &description, &None);
+    let m5 = client.submit_material(&WasteType::Glass, &5000, &user, &description, &None);
 
     // Get participant wastes
     let waste_ids = client.get_participant_wastes(&user);
@@ -260,7 +260,7 @@ fn test_get_participant_wastes_large_number() {
             3 => WasteType::Metal,
             _ => WasteType::Glass,
         };
-        let material = client.submit_material(&waste_type, &(1000 + i * 100), &user, &description);
+        let material = client.submit_material(&waste_type, &(1000 + i * 100), &user, &description, &None);
         expected_ids.push_back(material.id);
     }
 
@@ -288,8 +288,8 @@ fn test_get_participant_wastes_consistency() {
     client.register_participant(&user, &ParticipantRole::Recycler);
 
     // Submit wastes
-    client.submit_material(&WasteType::Paper, &1000, &user, &description);
-    client.submit_material(&WasteType::Plastic, &2000, &user, &description);
+    client.submit_material(&WasteType::Paper, &1000, &user, &description, &None);
+    client.submit_material(&WasteType::Plastic, &2000, &user, &description, &None);
 
     // Get wastes multiple times
     let wastes1 = client.get_participant_wastes(&user);
@@ -322,7 +322,7 @@ fn test_get_participant_wastes_after_verification() {
     client.register_participant(&verifier, &ParticipantRole::Recycler);
 
     // Submit waste
-    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description);
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &description, &None);
 
     // Get wastes before verification
     let wastes_before = client.get_participant_wastes(&submitter);
@@ -352,9 +352,9 @@ fn test_get_participant_wastes_order() {
     client.register_participant(&user, &ParticipantRole::Recycler);
 
     // Submit wastes in specific order
-    let m1 = client.submit_material(&WasteType::Paper, &1000, &user, &description);
-    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user, &description);
-    let m3 = client.submit_material(&WasteType::Metal, &3000, &user, &description);
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &user, &description, &None);
+    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user, &description, &None);
+    let m3 = client.submit_material(&WasteType::Metal, &3000, &user, &description, &None);
 
     // Get participant wastes
     let waste_ids = client.get_participant_wastes(&user);
@@ -380,7 +380,7 @@ fn test_get_participant_wastes_no_side_effects() {
     client.register_participant(&user, &ParticipantRole::Recycler);
 
     // Submit waste
-    let material = client.submit_material(&WasteType::Glass, &3000, &user, &description);
+    let material = client.submit_material(&WasteType::Glass, &3000, &user, &description, &None);
 
     // Get wastes
     let waste_ids = client.get_participant_wastes(&user);
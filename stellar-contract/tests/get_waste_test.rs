@@ -17,7 +17,7 @@ fn test_get_waste_returns_correct_data() {
     client.register_participant(&user, &stellar_scavngr_contract::ParticipantRole::Recycler);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Plastic, &5000, &user, &description);
+    let material = client.submit_material(&WasteType::Plastic, &5000, &user, &description, &None);
 
     // Get waste by ID
     let retrieved = client.get_waste(&material.id);
@@ -75,9 +75,9 @@ fn test_get_waste_multiple_materials() {
     let desc2 = String::from_str(&env, "Metal cans");
     let desc3 = String::from_str(&env, "Glass jars");
 
-    let m1 = client.submit_material(&WasteType::Plastic, &1000, &user, &desc1);
-    let m2 = client.submit_material(&WasteType::Metal, &2000, &user, &desc2);
-    let m3 = client.submit_material(&WasteType::Glass, &3000, &user, &desc3);
+    let m1 = client.submit_material(&WasteType::Plastic, &1000, &user, &desc1, &None);
+    let m2 = client.submit_material(&WasteType::Metal, &2000, &user, &desc2, &None);
+    let m3 = client.submit_material(&WasteType::Glass, &3000, &user, &desc3, &None);
 
     // Get each waste and verify
     let w1 = client.get_waste(&m1.id).unwrap();
@@ -110,7 +110,7 @@ fn test_get_waste_after_verification() {
     client.register_participant(&verifier, &stellar_scavngr_contract::ParticipantRole::Recycler);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Paper, &4000, &submitter, &description);
+    let material = client.submit_material(&WasteType::Paper, &4000, &submitter, &description, &None);
     
     // Verify it's not verified initially
     let waste_before = client.get_waste(&material.id).unwrap();
@@ -138,7 +138,7 @@ fn test_get_waste_consistency() {
     client.register_participant(&user, &stellar_scavngr_contract::ParticipantRole::Recycler);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Metal, &7000, &user, &description);
+    let material = client.submit_material(&WasteType::Metal, &7000, &user, &description, &None);
 
     // Get waste multiple times
     let w1 = client.get_waste(&material.id).unwrap();
@@ -168,11 +168,11 @@ fn test_get_waste_all_waste_types() {
 
     // Submit one of each waste type
     let desc = String::from_str(&env, "Test");
-    let paper = client.submit_material(&WasteType::Paper, &1000, &user, &desc);
-    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &user, &desc);
-    let plastic = client.submit_material(&WasteType::Plastic, &3000, &user, &desc);
-    let metal = client.submit_material(&WasteType::Metal, &4000, &user, &desc);
-    let glass = client.submit_material(&WasteType::Glass, &5000, &user, &desc);
+    let paper = client.submit_material(&WasteType::Paper, &1000, &user, &desc, &None);
+    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &user, &desc, &None);
+    let plastic = client.submit_material(&WasteType::Plastic, &3000, &user, &desc, &None);
+    let metal = client.submit_material(&WasteType::Metal, &4000, &user, &desc, &None);
+    let glass = client.submit_material(&WasteType::Glass, &5000, &user, &desc, &None);
 
     // Verify all can be retrieved
     assert_eq!(client.get_waste(&paper.id).unwrap().waste_type, WasteType::Paper);
@@ -209,9 +209,9 @@ fn test_get_waste_sequential_ids() {
     client.register_participant(&user, &stellar_scavngr_contract::ParticipantRole::Recycler);
 
     // Submit materials and verify IDs are sequential
-    let m1 = client.submit_material(&WasteType::Paper, &1000, &user, &description);
-    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user, &description);
-    let m3 = client.submit_material(&WasteType::Metal, &3000, &user, &description);
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &user, &description, &None);
+    let m2 = client.submit_material(&WasteType::Plastic, &2000, &user, &description, &None);
+    let m3 = client.submit_material(&WasteType::Metal, &3000, &user, &description, &None);
 
     // Verify we can get each by ID
     assert!(client.get_waste(&m1.id).is_some());
@@ -237,7 +237,7 @@ fn test_get_waste_alias_compatibility() {
     client.register_participant(&user, &stellar_scavngr_contract::ParticipantRole::Recycler);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Glass, &6000, &user, &description);
+    let material = client.submit_material(&WasteType::Glass, &6000, &user, &description, &None);
 
     // All three functions should return the same data
     let w1 = client.get_waste(&material.id);
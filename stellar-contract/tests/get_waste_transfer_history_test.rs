@@ -22,7 +22,7 @@ fn test_get_waste_transfer_history_returns_complete_history() {
     client.register_participant(&user3, &ParticipantRole::Manufacturer);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Plastic, &5000, &user1, &description);
+    let material = client.submit_material(&WasteType::Plastic, &5000, &user1, &description, &None);
 
     // Transfer: user1 -> user2
     client.transfer_waste(&material.id, &user1, &user2, &note);
@@ -72,7 +72,7 @@ fn test_get_waste_transfer_history_chronological_order() {
     client.register_participant(&user3, &ParticipantRole::Manufacturer);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Metal, &3000, &user1, &description);
+    let material = client.submit_material(&WasteType::Metal, &3000, &user1, &description, &None);
 
     // First transfer at timestamp 1000
     client.transfer_waste(&material.id, &user1, &user2, &note);
@@ -117,7 +117,7 @@ fn test_get_waste_transfer_history_includes_all_details() {
     client.register_participant(&receiver, &ParticipantRole::Collector);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Glass, &4000, &sender, &description);
+    let material = client.submit_material(&WasteType::Glass, &4000, &sender, &description, &None);
 
     // Transfer
     client.transfer_waste(&material.id, &sender, &receiver, &note);
@@ -150,7 +150,7 @@ fn test_get_waste_transfer_history_empty_for_no_transfers() {
     client.register_participant(&user, &ParticipantRole::Recycler);
 
     // Submit material but don't transfer
-    let material = client.submit_material(&WasteType::Paper, &2000, &user, &description);
+    let material = client.submit_material(&WasteType::Paper, &2000, &user, &description, &None);
 
     // Get history
     let history = client.get_waste_transfer_history(&material.id);
@@ -192,8 +192,8 @@ fn test_get_waste_transfer_history_multiple_wastes_separate() {
     client.register_participant(&user3, &ParticipantRole::Manufacturer);
 
     // Submit two materials
-    let material1 = client.submit_material(&WasteType::Plastic, &1000, &user1, &description);
-    let material2 = client.submit_material(&WasteType::Metal, &2000, &user1, &description);
+    let material1 = client.submit_material(&WasteType::Plastic, &1000, &user1, &description, &None);
+    let material2 = client.submit_material(&WasteType::Metal, &2000, &user1, &description, &None);
 
     // Transfer material1: user1 -> user2
     client.transfer_waste(&material1.id, &user1, &user2, &note1);
@@ -230,7 +230,7 @@ fn test_get_waste_transfer_history_immutable() {
     client.register_participant(&user2, &ParticipantRole::Collector);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Glass, &3000, &user1, &description);
+    let material = client.submit_material(&WasteType::Glass, &3000, &user1, &description, &None);
 
     // Transfer
     client.transfer_waste(&material.id, &user1, &user2, &note);
@@ -270,7 +270,7 @@ fn test_get_waste_transfer_history_long_chain() {
     }
 
     // Submit material with first user
-    let material = client.submit_material(&WasteType::Paper, &5000, &users.get(0).unwrap(), &description);
+    let material = client.submit_material(&WasteType::Paper, &5000, &users.get(0).unwrap(), &description, &None);
 
     // Create transfer chain: user0 -> user1 -> user2 -> user3 -> user4
     for i in 0..4 {
@@ -313,7 +313,7 @@ fn test_get_waste_transfer_history_with_different_notes() {
     client.register_participant(&user3, &ParticipantRole::Manufacturer);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Metal, &6000, &user1, &description);
+    let material = client.submit_material(&WasteType::Metal, &6000, &user1, &description, &None);
 
     // Transfers with different notes
     client.transfer_waste(&material.id, &user1, &user2, &note1);
@@ -345,7 +345,7 @@ fn test_get_waste_transfer_history_alias_compatibility() {
     client.register_participant(&user2, &ParticipantRole::Collector);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Plastic, &4000, &user1, &description);
+    let material = client.submit_material(&WasteType::Plastic, &4000, &user1, &description, &None);
 
     // Transfer
     client.transfer_waste(&material.id, &user1, &user2, &note);
@@ -386,7 +386,7 @@ fn test_get_waste_transfer_history_all_waste_types() {
     ];
 
     for waste_type in waste_types {
-        let material = client.submit_material(&waste_type, &1000, &user1, &description);
+        let material = client.submit_material(&waste_type, &1000, &user1, &description, &None);
         client.transfer_waste(&material.id, &user1, &user2, &note);
         
         let history = client.get_waste_transfer_history(&material.id);
@@ -414,7 +414,7 @@ fn test_get_waste_transfer_history_preserves_order_after_multiple_queries() {
     client.register_participant(&user3, &ParticipantRole::Manufacturer);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Glass, &5000, &user1, &description);
+    let material = client.submit_material(&WasteType::Glass, &5000, &user1, &description, &None);
 
     // Create transfers
     client.transfer_waste(&material.id, &user1, &user2, &note);
@@ -450,7 +450,7 @@ fn test_get_waste_transfer_history_no_side_effects() {
     client.register_participant(&user2, &ParticipantRole::Collector);
 
     // Submit material
-    let material = client.submit_material(&WasteType::Metal, &3000, &user1, &description);
+    let material = client.submit_material(&WasteType::Metal, &3000, &user1, &description, &None);
 
     // Transfer
     client.transfer_waste(&material.id, &user1, &user2, &note);
@@ -0,0 +1,190 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, admin, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_handover_within_tolerance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+
+    let transfer = client.record_handover_weight(&waste_id, &collector, &9_700);
+
+    assert_eq!(transfer.measured_weight, 9_700);
+    assert_eq!(transfer.discrepancy, -300);
+    assert!(!transfer.flagged);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().weight, 9_700);
+    assert_eq!(client.get_transfer_history_v2(&waste_id).get(0).unwrap(), transfer);
+    assert_eq!(client.get_flagged_wastes().len(), 0);
+}
+
+#[test]
+fn test_discrepancy_against_prior_hop() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &collector, &9_800);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0, &None);
+
+    let transfer = client.record_handover_weight(&waste_id, &manufacturer, &9_600);
+
+    assert_eq!(transfer.discrepancy, -200);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().weight, 9_600);
+}
+
+#[test]
+fn test_inflated_handover_is_flagged() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+
+    let transfer = client.record_handover_weight(&waste_id, &collector, &12_000);
+
+    assert!(transfer.flagged);
+    assert_eq!(transfer.discrepancy, 2_000);
+    // Rewards are based on the lower reading when the hop is flagged
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().weight, 10_000);
+    assert_eq!(client.get_flagged_wastes(), vec![&env, waste_id]);
+}
+
+#[test]
+fn test_configured_tolerance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector, _manufacturer) = setup(&env);
+
+    assert_eq!(client.get_weight_tolerance(&WasteType::Glass), 500);
+    client.set_weight_tolerance(&admin, &WasteType::Glass, &100);
+    assert_eq!(client.get_weight_tolerance(&WasteType::Glass), 100);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+
+    let transfer = client.record_handover_weight(&waste_id, &collector, &9_700);
+    assert!(transfer.flagged);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().weight, 9_700);
+}
+
+#[test]
+fn test_credits_use_agreed_weight() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &collector, &9_900);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &manufacturer, &9_800);
+    client.mark_processed(&waste_id, &manufacturer);
+
+    assert_eq!(client.mint_credits(&waste_id, &manufacturer), 9_800);
+}
+
+#[test]
+#[should_panic(expected = "Handover weight already recorded")]
+fn test_handover_recorded_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &collector, &9_900);
+    client.record_handover_weight(&waste_id, &collector, &9_800);
+}
+
+#[test]
+#[should_panic(expected = "No transfer to measure")]
+fn test_handover_without_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &recycler, &9_900);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not own waste")]
+fn test_handover_by_non_receiver() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &recycler, &9_900);
+}
+
+#[test]
+#[should_panic(expected = "Cannot measure deactivated waste")]
+fn test_handover_on_deactivated_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.deactivate_waste(&waste_id, &admin);
+    client.record_handover_weight(&waste_id, &collector, &9_900);
+}
+
+#[test]
+#[should_panic(expected = "Waste is listed for sale")]
+fn test_handover_on_listed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector, _manufacturer) = setup(&env);
+    client.set_market_token(&admin, &env.register_stellar_asset_contract(admin.clone()));
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.create_listing(&collector, &waste_id, &300, &3_600);
+    client.record_handover_weight(&waste_id, &collector, &9_900);
+}
+
+#[test]
+#[should_panic(expected = "Cannot measure credited waste")]
+fn test_handover_on_credited_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &collector, &9_900);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0, &None);
+    client.mark_processed(&waste_id, &manufacturer);
+    client.mint_credits(&waste_id, &manufacturer);
+    client.record_handover_weight(&waste_id, &manufacturer, &9_800);
+}
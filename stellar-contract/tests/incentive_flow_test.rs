@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, String,
+};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteSubType, WasteType,
+};
+
+struct Flow<'a> {
+    client: ScavengerContractClient<'a>,
+    token: token::Client<'a>,
+    manufacturer: Address,
+    collector: Address,
+    recycler: Address,
+}
+
+fn setup(env: &Env) -> Flow<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let manufacturer = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    token::StellarAssetClient::new(env, &token_id).mint(&manufacturer, &10_000);
+    client.set_market_token(&admin, &token_id);
+
+    Flow {
+        client,
+        token: token::Client::new(env, &token_id),
+        manufacturer,
+        collector,
+        recycler,
+    }
+}
+
+/// Submit and verify a material for the collector and deliver it to the manufacturer
+fn verified_material(env: &Env, f: &Flow, waste_type: WasteType, weight: u64) -> u64 {
+    let desc = String::from_str(env, "bale");
+    let material = f.client.submit_material(&waste_type, &weight, &f.collector, &desc, &None);
+    f.client.verify_material(&material.id, &f.recycler);
+    f.client.transfer_waste(&material.id, &f.collector, &f.manufacturer, &desc);
+    material.id
+}
+
+#[test]
+fn test_incentive_queries_agree() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let low = f.client.create_incentive(&WasteType::Metal, &50, &10_000, &5_000, &f.manufacturer);
+    let high = f.client.create_incentive(&WasteType::Metal, &80, &10_000, &5_000, &f.manufacturer);
+    let glass = f.client.create_incentive(&WasteType::Glass, &90, &10_000, &5_000, &f.manufacturer);
+
+    assert_eq!(f.client.get_incentives(&WasteType::Metal), vec![&env, high.clone(), low.clone()]);
+    assert_eq!(
+        f.client.get_incentives_by_rewarder(&f.manufacturer),
+        vec![&env, low.clone(), high.clone(), glass.clone()]
+    );
+    assert_eq!(f.client.get_incentives_by_waste_type(&WasteType::Metal).len(), 2);
+    assert_eq!(f.client.get_active_incentive(&f.manufacturer, &WasteType::Metal), Some(high.clone()));
+    assert_eq!(f.client.get_incentive_by_id(&glass.id), Some(glass));
+
+    f.client.deactivate_incentive(&high.id, &f.manufacturer);
+    assert_eq!(f.client.get_incentives(&WasteType::Metal), vec![&env, low.clone()]);
+    assert_eq!(f.client.get_active_incentive(&f.manufacturer, &WasteType::Metal), Some(low));
+    assert_eq!(f.client.get_active_incentives().len(), 2);
+}
+
+#[test]
+fn test_claim_draws_budget_and_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Metal, &100, &4_000, &1_000, &f.manufacturer);
+    assert_eq!(f.client.top_up_incentive(&incentive.id, &700), 700);
+
+    // Escrowed tokens are added to the budget, one point per token
+    let incentive = f.client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(incentive.total_budget, 1_700);
+    assert_eq!(incentive.remaining_budget, 1_700);
+
+    // 5kg is capped at 4kg, so 400 points
+    let material_id = verified_material(&env, &f, WasteType::Metal, 5_000);
+    let points_before = f.client.get_participant(&f.collector).unwrap().total_tokens_earned;
+    assert_eq!(f.client.calculate_incentive_reward(&incentive.id, &5_000), 400);
+    assert_eq!(f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector), 400);
+
+    let incentive = f.client.get_incentive_by_id(&incentive.id).unwrap();
+    assert_eq!(incentive.remaining_budget, 1_300);
+    assert_eq!(f.client.get_incentive_claim(&material_id), Some(incentive.id));
+    assert_eq!(
+        f.client.get_participant(&f.collector).unwrap().total_tokens_earned,
+        points_before + 400
+    );
+
+    // The reward is paid in full, one token per point
+    assert_eq!(f.token.balance(&f.collector), 400);
+    assert_eq!(f.client.get_incentive_escrow(&incentive.id), 300);
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Insufficient escrowed funds")]
+fn test_claim_requires_escrowed_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Metal, &100, &10_000, &1_000, &f.manufacturer);
+    f.client.top_up_incentive(&incentive.id, &300);
+    let material_id = verified_material(&env, &f, WasteType::Metal, 4_000);
+
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector);
+}
+
+#[test]
+fn test_exhausted_incentive_reactivates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &0, &f.manufacturer);
+    f.client.top_up_incentive(&incentive.id, &500);
+    let material_id = verified_material(&env, &f, WasteType::Paper, 5_000);
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector);
+
+    let exhausted = f.client.get_incentive_by_id(&incentive.id).unwrap();
+    assert!(!exhausted.is_active);
+    assert_eq!(exhausted.remaining_budget, 0);
+    assert_eq!(f.client.get_incentives(&WasteType::Paper).len(), 0);
+
+    // Topping up adds to the budget and reactivates it
+    f.client.top_up_incentive(&incentive.id, &1_000);
+    let topped_up = f.client.get_incentive_by_id(&incentive.id).unwrap();
+    assert!(topped_up.is_active);
+    assert_eq!(topped_up.total_budget, 1_500);
+    assert_eq!(topped_up.remaining_budget, 1_000);
+
+    // Shrinking the budget below what was paid out exhausts it again
+    let updated = f.client.update_incentive(&incentive.id, &100, &10_000, &400);
+    assert!(!updated.is_active);
+    assert_eq!(updated.remaining_budget, 0);
+
+    // Closing refunds the escrow that was never paid out
+    assert_eq!(f.client.close_incentive(&incentive.id), 1_000);
+    assert_eq!(f.token.balance(&f.manufacturer), 9_500);
+}
+
+#[test]
+#[should_panic(expected = "Incentive budget exhausted")]
+fn test_exhausted_incentive_cannot_be_reactivated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &0, &f.manufacturer);
+    f.client.top_up_incentive(&incentive.id, &500);
+    let material_id = verified_material(&env, &f, WasteType::Paper, 5_000);
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector);
+
+    f.client.update_incentive_status(&incentive.id, &true);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient incentive budget")]
+fn test_claim_over_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &400, &f.manufacturer);
+    let material_id = verified_material(&env, &f, WasteType::Paper, 5_000);
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector);
+}
+
+#[test]
+#[should_panic(expected = "Reward already claimed")]
+fn test_material_claims_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let first = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &5_000, &f.manufacturer);
+    let second = f.client.create_incentive(&WasteType::Paper, &50, &10_000, &5_000, &f.manufacturer);
+    f.client.top_up_incentive(&first.id, &500);
+    let material_id = verified_material(&env, &f, WasteType::Paper, 5_000);
+
+    f.client.claim_incentive_reward(&first.id, &material_id, &f.collector);
+    f.client.claim_incentive_reward(&second.id, &material_id, &f.collector);
+}
+
+#[test]
+#[should_panic(expected = "Material not verified")]
+fn test_claim_requires_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &5_000, &f.manufacturer);
+    let desc = String::from_str(&env, "bale");
+    let material = f.client.submit_material(&WasteType::Paper, &5_000, &f.collector, &desc, &None);
+
+    f.client.claim_incentive_reward(&incentive.id, &material.id, &f.collector);
+}
+
+#[test]
+#[should_panic(expected = "Only a supplier of the material can claim")]
+fn test_only_supplier_can_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &5_000, &f.manufacturer);
+    let material_id = verified_material(&env, &f, WasteType::Paper, 5_000);
+
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.recycler);
+}
+
+#[test]
+#[should_panic(expected = "Waste not delivered to incentive manufacturer")]
+fn test_claim_against_another_manufacturer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let rival = Address::generate(&env);
+    f.client.register_participant(&rival, &ParticipantRole::Manufacturer, &symbol_short!("rival"), &0, &0);
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &5_000, &rival);
+    token::StellarAssetClient::new(&env, &f.token.address).mint(&rival, &5_000);
+    f.client.top_up_incentive(&incentive.id, &5_000);
+
+    // Delivered to the first manufacturer, not to the rival paying the incentive
+    let material_id = verified_material(&env, &f, WasteType::Paper, 5_000);
+
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector);
+}
+
+#[test]
+#[should_panic(expected = "Incentive targets specific sub-types")]
+fn test_sub_typed_incentive_cannot_pay_materials() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Plastic, &100, &10_000, &5_000, &f.manufacturer);
+    f.client.set_incentive_sub_types(&incentive.id, &vec![&env, WasteSubType::Hdpe]);
+    let material_id = verified_material(&env, &f, WasteType::Plastic, 5_000);
+
+    f.client.claim_incentive_reward(&incentive.id, &material_id, &f.collector);
+}
+
+#[test]
+#[should_panic(expected = "Only incentive creator can deactivate")]
+fn test_only_creator_can_deactivate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let f = setup(&env);
+
+    let incentive = f.client.create_incentive(&WasteType::Paper, &100, &10_000, &5_000, &f.manufacturer);
+    f.client.deactivate_incentive(&incentive.id, &f.collector);
+}
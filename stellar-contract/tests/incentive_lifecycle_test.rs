@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    token, Address, Env,
+};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, token::Client<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    token::StellarAssetClient::new(env, &token_id).mint(&manufacturer, &10_000);
+    client.set_market_token(&admin, &token_id);
+
+    (client, token::Client::new(env, &token_id), manufacturer)
+}
+
+#[test]
+fn test_create_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, manufacturer) = setup(&env);
+
+    client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    assert!(!env.events().all().is_empty());
+}
+
+#[test]
+fn test_top_up_escrows_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    assert_eq!(client.top_up_incentive(&incentive.id, &3_000), 3_000);
+    assert_eq!(client.top_up_incentive(&incentive.id, &1_000), 4_000);
+
+    assert_eq!(client.get_incentive_escrow(&incentive.id), 4_000);
+    assert_eq!(client.get_incentive_by_id(&incentive.id).unwrap().total_budget, 54_000);
+    assert_eq!(token.balance(&manufacturer), 6_000);
+}
+
+#[test]
+fn test_top_up_activates_empty_incentive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, manufacturer) = setup(&env);
+
+    // With nothing to pay out the incentive starts inactive
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &0, &manufacturer);
+    assert!(!incentive.is_active);
+
+    client.top_up_incentive(&incentive.id, &2_000);
+    let topped_up = client.get_incentive_by_id(&incentive.id).unwrap();
+    assert!(topped_up.is_active);
+    assert_eq!(topped_up.remaining_budget, 2_000);
+}
+
+#[test]
+fn test_top_up_keeps_paused_incentive_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    client.update_incentive_status(&incentive.id, &false);
+
+    client.top_up_incentive(&incentive.id, &2_000);
+    assert!(!client.get_incentive_by_id(&incentive.id).unwrap().is_active);
+}
+
+#[test]
+fn test_close_refunds_and_deactivates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    client.top_up_incentive(&incentive.id, &3_000);
+
+    assert_eq!(client.close_incentive(&incentive.id), 3_000);
+    assert!(client.is_incentive_closed(&incentive.id));
+    assert!(!client.get_incentive_by_id(&incentive.id).unwrap().is_active);
+    assert_eq!(client.get_incentive_escrow(&incentive.id), 0);
+    assert_eq!(token.balance(&manufacturer), 10_000);
+}
+
+#[test]
+fn test_status_toggle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    assert!(!client.update_incentive_status(&incentive.id, &false).is_active);
+    assert!(client.update_incentive_status(&incentive.id, &true).is_active);
+}
+
+#[test]
+#[should_panic(expected = "Incentive is closed")]
+fn test_closed_incentive_cannot_be_reactivated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    client.close_incentive(&incentive.id);
+    client.update_incentive_status(&incentive.id, &true);
+}
+
+#[test]
+#[should_panic(expected = "Incentive is closed")]
+fn test_closed_incentive_cannot_be_topped_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &10_000, &50_000, &manufacturer);
+    client.close_incentive(&incentive.id);
+    client.top_up_incentive(&incentive.id, &1_000);
+}
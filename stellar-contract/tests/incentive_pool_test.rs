@@ -0,0 +1,192 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, token, Address, Env, String};
+use stellar_scavngr_contract::{
+    Incentive, ParticipantRole, PoolPayout, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+struct Pool<'a> {
+    client: ScavengerContractClient<'a>,
+    token: token::Client<'a>,
+    lead: Address,
+    partner: Address,
+    collector: Address,
+    recycler: Address,
+}
+
+fn setup(env: &Env) -> Pool<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let lead = Address::generate(env);
+    let partner = Address::generate(env);
+    let collector = Address::generate(env);
+    let recycler = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&lead, &ParticipantRole::Manufacturer, &symbol_short!("lead"), &0, &0);
+    client.register_participant(&partner, &ParticipantRole::Manufacturer, &symbol_short!("partner"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    let minter = token::StellarAssetClient::new(env, &token_id);
+    minter.mint(&lead, &10_000);
+    minter.mint(&partner, &10_000);
+    client.set_market_token(&admin, &token_id);
+
+    Pool {
+        client,
+        token: token::Client::new(env, &token_id),
+        lead,
+        partner,
+        collector,
+        recycler,
+    }
+}
+
+/// Verify 10kg of paper for the collector, deliver it to the lead funder and
+/// claim the 100 points/kg pool reward for it
+fn claim_delivery(env: &Env, p: &Pool, pool: &Incentive) -> u128 {
+    let desc = String::from_str(env, "bale");
+    let material = p.client.submit_material(&WasteType::Paper, &10_000, &p.collector, &desc, &None);
+    p.client.verify_material(&material.id, &p.recycler);
+    p.client.transfer_waste(&material.id, &p.collector, &p.lead, &desc);
+    p.client.claim_incentive_reward(&pool.id, &material.id, &p.collector)
+}
+
+fn create_pool(p: &Pool, payout: PoolPayout) -> Incentive {
+    p.client.create_incentive_pool(&WasteType::Paper, &100, &20_000, &p.lead, &payout)
+}
+
+#[test]
+fn test_contributions_build_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::ProRata);
+    assert_eq!(pool.total_budget, 0);
+    assert!(!pool.is_active);
+    assert_eq!(p.client.get_pool_payout(&pool.id), Some(PoolPayout::ProRata));
+
+    p.client.contribute_to_pool(&p.lead, &pool.id, &3_000, &0);
+    let contribution = p.client.contribute_to_pool(&p.partner, &pool.id, &1_000, &0);
+    assert_eq!(contribution.contributed, 1_000);
+    assert_eq!(contribution.remaining, 1_000);
+
+    let pool = p.client.get_incentive_by_id(&pool.id).unwrap();
+    assert!(pool.is_active);
+    assert_eq!(pool.total_budget, 4_000);
+    assert_eq!(pool.remaining_budget, 4_000);
+    assert_eq!(p.client.get_incentive_escrow(&pool.id), 4_000);
+    assert_eq!(p.token.balance(&p.client.address), 4_000);
+    assert_eq!(p.client.get_pool_contributions(&pool.id).len(), 2);
+}
+
+#[test]
+fn test_pro_rata_payout_and_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::ProRata);
+    p.client.contribute_to_pool(&p.lead, &pool.id, &3_000, &0);
+    p.client.contribute_to_pool(&p.partner, &pool.id, &1_000, &0);
+
+    assert_eq!(claim_delivery(&env, &p, &pool), 1_000);
+    assert_eq!(p.token.balance(&p.collector), 1_000);
+
+    let contributions = p.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 2_250);
+    assert_eq!(contributions.get(1).unwrap().remaining, 750);
+    assert_eq!(p.client.get_incentive_escrow(&pool.id), 3_000);
+
+    // Closing refunds each funder's unspent contribution
+    assert_eq!(p.client.close_incentive(&pool.id), 3_000);
+    assert_eq!(p.token.balance(&p.lead), 9_250);
+    assert_eq!(p.token.balance(&p.partner), 9_750);
+    assert_eq!(p.client.get_incentive_escrow(&pool.id), 0);
+    assert!(!p.client.get_incentive_by_id(&pool.id).unwrap().is_active);
+}
+
+#[test]
+fn test_configured_shares_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::Shares);
+    p.client.contribute_to_pool(&p.lead, &pool.id, &2_000, &2_500);
+    p.client.contribute_to_pool(&p.partner, &pool.id, &2_000, &7_500);
+
+    claim_delivery(&env, &p, &pool);
+
+    let contributions = p.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 1_750);
+    assert_eq!(contributions.get(1).unwrap().remaining, 1_250);
+}
+
+#[test]
+fn test_exhausted_share_drawn_from_others() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::Shares);
+    p.client.contribute_to_pool(&p.lead, &pool.id, &100, &9_000);
+    p.client.contribute_to_pool(&p.partner, &pool.id, &5_000, &1_000);
+
+    claim_delivery(&env, &p, &pool);
+
+    let contributions = p.client.get_pool_contributions(&pool.id);
+    assert_eq!(contributions.get(0).unwrap().remaining, 0);
+    assert_eq!(contributions.get(1).unwrap().remaining, 4_100);
+}
+
+#[test]
+#[should_panic(expected = "Shares exceed 100%")]
+fn test_shares_cannot_exceed_whole() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::Shares);
+    p.client.contribute_to_pool(&p.lead, &pool.id, &1_000, &6_000);
+    p.client.contribute_to_pool(&p.partner, &pool.id, &1_000, &5_000);
+}
+
+#[test]
+#[should_panic(expected = "Only manufacturers can fund pools")]
+fn test_only_manufacturers_contribute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::ProRata);
+    p.client.contribute_to_pool(&p.recycler, &pool.id, &1_000, &0);
+}
+
+#[test]
+#[should_panic(expected = "Contribute to pooled incentives instead")]
+fn test_pool_cannot_be_topped_up_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::ProRata);
+    p.client.top_up_incentive(&pool.id, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Pooled budgets follow contributions")]
+fn test_pool_budget_cannot_be_set() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let p = setup(&env);
+
+    let pool = create_pool(&p, PoolPayout::ProRata);
+    p.client.contribute_to_pool(&p.lead, &pool.id, &1_000, &0);
+    p.client.update_incentive(&pool.id, &100, &20_000, &5_000);
+}
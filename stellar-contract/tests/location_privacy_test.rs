@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Events}, vec, Address, BytesN, Env,
+};
+use stellar_scavngr_contract::{
+    location_commitment, LocationPrivacy, ParticipantRole, ScavengerContract,
+    ScavengerContractClient, WasteType,
+};
+
+// Central Lagos, scaled by 1e6
+const LAT: i128 = 6_524_000;
+const LON: i128 = 3_379_000;
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &LAT, &LON);
+
+    (client, recycler)
+}
+
+#[test]
+fn test_default_mode_is_exact() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    assert_eq!(client.get_location_privacy(&recycler), LocationPrivacy::Exact);
+    let participant = client.get_participant(&recycler).unwrap();
+    assert_eq!((participant.latitude, participant.longitude), (LAT, LON));
+}
+
+#[test]
+fn test_coarse_mode_snaps_to_grid_cell() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    let participant = client.set_location_privacy(&recycler, &LocationPrivacy::Coarse, &None);
+    assert_eq!((participant.latitude, participant.longitude), (6_550_000, 3_350_000));
+    assert!(!env.events().all().is_empty());
+
+    let participant = client.update_location(&recycler, &(LAT + 1_000_000), &LON);
+    assert_eq!((participant.latitude, participant.longitude), (7_550_000, 3_350_000));
+
+    // Wastes are registered at the cell centre too
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1_000, &recycler, &LAT, &LON, &None);
+    let waste = client.get_waste_v2(&waste_id).unwrap();
+    assert_eq!((waste.latitude, waste.longitude), (6_550_000, 3_350_000));
+    assert_eq!(client.find_wastes_near(&6_550_000, &3_350_000, &100, &None), vec![&env, waste_id]);
+}
+
+#[test]
+fn test_committed_location_selective_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    let commitment = location_commitment(&env, LAT, LON, &salt);
+    let participant = client.set_location_privacy(&recycler, &LocationPrivacy::Committed, &Some(commitment.clone()));
+
+    assert_eq!((participant.latitude, participant.longitude), (0, 0));
+    assert_eq!(client.get_location_commitment(&recycler), Some(commitment));
+    assert_eq!(client.find_participants_near(&LAT, &LON, &5_000, &None).len(), 0);
+
+    assert!(client.verify_location(&recycler, &LAT, &LON, &salt));
+    assert!(!client.verify_location(&recycler, &LAT, &(LON + 1), &salt));
+    assert!(!client.verify_location(&recycler, &LAT, &LON, &BytesN::from_array(&env, &[8u8; 32])));
+}
+
+#[test]
+fn test_leaving_committed_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    let salt = BytesN::from_array(&env, &[1u8; 32]);
+    client.set_location_privacy(&recycler, &LocationPrivacy::Committed, &Some(location_commitment(&env, LAT, LON, &salt)));
+    client.set_location_privacy(&recycler, &LocationPrivacy::Exact, &None);
+
+    assert_eq!(client.get_location_commitment(&recycler), None);
+    assert!(!client.verify_location(&recycler, &LAT, &LON, &salt));
+    assert_eq!(client.find_participants_near(&0, &0, &5_000, &None).len(), 0);
+
+    client.update_location(&recycler, &LAT, &LON);
+    assert_eq!(client.find_participants_near(&LAT, &LON, &5_000, &None), vec![&env, recycler]);
+}
+
+#[test]
+#[should_panic(expected = "Location is committed")]
+fn test_committed_location_cannot_be_updated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    let salt = BytesN::from_array(&env, &[1u8; 32]);
+    client.set_location_privacy(&recycler, &LocationPrivacy::Committed, &Some(location_commitment(&env, LAT, LON, &salt)));
+    client.update_location(&recycler, &LAT, &LON);
+}
+
+#[test]
+#[should_panic(expected = "Commitment required")]
+fn test_committed_mode_requires_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    client.set_location_privacy(&recycler, &LocationPrivacy::Committed, &None);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_register_rejects_out_of_range_latitude() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _recycler) = setup(&env);
+
+    let collector = Address::generate(&env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &90_000_001, &0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_update_location_rejects_out_of_range_longitude() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    client.update_location(&recycler, &LAT, &-180_000_001);
+}
+
+#[test]
+#[should_panic(expected = "Invalid coordinates")]
+fn test_recycle_waste_rejects_invalid_coordinates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler) = setup(&env);
+
+    client.recycle_waste(&WasteType::Metal, &1_000, &recycler, &i128::MAX, &LON, &None);
+}
@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env,
+};
+use stellar_scavngr_contract::{
+    ListingStatus, OfferStatus, ParticipantRole, ScavengerContract, ScavengerContractClient,
+    WasteType,
+};
+
+// Central Lagos, scaled by 1e6
+const LAT: i128 = 6_524_000;
+const LON: i128 = 3_379_000;
+
+struct Market<'a> {
+    client: ScavengerContractClient<'a>,
+    token: token::Client<'a>,
+    recycler: Address,
+    collector: Address,
+    rival: Address,
+    waste_id: u128,
+}
+
+fn setup(env: &Env) -> Market<'_> {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let rival = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &LAT, &LON);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &LAT, &LON);
+    client.register_participant(&rival, &ParticipantRole::Collector, &symbol_short!("riv"), &LAT, &LON);
+
+    let token_id = env.register_stellar_asset_contract(admin.clone());
+    let minter = token::StellarAssetClient::new(env, &token_id);
+    minter.mint(&collector, &1_000);
+    minter.mint(&rival, &1_000);
+    client.set_market_token(&admin, &token_id);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &5_000, &recycler, &LAT, &LON, &None);
+
+    Market {
+        client,
+        token: token::Client::new(env, &token_id),
+        recycler,
+        collector,
+        rival,
+        waste_id,
+    }
+}
+
+#[test]
+fn test_create_listing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    assert_eq!(listing.status, ListingStatus::Open);
+    assert_eq!(listing.waste_type, WasteType::Plastic);
+    assert_eq!((listing.latitude, listing.longitude), (LAT, LON));
+    assert_eq!(m.client.get_listing(&listing.listing_id), Some(listing.clone()));
+    assert!(!env.events().all().is_empty());
+
+    assert_eq!(m.client.get_listings_by_type(&WasteType::Plastic), vec![&env, listing.clone()]);
+    assert_eq!(m.client.get_listings_by_type(&WasteType::Metal).len(), 0);
+    assert_eq!(m.client.find_listings_near(&LAT, &LON, &1_000, &None), vec![&env, listing]);
+}
+
+#[test]
+fn test_accept_offer_settles_atomically() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    let offer = m.client.place_offer(&m.collector, &listing.listing_id, &250, &600);
+    let rival = m.client.place_offer(&m.rival, &listing.listing_id, &200, &600);
+    assert_eq!(m.token.balance(&m.collector), 750);
+    assert_eq!(m.token.balance(&m.client.address), 450);
+
+    let transfer = m.client.accept_offer(&m.recycler, &offer.offer_id);
+    assert_eq!(transfer.to, m.collector);
+
+    assert_eq!(m.client.get_waste_v2(&m.waste_id).unwrap().current_owner, m.collector);
+    assert_eq!(m.token.balance(&m.recycler), 250);
+    assert_eq!(m.token.balance(&m.rival), 1_000);
+    assert_eq!(m.token.balance(&m.client.address), 0);
+
+    assert_eq!(m.client.get_listing(&listing.listing_id).unwrap().status, ListingStatus::Sold);
+    assert_eq!(m.client.get_offer(&offer.offer_id).unwrap().status, OfferStatus::Accepted);
+    assert_eq!(m.client.get_offer(&rival.offer_id).unwrap().status, OfferStatus::Refunded);
+    assert_eq!(m.client.get_listings_by_type(&WasteType::Plastic).len(), 0);
+}
+
+#[test]
+fn test_withdraw_offer_refunds_buyer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    let offer = m.client.place_offer(&m.collector, &listing.listing_id, &250, &600);
+
+    let offer = m.client.withdraw_offer(&m.collector, &offer.offer_id);
+    assert_eq!(offer.status, OfferStatus::Withdrawn);
+    assert_eq!(m.token.balance(&m.collector), 1_000);
+    assert_eq!(m.client.get_listing_offers(&listing.listing_id), vec![&env, offer]);
+}
+
+#[test]
+fn test_cancel_listing_releases_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    m.client.place_offer(&m.collector, &listing.listing_id, &250, &600);
+
+    let listing = m.client.cancel_listing(&m.recycler, &listing.listing_id);
+    assert_eq!(listing.status, ListingStatus::Cancelled);
+    assert_eq!(m.token.balance(&m.collector), 1_000);
+
+    // Once delisted the waste moves freely again
+    m.client.transfer_waste_v2(&m.waste_id, &m.recycler, &m.collector, &LAT, &LON, &None);
+    assert_eq!(m.client.get_waste_v2(&m.waste_id).unwrap().current_owner, m.collector);
+}
+
+#[test]
+fn test_expire_listing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    m.client.place_offer(&m.collector, &listing.listing_id, &250, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    assert_eq!(m.client.get_listings_by_type(&WasteType::Plastic).len(), 0);
+
+    let listing = m.client.expire_listing(&listing.listing_id);
+    assert_eq!(listing.status, ListingStatus::Expired);
+    assert_eq!(m.token.balance(&m.collector), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Waste is listed for sale")]
+fn test_listed_waste_cannot_be_transferred() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    m.client.transfer_waste_v2(&m.waste_id, &m.recycler, &m.collector, &LAT, &LON, &None);
+}
+
+#[test]
+#[should_panic(expected = "Offer is not live")]
+fn test_expired_offer_cannot_be_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    let offer = m.client.place_offer(&m.collector, &listing.listing_id, &250, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 600);
+    m.client.accept_offer(&m.recycler, &offer.offer_id);
+}
+
+#[test]
+#[should_panic(expected = "Invalid transfer")]
+fn test_offer_requires_valid_route() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    // Recyclers cannot sell to other recyclers
+    let other = Address::generate(&env);
+    m.client.register_participant(&other, &ParticipantRole::Recycler, &symbol_short!("oth"), &LAT, &LON);
+
+    let listing = m.client.create_listing(&m.recycler, &m.waste_id, &300, &3_600);
+    m.client.place_offer(&other, &listing.listing_id, &250, &600);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not own waste")]
+fn test_only_owner_can_list() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let m = setup(&env);
+
+    m.client.create_listing(&m.collector, &m.waste_id, &300, &3_600);
+}
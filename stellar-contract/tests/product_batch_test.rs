@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteStatus, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, recycler, collector, manufacturer)
+}
+
+/// Register waste and move it through to a processed state at the manufacturer
+fn processed_waste(
+    client: &ScavengerContractClient,
+    recycler: &Address,
+    collector: &Address,
+    manufacturer: &Address,
+    weight: u128,
+    latitude: i128,
+) -> u128 {
+    let waste_id = client.recycle_waste(&WasteType::PetPlastic, &weight, recycler, &latitude, &7_000_000, &None);
+    client.transfer_waste_v2(&waste_id, recycler, collector, &1, &1, &None);
+    client.transfer_waste_v2(&waste_id, collector, manufacturer, &2, &2, &None);
+    client.mark_processed(&waste_id, manufacturer);
+    waste_id
+}
+
+#[test]
+fn test_create_product_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let first = processed_waste(&client, &recycler, &collector, &manufacturer, 3_000, 1_000_000);
+    let second = processed_waste(&client, &recycler, &collector, &manufacturer, 2_000, 2_000_000);
+
+    let batch = client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("bottles"),
+        &10_000,
+        &vec![&env, (first, 3_000u128), (second, 1_000u128)],
+    );
+
+    assert_eq!(batch.batch_id, 1);
+    assert_eq!(batch.recycled_weight, 4_000);
+    assert_eq!(batch.recycled_content_percentage(), 40);
+    assert_eq!(client.get_recycled_content(&batch.batch_id), 40);
+    assert_eq!(client.get_product_batch(&1), Some(batch));
+    assert_eq!(client.get_manufacturer_batches(&manufacturer), vec![&env, 1u64]);
+
+    // Fully consumed waste is recycled, partially consumed waste stays processed
+    assert_eq!(client.get_waste_v2(&first).unwrap().status, WasteStatus::Recycled);
+    assert_eq!(client.get_waste_v2(&second).unwrap().status, WasteStatus::Processed);
+}
+
+#[test]
+fn test_trace_product() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = processed_waste(&client, &recycler, &collector, &manufacturer, 3_000, 1_000_000);
+    client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("bottles"),
+        &3_000,
+        &vec![&env, (waste_id, 3_000u128)],
+    );
+
+    let trace = client.trace_product(&1);
+    assert_eq!(trace.len(), 1);
+
+    let record = trace.get(0).unwrap();
+    assert_eq!(record.waste_id, waste_id);
+    assert_eq!(record.origin_waste_id, waste_id);
+    assert_eq!(record.recycler, recycler);
+    assert_eq!(record.latitude, 1_000_000);
+    assert_eq!(record.longitude, 7_000_000);
+    assert_eq!(record.weight, 3_000);
+    assert_eq!(record.hops.len(), 2);
+    assert_eq!(record.hops.get(1).unwrap().to, manufacturer);
+}
+
+#[test]
+fn test_trace_product_through_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let parent = client.recycle_waste(&WasteType::PetPlastic, &6_000, &recycler, &5_000_000, &6_000_000, &None);
+    client.transfer_waste_v2(&parent, &recycler, &collector, &1, &1, &None);
+    let children = client.split_waste(&parent, &collector, &vec![&env, 4_000u128, 2_000u128]);
+    let child = children.get(0).unwrap();
+    client.transfer_waste_v2(&child, &collector, &manufacturer, &2, &2, &None);
+    client.mark_processed(&child, &manufacturer);
+
+    client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("fleece"),
+        &8_000,
+        &vec![&env, (child, 4_000u128)],
+    );
+
+    let record = client.trace_product(&1).get(0).unwrap();
+    assert_eq!(record.waste_id, child);
+    assert_eq!(record.origin_waste_id, parent);
+    assert_eq!(record.recycler, recycler);
+    assert_eq!(record.latitude, 5_000_000);
+    assert_eq!(record.hops.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Waste must be processed before use")]
+fn test_batch_requires_processed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+
+    client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("jars"),
+        &1_000,
+        &vec![&env, (waste_id, 1_000u128)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Input exceeds remaining waste weight")]
+fn test_batch_cannot_overconsume_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = processed_waste(&client, &recycler, &collector, &manufacturer, 3_000, 0);
+    client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("a"),
+        &5_000,
+        &vec![&env, (waste_id, 2_000u128)],
+    );
+    client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("b"),
+        &5_000,
+        &vec![&env, (waste_id, 2_000u128)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recycled inputs exceed batch weight")]
+fn test_batch_recycled_exceeds_total() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = processed_waste(&client, &recycler, &collector, &manufacturer, 3_000, 0);
+    client.create_product_batch(
+        &manufacturer,
+        &symbol_short!("a"),
+        &1_000,
+        &vec![&env, (waste_id, 3_000u128)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only manufacturers can perform this action")]
+fn test_batch_non_manufacturer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = processed_waste(&client, &recycler, &collector, &manufacturer, 3_000, 0);
+    client.create_product_batch(
+        &collector,
+        &symbol_short!("a"),
+        &3_000,
+        &vec![&env, (waste_id, 3_000u128)],
+    );
+}
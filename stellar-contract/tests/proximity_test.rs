@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+// Central Lagos, scaled by 1e6
+const LAT: i128 = 6_524_000;
+const LON: i128 = 3_379_000;
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &LAT, &LON);
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_find_wastes_near() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = setup(&env);
+
+    // ~1.1km north, ~22km north, and a different cell ~3.3km east
+    let near = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &(LAT + 10_000), &LON, &None);
+    client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &(LAT + 200_000), &LON, &None);
+    let east = client.recycle_waste(&WasteType::Metal, &1_000, &recycler, &LAT, &(LON + 30_000), &None);
+
+    assert_eq!(client.find_wastes_near(&LAT, &LON, &5_000, &None), vec![&env, near, east]);
+    assert_eq!(client.find_wastes_near(&LAT, &LON, &5_000, &Some(WasteType::Metal)), vec![&env, east]);
+    assert_eq!(client.find_wastes_near(&LAT, &LON, &500, &None).len(), 0);
+}
+
+#[test]
+fn test_find_participants_near_by_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = setup(&env);
+
+    let collector = Address::generate(&env);
+    let far_collector = Address::generate(&env);
+    let manufacturer = Address::generate(&env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &(LAT - 20_000), &LON);
+    client.register_participant(&far_collector, &ParticipantRole::Collector, &symbol_short!("far"), &(LAT + 500_000), &LON);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &LAT, &(LON + 1_000));
+
+    assert_eq!(
+        client.find_participants_near(&LAT, &LON, &5_000, &Some(ParticipantRole::Collector)),
+        vec![&env, collector.clone()]
+    );
+    assert_eq!(client.find_participants_near(&LAT, &LON, &5_000, &None).len(), 3);
+    assert!(client
+        .find_participants_near(&LAT, &LON, &5_000, &None)
+        .contains(&recycler));
+}
+
+#[test]
+fn test_update_location_moves_participant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = setup(&env);
+
+    client.update_location(&recycler, &(LAT + 1_000_000), &LON);
+
+    assert_eq!(client.find_participants_near(&LAT, &LON, &5_000, &None).len(), 0);
+    assert_eq!(
+        client.find_participants_near(&(LAT + 1_000_000), &LON, &5_000, &None),
+        vec![&env, recycler]
+    );
+}
+
+#[test]
+fn test_transfer_moves_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = setup(&env);
+
+    let collector = Address::generate(&env);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1_000, &recycler, &LAT, &LON, &None);
+    let depot = (LAT + 400_000, LON + 400_000);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &depot.0, &depot.1, &None);
+
+    assert_eq!(client.get_waste_location(&waste_id), depot);
+    assert_eq!(client.find_wastes_near(&LAT, &LON, &5_000, &None).len(), 0);
+    assert_eq!(client.find_wastes_near(&depot.0, &depot.1, &5_000, &None), vec![&env, waste_id]);
+    // The registration point is kept for provenance
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().latitude, LAT);
+}
+
+#[test]
+fn test_inactive_wastes_excluded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &3_000, &recycler, &LAT, &LON, &None);
+    let children = client.split_waste(&waste_id, &recycler, &vec![&env, 1_000u128, 2_000u128]);
+    assert_eq!(client.find_wastes_near(&LAT, &LON, &1_000, &None), children);
+
+    client.deactivate_waste(&children.get(0).unwrap(), &admin);
+    assert_eq!(
+        client.find_wastes_near(&LAT, &LON, &1_000, &None),
+        vec![&env, children.get(1).unwrap()]
+    );
+}
+
+#[test]
+fn test_search_across_antimeridian() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1_000, &recycler, &0, &179_990_000, &None);
+
+    assert_eq!(client.find_wastes_near(&0, &-179_990_000, &5_000, &None), vec![&env, waste_id]);
+}
+
+#[test]
+#[should_panic(expected = "Search radius too large")]
+fn test_search_radius_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _recycler) = setup(&env);
+
+    client.find_wastes_near(&LAT, &LON, &50_001, &None);
+}
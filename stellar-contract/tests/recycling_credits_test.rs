@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Events}, vec, Address, Env, IntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_mint_credits_from_processed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::PetPlastic, &4_500, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+    client.mark_processed(&waste_id, &manufacturer);
+
+    let minted = client.mint_credits(&waste_id, &manufacturer);
+
+    assert_eq!(minted, 4_500);
+    assert!(client.is_waste_credited(&waste_id));
+    assert_eq!(client.get_credit_balance(&manufacturer, &WasteType::PetPlastic), 4_500);
+    assert_eq!(client.get_credit_balance(&manufacturer, &WasteType::Glass), 0);
+}
+
+#[test]
+fn test_mint_credits_from_confirmed_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &2_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+
+    client.mint_credits(&waste_id, &recycler);
+
+    assert_eq!(client.get_credit_balance(&recycler, &WasteType::Glass), 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Waste must be processed or confirmed")]
+fn test_mint_credits_unverified_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &2_000, &recycler, &0, &0, &None);
+    client.mint_credits(&waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Credits already minted for waste")]
+fn test_mint_credits_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &2_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.mint_credits(&waste_id, &recycler);
+    client.mint_credits(&waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Cannot split credited waste")]
+fn test_credited_waste_cannot_be_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &2_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.mint_credits(&waste_id, &recycler);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 1_000u128, 1_000u128]);
+}
+
+#[test]
+fn test_transfer_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.mint_credits(&waste_id, &recycler);
+
+    client.transfer_credits(&recycler, &buyer, &WasteType::Metal, &1_200);
+
+    assert_eq!(client.get_credit_balance(&recycler, &WasteType::Metal), 1_800);
+    assert_eq!(client.get_credit_balance(&buyer, &WasteType::Metal), 1_200);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient credit balance")]
+fn test_transfer_credits_insufficient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    client.transfer_credits(&recycler, &buyer, &WasteType::Metal, &1);
+}
+
+#[test]
+fn test_retire_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &10_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.mint_credits(&waste_id, &recycler);
+    client.transfer_credits(&recycler, &buyer, &WasteType::Paper, &6_000);
+
+    let certificate = client.retire_credits(&buyer, &WasteType::Paper, &4_000);
+
+    assert_eq!(certificate.certificate_id, 1);
+    assert_eq!(certificate.owner, buyer);
+    assert_eq!(certificate.grams, 4_000);
+    assert_eq!(client.get_retirement_certificate(&1), Some(certificate));
+    assert_eq!(client.get_credit_balance(&buyer, &WasteType::Paper), 2_000);
+    assert_eq!(client.get_total_retired(&WasteType::Paper), 4_000);
+
+    let second = client.retire_credits(&buyer, &WasteType::Paper, &2_000);
+    assert_eq!(second.certificate_id, 2);
+    assert_eq!(client.get_total_retired(&WasteType::Paper), 6_000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient credit balance")]
+fn test_retired_credits_cannot_be_reused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+    let buyer = Address::generate(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.mint_credits(&waste_id, &recycler);
+    client.retire_credits(&recycler, &WasteType::Paper, &1_000);
+
+    client.transfer_credits(&recycler, &buyer, &WasteType::Paper, &1_000);
+}
+
+#[test]
+fn test_credit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.mint_credits(&waste_id, &recycler);
+    client.retire_credits(&recycler, &WasteType::Paper, &1_000);
+
+    let events = env.events().all();
+    let minted: Vec<Val> = (symbol_short!("cr_mint"), waste_id).into_val(&env);
+    let retired: Vec<Val> = (symbol_short!("cr_retire"), 1u64).into_val(&env);
+    assert!(events.iter().any(|e| e.1 == minted));
+    assert!(events.iter().any(|e| e.1 == retired));
+}
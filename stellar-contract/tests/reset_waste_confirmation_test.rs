@@ -46,7 +46,7 @@ fn test_reset_waste_confirmation() {
     );
 
     // Confirm the waste
-    client.confirm_waste_details(&waste.waste_id, &confirmer);
+    client.confirm_waste_details(&waste.waste_id, &confirmer, &None);
 
     // Verify waste is confirmed
     let confirmed_waste = client.get_waste(&waste.waste_id);
@@ -61,7 +61,7 @@ fn test_reset_waste_confirmation() {
     assert_eq!(reset_waste.confirmer, owner);
 
     // Verify waste can be re-confirmed
-    let reconfirmed = client.confirm_waste_details(&waste.waste_id, &confirmer);
+    let reconfirmed = client.confirm_waste_details(&waste.waste_id, &confirmer, &None);
     assert_eq!(reconfirmed.is_confirmed, true);
     assert_eq!(reconfirmed.confirmer, confirmer);
 }
@@ -91,7 +91,7 @@ fn test_reset_waste_confirmation_non_owner() {
     );
 
     // Confirm the waste
-    client.confirm_waste_details(&waste.waste_id, &confirmer);
+    client.confirm_waste_details(&waste.waste_id, &confirmer, &None);
 
     // Try to reset as non-owner (should panic)
     client.reset_waste_confirmation(&waste.waste_id, &non_owner);
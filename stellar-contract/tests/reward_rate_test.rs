@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Events, Ledger}, vec, Address, Env, String,
+};
+use stellar_scavngr_contract::{
+    ParticipantRole, RoundingMode, ScavengerContract, ScavengerContractClient, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+
+    (client, admin, recycler, collector)
+}
+
+fn submit(env: &Env, client: &ScavengerContractClient, waste_type: WasteType, weight: u64, submitter: &Address) -> u64 {
+    client
+        .submit_material(
+            &waste_type,
+            &weight,
+            submitter,
+            &String::from_str(env, "material"),
+            &None)
+        .id
+}
+
+#[test]
+fn test_default_rate_from_catalogue() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _recycler, _collector) = setup(&env);
+
+    let rate = client.get_reward_rate(&WasteType::Metal);
+    assert_eq!(rate.points_per_kg, 50);
+    assert_eq!(rate.rounding, RoundingMode::Floor);
+    assert_eq!(rate.version, 0);
+}
+
+#[test]
+fn test_gram_level_precision() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector) = setup(&env);
+
+    // 1.55kg of metal at 50 points/kg = 77.5 points, floored
+    let material_id = submit(&env, &client, WasteType::Metal, 1_550, &collector);
+    client.verify_material(&material_id, &recycler);
+
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, 77);
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 77);
+}
+
+#[test]
+fn test_set_reward_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    let rate = client.set_reward_rate(&admin, &WasteType::Paper, &25, &RoundingMode::HalfUp);
+    assert_eq!(rate.version, 1);
+    assert_eq!(rate.effective_from, 500);
+    assert_eq!(client.get_reward_rate(&WasteType::Paper), rate);
+    assert!(!env.events().all().is_empty());
+
+    // 1.5kg * 25 = 37.5 points, rounded half up
+    let material_id = submit(&env, &client, WasteType::Paper, 1_500, &collector);
+    client.verify_material(&material_id, &recycler);
+
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, 38);
+    assert_eq!(client.get_material_reward_rate(&material_id), Some(rate));
+}
+
+#[test]
+fn test_historical_rate_is_kept() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    let first = submit(&env, &client, WasteType::Glass, 2_000, &collector);
+    client.verify_material(&first, &recycler);
+
+    client.set_reward_rate(&admin, &WasteType::Glass, &100, &RoundingMode::Floor);
+    let second = submit(&env, &client, WasteType::Glass, 2_000, &collector);
+    client.verify_material(&second, &recycler);
+
+    assert_eq!(client.get_material_reward_rate(&first).unwrap().points_per_kg, 20);
+    assert_eq!(client.get_material_reward_rate(&second).unwrap().points_per_kg, 100);
+    // 2kg * 20 + 2kg * 100
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, 240);
+}
+
+#[test]
+fn test_batch_verification_uses_rate_table() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    client.set_reward_rate(&admin, &WasteType::Plastic, &33, &RoundingMode::Ceil);
+    let a = submit(&env, &client, WasteType::Plastic, 1_010, &collector);
+    let b = submit(&env, &client, WasteType::Plastic, 1_000, &collector);
+    client.verify_materials_batch(&vec![&env, a, b], &recycler);
+
+    // ceil(33.33) + 33
+    assert_eq!(client.get_stats(&collector).unwrap().total_points, 67);
+    assert_eq!(client.get_reward_rate(&WasteType::Plastic).version, 1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller is not admin")]
+fn test_set_reward_rate_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector) = setup(&env);
+
+    client.set_reward_rate(&recycler, &WasteType::Paper, &25, &RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic(expected = "Unknown waste type")]
+fn test_set_reward_rate_unknown_type() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _recycler, _collector) = setup(&env);
+
+    client.set_reward_rate(&admin, &WasteType(9), &25, &RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic(expected = "Material already verified")]
+fn test_material_cannot_be_rewarded_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector) = setup(&env);
+
+    let material_id = submit(&env, &client, WasteType::Metal, 1_000, &collector);
+    client.verify_material(&material_id, &recycler);
+    client.verify_material(&material_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Material already verified")]
+fn test_batch_rejects_verified_material() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector) = setup(&env);
+
+    let a = submit(&env, &client, WasteType::Metal, 1_000, &collector);
+    let b = submit(&env, &client, WasteType::Metal, 1_000, &collector);
+    client.verify_material(&a, &recycler);
+    client.verify_materials_batch(&vec![&env, a, b], &recycler);
+}
@@ -0,0 +1,270 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, Address, BytesN, Env, String,
+};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, SubmissionProof, WasteType,
+    WeighReceipt,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    (client, admin, recycler, manufacturer)
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn signed_receipt(
+    env: &Env,
+    contract: &Address,
+    key: &SigningKey,
+    waste_type: WasteType,
+    grams: u128,
+    timestamp: u64,
+    nonce: u64,
+) -> WeighReceipt {
+    let mut receipt = WeighReceipt {
+        device: public_key(env, key),
+        waste_type,
+        grams,
+        timestamp,
+        nonce,
+        signature: BytesN::from_array(env, &[0; 64]),
+    };
+
+    let payload: std::vec::Vec<u8> = receipt.payload(env, contract).iter().collect();
+    receipt.signature = BytesN::from_array(env, &key.sign(&payload).to_bytes());
+    receipt
+}
+
+#[test]
+fn test_attested_recycle_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 9_990, 1);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+
+    assert!(client.is_weight_attested(&waste_id));
+    assert_eq!(client.get_weight_attestation(&waste_id), Some(public_key(&env, &key)));
+}
+
+#[test]
+fn test_attested_material() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Paper, 3_000, 10_000, 1);
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &3_000,
+        &recycler,
+        &String::from_str(&env, "paper"),
+        &Some(SubmissionProof::Receipt(receipt)));
+
+    assert!(client.is_weight_attested(&(material.id as u128)));
+}
+
+#[test]
+fn test_location_bound_device() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[8; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &None, &40_000_000, &-74_000_000);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Glass, 2_000, 10_000, 1);
+    let waste_id = client.recycle_waste(
+        &WasteType::Glass,
+        &2_000,
+        &recycler,
+        &40_000_500,
+        &-74_000_500,
+        &Some(SubmissionProof::Receipt(receipt)));
+
+    assert!(client.is_weight_attested(&waste_id));
+}
+
+#[test]
+fn test_incentive_requires_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let incentive = client.create_incentive(&WasteType::Metal, &100, &1_000_000, &50_000, &manufacturer);
+    client.set_incentive_attestation(&incentive.id, &true);
+
+    let plain = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 10_000, 1);
+    let attested = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+
+    assert_eq!(client.calculate_waste_incentive_reward(&incentive.id, &plain), 0);
+    assert_eq!(client.calculate_waste_incentive_reward(&incentive.id, &attested), 500);
+}
+
+#[test]
+#[should_panic(expected = "Receipt already used")]
+fn test_receipt_replay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 10_000, 1);
+    client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt.clone())));
+    client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+#[should_panic(expected = "Receipt does not match submission")]
+fn test_receipt_weight_mismatch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 10_000, 1);
+    client.recycle_waste(&WasteType::Metal, &6_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+#[should_panic(expected = "Receipt has expired")]
+fn test_stale_receipt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 1_000, 1);
+    client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+#[should_panic(expected = "Device is bound to another participant")]
+fn test_device_bound_to_other_participant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(manufacturer), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 10_000, 1);
+    client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+#[should_panic(expected = "Device is not active")]
+fn test_deactivated_device() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+    client.deactivate_scale_device(&admin, &public_key(&env, &key));
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 10_000, 1);
+    client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+#[should_panic]
+fn test_forged_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let mut receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 10_000, 1);
+    receipt.grams = 50_000;
+    client.recycle_waste(&WasteType::Metal, &50_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+#[should_panic]
+fn test_receipt_for_another_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    // A receipt signed for another deployment cannot be replayed here
+    let other = env.register_contract(None, ScavengerContract);
+    let receipt = signed_receipt(&env, &other, &key, WasteType::Metal, 5_000, 10_000, 1);
+    client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+}
+
+#[test]
+fn test_handover_reweigh_clears_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 9_990, 1);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &manufacturer, &4_900);
+
+    // The signed 5kg no longer describes the waste
+    assert!(!client.is_weight_attested(&waste_id));
+    assert_eq!(client.get_weight_attestation(&waste_id), None);
+}
+
+#[test]
+fn test_matching_handover_keeps_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, manufacturer) = setup(&env);
+
+    let key = SigningKey::from_bytes(&[7; 32]);
+    client.register_scale_device(&admin, &public_key(&env, &key), &Some(recycler.clone()), &0, &0);
+
+    let receipt = signed_receipt(&env, &client.address, &key, WasteType::Metal, 5_000, 9_990, 1);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &Some(SubmissionProof::Receipt(receipt)));
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+    client.record_handover_weight(&waste_id, &manufacturer, &5_000);
+
+    assert!(client.is_weight_attested(&waste_id));
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_split_waste_creates_children() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &200_000, &recycler, &40_000_000, &-74_000_000, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &40_100_000, &-74_100_000, &None);
+
+    let children = client.split_waste(&waste_id, &collector, &vec![&env, 120_000u128, 80_000u128]);
+    assert_eq!(children.len(), 2);
+
+    let first = client.get_waste_v2(&children.get(0).unwrap()).unwrap();
+    let second = client.get_waste_v2(&children.get(1).unwrap()).unwrap();
+    assert_eq!(first.weight, 120_000);
+    assert_eq!(second.weight, 80_000);
+    assert_eq!(first.waste_type, WasteType::Plastic);
+    assert_eq!(first.current_owner, collector);
+    assert_eq!(first.latitude, 40_000_000);
+    assert!(first.is_active);
+
+    // Parent is retired and linked to its children
+    let parent = client.get_waste_v2(&waste_id).unwrap();
+    assert!(!parent.is_active);
+    assert_eq!(client.get_waste_children(&waste_id), children);
+    assert_eq!(client.get_waste_parent(&first.waste_id), Some(waste_id));
+    assert_eq!(client.get_waste_parent(&waste_id), None);
+}
+
+#[test]
+fn test_split_waste_preserves_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &10_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &1, &1, &None);
+
+    let children = client.split_waste(&waste_id, &collector, &vec![&env, 4_000u128, 6_000u128]);
+    let child_id = children.get(0).unwrap();
+
+    // Child carries the parent's custody chain
+    let history = client.get_transfer_history_v2(&child_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().from, recycler);
+    assert_eq!(history.get(0).unwrap().to, collector);
+    assert_eq!(history.get(0).unwrap().waste_id, waste_id);
+
+    // Children can move independently
+    client.transfer_waste_v2(&child_id, &collector, &manufacturer, &2, &2, &None);
+    assert_eq!(client.get_transfer_history_v2(&child_id).len(), 2);
+    assert_eq!(client.get_transfer_history_v2(&children.get(1).unwrap()).len(), 1);
+    assert_eq!(client.get_transfer_history_v2(&waste_id).len(), 1);
+}
+
+#[test]
+fn test_split_waste_inherits_confirmation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+
+    let children = client.split_waste(&waste_id, &recycler, &vec![&env, 1_000u128, 2_000u128]);
+    let child = client.get_waste_v2(&children.get(1).unwrap()).unwrap();
+    assert!(child.is_confirmed);
+    assert_eq!(child.confirmer, collector);
+}
+
+#[test]
+#[should_panic(expected = "Split weights must sum to waste weight")]
+fn test_split_waste_weight_mismatch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &5_000, &recycler, &0, &0, &None);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 2_000u128, 2_000u128]);
+}
+
+#[test]
+#[should_panic(expected = "Split requires at least two parts")]
+fn test_split_waste_single_part() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &5_000, &recycler, &0, &0, &None);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 5_000u128]);
+}
+
+#[test]
+#[should_panic(expected = "Split weight must be greater than zero")]
+fn test_split_waste_zero_part() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &5_000, &recycler, &0, &0, &None);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 5_000u128, 0u128]);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not own waste")]
+fn test_split_waste_non_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &5_000, &recycler, &0, &0, &None);
+    client.split_waste(&waste_id, &collector, &vec![&env, 2_500u128, 2_500u128]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot split deactivated waste")]
+fn test_split_waste_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &5_000, &recycler, &0, &0, &None);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 2_500u128, 2_500u128]);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 2_500u128, 2_500u128]);
+}
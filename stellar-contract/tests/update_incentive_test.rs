@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
 use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
 
 #[test]
@@ -13,15 +13,15 @@ fn test_update_incentive_success() {
     env.mock_all_auths();
 
     // Register manufacturer
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
     // Create incentive using the correct signature: (waste_type, reward, max_waste_amount, rewarder)
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
     assert_eq!(incentive.reward, 100);
     assert_eq!(incentive.max_waste_amount, 5000);
 
     // Update incentive
-    let updated = client.update_incentive(&incentive.id, &200, &10000);
+    let updated = client.update_incentive(&incentive.id, &200, &10000, &50_000);
     assert_eq!(updated.reward, 200);
     assert_eq!(updated.max_waste_amount, 10000);
 
@@ -48,10 +48,10 @@ fn test_update_incentive_not_found() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
     // Try to update non-existent incentive
-    client.update_incentive(&999, &100, &5000);
+    client.update_incentive(&999, &100, &5000, &50_000);
 }
 
 #[test]
@@ -64,14 +64,14 @@ fn test_update_incentive_inactive() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
     // Create and deactivate incentive
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
     client.update_incentive_status(&incentive.id, &false);
 
     // Try to update inactive incentive
-    client.update_incentive(&incentive.id, &200, &10000);
+    client.update_incentive(&incentive.id, &200, &10000, &50_000);
 }
 
 #[test]
@@ -84,12 +84,12 @@ fn test_update_incentive_zero_reward() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
 
     // Try to update with zero reward
-    client.update_incentive(&incentive.id, &0, &5000);
+    client.update_incentive(&incentive.id, &0, &5000, &50_000);
 }
 
 #[test]
@@ -102,12 +102,12 @@ fn test_update_incentive_zero_max_waste() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
 
     // Try to update with zero max_waste_amount
-    client.update_incentive(&incentive.id, &100, &0);
+    client.update_incentive(&incentive.id, &100, &0, &50_000);
 }
 
 #[test]
@@ -119,12 +119,12 @@ fn test_update_incentive_minimum_values() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
 
     // Update with minimum valid values
-    let updated = client.update_incentive(&incentive.id, &1, &1);
+    let updated = client.update_incentive(&incentive.id, &1, &1, &50_000);
     assert_eq!(updated.reward, 1);
     assert_eq!(updated.max_waste_amount, 1);
 }
@@ -138,22 +138,62 @@ fn test_update_incentive_multiple_times() {
     let manufacturer = Address::generate(&env);
     env.mock_all_auths();
 
-    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
 
-    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &manufacturer);
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
 
     // First update
-    let updated1 = client.update_incentive(&incentive.id, &200, &10000);
+    let updated1 = client.update_incentive(&incentive.id, &200, &10000, &50_000);
     assert_eq!(updated1.reward, 200);
     assert_eq!(updated1.max_waste_amount, 10000);
 
     // Second update
-    let updated2 = client.update_incentive(&incentive.id, &300, &15000);
+    let updated2 = client.update_incentive(&incentive.id, &300, &15000, &50_000);
     assert_eq!(updated2.reward, 300);
     assert_eq!(updated2.max_waste_amount, 15000);
 
     // Third update
-    let updated3 = client.update_incentive(&incentive.id, &400, &20000);
+    let updated3 = client.update_incentive(&incentive.id, &400, &20000, &50_000);
     assert_eq!(updated3.reward, 400);
     assert_eq!(updated3.max_waste_amount, 20000);
 }
+
+#[test]
+#[should_panic(expected = "Total budget must be greater than zero")]
+fn test_update_incentive_zero_budget() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
+
+    // Try to update with zero budget
+    client.update_incentive(&incentive.id, &100, &5000, &0);
+}
+
+#[test]
+fn test_update_incentive_budget() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(&env, &contract_id);
+
+    let manufacturer = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    let incentive = client.create_incentive(&WasteType::Paper, &100, &5000, &50_000, &manufacturer);
+    assert_eq!(incentive.total_budget, 50_000);
+    assert_eq!(incentive.remaining_budget, 50_000);
+
+    // Nothing has been paid out, so the whole new budget remains
+    let updated = client.update_incentive(&incentive.id, &100, &5000, &20_000);
+    assert_eq!(updated.total_budget, 20_000);
+    assert_eq!(updated.remaining_budget, 20_000);
+    assert!(updated.is_active);
+}
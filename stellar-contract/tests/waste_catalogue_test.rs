@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteCategory, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, admin, recycler, collector, manufacturer)
+}
+
+fn add_ewaste(env: &Env, client: &ScavengerContractClient, admin: &Address) -> WasteType {
+    let category = WasteCategory {
+        id: 0,
+        name: String::from_str(env, "E-WASTE"),
+        is_plastic: false,
+        is_biodegradable: false,
+        is_infinitely_recyclable: false,
+        reward_multiplier: 8,
+        max_weight: 20_000,
+        is_active: false,
+    };
+    client.add_waste_category(admin, &category).waste_type()
+}
+
+#[test]
+fn test_builtin_categories() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _recycler, _collector, _manufacturer) = setup(&env);
+
+    let categories = client.get_waste_categories();
+    assert_eq!(categories.len(), 5);
+
+    let metal = client.get_waste_category(&WasteType::Metal).unwrap();
+    assert_eq!(metal.name, String::from_str(&env, "METAL"));
+    assert!(metal.is_infinitely_recyclable);
+    assert_eq!(metal.reward_multiplier, 5);
+    assert_eq!(client.get_waste_category(&WasteType(5)), None);
+}
+
+#[test]
+fn test_add_category_and_register_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _collector, _manufacturer) = setup(&env);
+
+    let ewaste = add_ewaste(&env, &client, &admin);
+    assert_eq!(ewaste, WasteType(5));
+    assert!(client.get_waste_category(&ewaste).unwrap().is_active);
+    assert_eq!(client.get_waste_categories().len(), 6);
+    assert_eq!(client.get_waste_type_string(&ewaste), String::from_str(&env, "E-WASTE"));
+
+    let waste_id = client.recycle_waste(&ewaste, &5_000, &recycler, &0, &0, &None);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().waste_type, ewaste);
+}
+
+#[test]
+fn test_catalogue_multiplier_drives_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let ewaste = add_ewaste(&env, &client, &admin);
+    let material = client.submit_material(
+        &ewaste,
+        &5_000,
+        &collector,
+        &String::from_str(&env, "phones"),
+        &None);
+    client.verify_material(&material.id, &recycler);
+
+    // 5kg * 8 * 10
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, 400);
+    assert_eq!(client.get_stats(&collector).unwrap().other_count, 1);
+}
+
+#[test]
+fn test_update_builtin_multiplier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let mut paper = client.get_waste_category(&WasteType::Paper).unwrap();
+    paper.reward_multiplier = 4;
+    client.update_waste_category(&admin, &paper);
+
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &5_000,
+        &collector,
+        &String::from_str(&env, "paper"),
+        &None);
+    client.verify_material(&material.id, &recycler);
+
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, 200);
+}
+
+#[test]
+fn test_incentive_for_catalogue_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _recycler, _collector, manufacturer) = setup(&env);
+
+    let ewaste = add_ewaste(&env, &client, &admin);
+    let incentive = client.create_incentive(&ewaste, &100, &10_000, &50_000, &manufacturer);
+
+    assert_eq!(incentive.waste_type, ewaste);
+    assert_eq!(client.get_incentives_by_waste_type(&ewaste).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Weight exceeds category maximum")]
+fn test_implausible_weight_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _collector, _manufacturer) = setup(&env);
+
+    let ewaste = add_ewaste(&env, &client, &admin);
+    client.recycle_waste(&ewaste, &20_001, &recycler, &0, &0, &None);
+}
+
+#[test]
+#[should_panic(expected = "Unknown waste type")]
+fn test_unknown_waste_type_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, _manufacturer) = setup(&env);
+
+    client.recycle_waste(&WasteType(42), &1_000, &recycler, &0, &0, &None);
+}
+
+#[test]
+#[should_panic(expected = "Waste type is not active")]
+fn test_inactive_category_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _recycler, _collector, manufacturer) = setup(&env);
+
+    let mut glass = client.get_waste_category(&WasteType::Glass).unwrap();
+    glass.is_active = false;
+    client.update_waste_category(&admin, &glass);
+
+    client.create_incentive(&WasteType::Glass, &100, &10_000, &50_000, &manufacturer);
+}
@@ -27,6 +27,7 @@ fn test_waste_registered_event_emitted() {
         &recycler,
         &latitude,
         &longitude,
+        &None,
     );
 
     // Verify event was emitted
@@ -75,6 +76,7 @@ fn test_waste_registered_event_fields() {
             &recycler,
             &lat,
             &lon,
+            &None,
         );
 
         // Get the last event
@@ -118,6 +120,7 @@ fn test_waste_registered_event_multiple_wastes() {
         &recycler1,
         &40_000_000,
         &-74_000_000,
+        &None,
     );
 
     let waste_id2 = client.recycle_waste(
@@ -126,6 +129,7 @@ fn test_waste_registered_event_multiple_wastes() {
         &recycler2,
         &41_000_000,
         &-73_000_000,
+        &None,
     );
 
     // Verify both events were emitted
@@ -192,6 +196,7 @@ fn test_waste_registered_event_with_boundary_coordinates() {
             &recycler,
             &lat,
             &lon,
+            &None,
         );
 
         let events = env.events().all();
@@ -220,6 +225,7 @@ fn test_waste_registered_event_symbol() {
         &recycler,
         &40_000_000,
         &-74_000_000,
+        &None,
     );
 
     let events = env.events().all();
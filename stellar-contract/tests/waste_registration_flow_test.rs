@@ -33,7 +33,7 @@ fn test_successful_waste_registration() {
     let description = String::from_str(&env, "Plastic bottles");
     
     // Register waste
-    let waste = client.submit_material(&waste_type, &weight, &recycler, &description);
+    let waste = client.submit_material(&waste_type, &weight, &recycler, &description, &None);
     
     // Verify waste was created successfully
     assert_eq!(waste.waste_type, waste_type);
@@ -51,11 +51,11 @@ fn test_successful_waste_registration_all_types() {
     let desc = String::from_str(&env, "Test waste");
     
     // Test all waste types
-    let paper = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc);
-    let plastic = client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc);
-    let metal = client.submit_material(&WasteType::Metal, &4000, &recycler, &desc);
-    let glass = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc);
+    let paper = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc, &None);
+    let plastic = client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc, &None);
+    let metal = client.submit_material(&WasteType::Metal, &4000, &recycler, &desc, &None);
+    let glass = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc, &None);
     
     // Verify all were registered
     assert_eq!(paper.waste_type, WasteType::Paper);
@@ -79,7 +79,7 @@ fn test_unregistered_user_cannot_register_waste() {
     let description = String::from_str(&env, "Test waste");
     
     // Try to register waste without being registered as participant
-    client.submit_material(&WasteType::Plastic, &1000, &unregistered_user, &description);
+    client.submit_material(&WasteType::Plastic, &1000, &unregistered_user, &description, &None);
 }
 
 #[test]
@@ -93,7 +93,7 @@ fn test_unregistered_user_cannot_register_any_waste_type() {
     let desc = String::from_str(&env, "Test");
     
     // Try with different waste types - all should fail
-    client.submit_material(&WasteType::Paper, &1000, &unregistered_user, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &unregistered_user, &desc, &None);
 }
 
 // ========== Test 3: Waste ID Generation ==========
@@ -106,9 +106,9 @@ fn test_waste_id_generation_sequential() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register multiple wastes
-    let waste1 = client.submit_material(&WasteType::Plastic, &1000, &recycler, &desc);
-    let waste2 = client.submit_material(&WasteType::Metal, &2000, &recycler, &desc);
-    let waste3 = client.submit_material(&WasteType::Glass, &3000, &recycler, &desc);
+    let waste1 = client.submit_material(&WasteType::Plastic, &1000, &recycler, &desc, &None);
+    let waste2 = client.submit_material(&WasteType::Metal, &2000, &recycler, &desc, &None);
+    let waste3 = client.submit_material(&WasteType::Glass, &3000, &recycler, &desc, &None);
     
     // Verify IDs are sequential
     assert_eq!(waste1.id, 1);
@@ -133,7 +133,7 @@ fn test_waste_id_generation_unique() {
             3 => WasteType::Metal,
             _ => WasteType::Glass,
         };
-        let waste = client.submit_material(&waste_type, &(1000 * (i as u128 + 1)), &recycler, &desc);
+        let waste = client.submit_material(&waste_type, &(1000 * (i as u128 + 1)), &recycler, &desc, &None);
         ids.push(waste.id);
     }
     
@@ -154,7 +154,7 @@ fn test_waste_id_generation_no_gaps() {
     
     // Register wastes and verify no gaps in sequence
     for expected_id in 1..=5 {
-        let waste = client.submit_material(&WasteType::Plastic, &1000, &recycler, &desc);
+        let waste = client.submit_material(&WasteType::Plastic, &1000, &recycler, &desc, &None);
         assert_eq!(waste.id, expected_id as u64);
     }
 }
@@ -171,7 +171,7 @@ fn test_waste_registration_event_emitted() {
     let description = String::from_str(&env, "Plastic bottles");
     
     // Register waste
-    let waste = client.submit_material(&waste_type, &weight, &recycler, &description);
+    let waste = client.submit_material(&waste_type, &weight, &recycler, &description, &None);
     
     // Verify event was emitted
     let events = env.events().all();
@@ -197,7 +197,7 @@ fn test_waste_registration_event_contains_waste_id() {
     let _ = env.events().all();
     
     // Register waste
-    let waste = client.submit_material(&WasteType::Metal, &2000, &recycler, &desc);
+    let waste = client.submit_material(&WasteType::Metal, &2000, &recycler, &desc, &None);
     
     // Get events
     let events = env.events().all();
@@ -216,9 +216,9 @@ fn test_waste_registration_event_emitted_for_each_waste() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register multiple wastes
-    let waste1 = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    let waste2 = client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc);
-    let waste3 = client.submit_material(&WasteType::Glass, &3000, &recycler, &desc);
+    let waste1 = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    let waste2 = client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc, &None);
+    let waste3 = client.submit_material(&WasteType::Glass, &3000, &recycler, &desc, &None);
     
     // Get all events
     let events = env.events().all();
@@ -237,7 +237,7 @@ fn test_participant_wastes_updated_on_registration() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register waste
-    let waste = client.submit_material(&WasteType::Plastic, &5000, &recycler, &desc);
+    let waste = client.submit_material(&WasteType::Plastic, &5000, &recycler, &desc, &None);
     
     // Get participant info
     let info = client.get_participant_info(&recycler);
@@ -259,9 +259,9 @@ fn test_participant_wastes_accumulate() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register multiple wastes
-    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc);
-    client.submit_material(&WasteType::Metal, &3000, &recycler, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Metal, &3000, &recycler, &desc, &None);
     
     // Get participant info
     let info = client.get_participant_info(&recycler).unwrap();
@@ -280,10 +280,10 @@ fn test_participant_wastes_by_type_tracked() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register wastes of different types
-    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    client.submit_material(&WasteType::Paper, &1500, &recycler, &desc);
-    client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc);
-    client.submit_material(&WasteType::Metal, &3000, &recycler, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Paper, &1500, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Metal, &3000, &recycler, &desc, &None);
     
     // Get participant info
     let info = client.get_participant_info(&recycler).unwrap();
@@ -312,9 +312,9 @@ fn test_multiple_participants_wastes_independent() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register wastes for each
-    client.submit_material(&WasteType::Plastic, &1000, &recycler1, &desc);
-    client.submit_material(&WasteType::Plastic, &2000, &recycler1, &desc);
-    client.submit_material(&WasteType::Metal, &3000, &recycler2, &desc);
+    client.submit_material(&WasteType::Plastic, &1000, &recycler1, &desc, &None);
+    client.submit_material(&WasteType::Plastic, &2000, &recycler1, &desc, &None);
+    client.submit_material(&WasteType::Metal, &3000, &recycler2, &desc, &None);
     
     // Verify stats are independent
     let info1 = client.get_participant_info(&recycler1).unwrap();
@@ -337,11 +337,11 @@ fn test_all_waste_types_can_be_registered() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register all waste types
-    let paper = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc);
-    let plastic = client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc);
-    let metal = client.submit_material(&WasteType::Metal, &4000, &recycler, &desc);
-    let glass = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc);
+    let paper = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc, &None);
+    let plastic = client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc, &None);
+    let metal = client.submit_material(&WasteType::Metal, &4000, &recycler, &desc, &None);
+    let glass = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc, &None);
     
     // Verify all were registered with correct types
     assert_eq!(paper.waste_type, WasteType::Paper);
@@ -359,11 +359,11 @@ fn test_all_waste_types_retrievable() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register all waste types
-    let paper = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc);
-    let plastic = client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc);
-    let metal = client.submit_material(&WasteType::Metal, &4000, &recycler, &desc);
-    let glass = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc);
+    let paper = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    let pet = client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc, &None);
+    let plastic = client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc, &None);
+    let metal = client.submit_material(&WasteType::Metal, &4000, &recycler, &desc, &None);
+    let glass = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc, &None);
     
     // Retrieve each and verify
     let paper_retrieved = client.get_waste(&paper.id).unwrap();
@@ -387,11 +387,11 @@ fn test_all_waste_types_tracked_in_stats() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register all waste types
-    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc);
-    client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc);
-    client.submit_material(&WasteType::Metal, &4000, &recycler, &desc);
-    client.submit_material(&WasteType::Glass, &5000, &recycler, &desc);
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::PetPlastic, &2000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Plastic, &3000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Metal, &4000, &recycler, &desc, &None);
+    client.submit_material(&WasteType::Glass, &5000, &recycler, &desc, &None);
     
     // Get participant stats
     let info = client.get_participant_info(&recycler).unwrap();
@@ -417,7 +417,7 @@ fn test_waste_registration_flow_complete() {
     let desc = String::from_str(&env, "Comprehensive test waste");
     
     // Step 1: Register waste
-    let waste = client.submit_material(&WasteType::Plastic, &5000, &recycler, &desc);
+    let waste = client.submit_material(&WasteType::Plastic, &5000, &recycler, &desc, &None);
     
     // Step 2: Verify waste ID is generated
     assert!(waste.id > 0, "Waste ID should be positive");
@@ -444,9 +444,9 @@ fn test_multiple_waste_registrations_flow() {
     let desc = String::from_str(&env, "Test waste");
     
     // Register multiple wastes
-    let waste1 = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
-    let waste2 = client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc);
-    let waste3 = client.submit_material(&WasteType::Metal, &3000, &recycler, &desc);
+    let waste1 = client.submit_material(&WasteType::Paper, &1000, &recycler, &desc, &None);
+    let waste2 = client.submit_material(&WasteType::Plastic, &2000, &recycler, &desc, &None);
+    let waste3 = client.submit_material(&WasteType::Metal, &3000, &recycler, &desc, &None);
     
     // Verify all IDs are unique and sequential
     assert_eq!(waste1.id, 1);
@@ -480,8 +480,8 @@ fn test_waste_registration_with_different_roles() {
     let desc = String::from_str(&env, "Test waste");
     
     // Both should be able to register waste
-    let waste1 = client.submit_material(&WasteType::Plastic, &1000, &recycler, &desc);
-    let waste2 = client.submit_material(&WasteType::Metal, &2000, &collector, &desc);
+    let waste1 = client.submit_material(&WasteType::Plastic, &1000, &recycler, &desc, &None);
+    let waste2 = client.submit_material(&WasteType::Metal, &2000, &collector, &desc, &None);
     
     // Verify both registrations succeeded
     assert_eq!(waste1.id, 1);
@@ -505,7 +505,7 @@ fn test_waste_registration_with_zero_weight() {
     let desc = String::from_str(&env, "Zero weight waste");
     
     // Register waste with zero weight (should be allowed)
-    let waste = client.submit_material(&WasteType::Plastic, &0, &recycler, &desc);
+    let waste = client.submit_material(&WasteType::Plastic, &0, &recycler, &desc, &None);
     
     // Verify it was registered
     assert_eq!(waste.weight, 0);
@@ -521,7 +521,7 @@ fn test_waste_registration_with_large_weight() {
     
     // Register waste with large weight
     let large_weight: u128 = 1_000_000_000; // 1 billion grams
-    let waste = client.submit_material(&WasteType::Metal, &large_weight, &recycler, &desc);
+    let waste = client.submit_material(&WasteType::Metal, &large_weight, &recycler, &desc, &None);
     
     // Verify it was registered
     assert_eq!(waste.weight, large_weight);
@@ -535,7 +535,7 @@ fn test_waste_registration_preserves_metadata() {
     let desc = String::from_str(&env, "Detailed waste description");
     
     // Register waste
-    let waste = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc);
+    let waste = client.submit_material(&WasteType::Glass, &5000, &recycler, &desc, &None);
     
     // Retrieve and verify all metadata preserved
     let retrieved = client.get_waste(&waste.id).unwrap();
@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Events, Ledger}, vec, Address, Env, IntoVal, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteStatus, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.initialize_admin(&admin);
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, admin, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_full_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &5_000, &recycler, &0, &0, &None);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().status, WasteStatus::Registered);
+
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().status, WasteStatus::Collected);
+
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0, &None);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().status, WasteStatus::Delivered);
+
+    env.ledger().with_mut(|li| li.timestamp = 12_345);
+    let processed = client.mark_processed(&waste_id, &manufacturer);
+    assert_eq!(processed.status, WasteStatus::Processed);
+    assert!(processed.is_recycled());
+    assert_eq!(processed.recycled_timestamp, 12_345);
+}
+
+#[test]
+fn test_direct_delivery_from_recycler() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &5_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().status, WasteStatus::Delivered);
+}
+
+#[test]
+fn test_status_change_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &5_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+
+    let events = env.events().all();
+    let status_event = events
+        .iter()
+        .find(|e| {
+            let expected: Vec<Val> = (symbol_short!("status"), waste_id).into_val(&env);
+            e.1 == expected
+        })
+        .expect("status event not emitted");
+
+    let data: (WasteStatus, WasteStatus) = status_event.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (WasteStatus::Registered, WasteStatus::Collected));
+}
+
+#[test]
+fn test_reject_delivered_waste() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+
+    let rejected = client.reject_waste(&waste_id, &manufacturer);
+    assert_eq!(rejected.status, WasteStatus::Rejected);
+    assert!(!rejected.is_recycled());
+}
+
+#[test]
+fn test_deactivate_sets_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    let waste = client.deactivate_waste(&waste_id, &admin);
+
+    assert_eq!(waste.status, WasteStatus::Deactivated);
+    assert!(!waste.is_active);
+}
+
+#[test]
+#[should_panic(expected = "Invalid status transition")]
+fn test_mark_processed_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &manufacturer, &0, &0, &None);
+    client.mark_processed(&waste_id, &manufacturer);
+
+    // Processing twice is not a valid transition
+    client.mark_processed(&waste_id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Only manufacturers can perform this action")]
+fn test_mark_processed_non_manufacturer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0, &None);
+    client.mark_processed(&waste_id, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Caller does not own waste")]
+fn test_mark_processed_non_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, _collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    client.mark_processed(&waste_id, &manufacturer);
+}
+
+#[test]
+fn test_status_filtered_queries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, recycler, collector, manufacturer) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1_000, &recycler, &0, &0, &None);
+    let second = client.recycle_waste(&WasteType::Plastic, &2_000, &recycler, &0, &0, &None);
+    let third = client.recycle_waste(&WasteType::Glass, &3_000, &recycler, &0, &0, &None);
+
+    client.transfer_waste_v2(&second, &recycler, &collector, &0, &0, &None);
+    client.transfer_waste_v2(&third, &recycler, &manufacturer, &0, &0, &None);
+
+    assert_eq!(client.get_wastes_by_status(&WasteStatus::Registered), vec![&env, first]);
+    assert_eq!(client.get_wastes_by_status(&WasteStatus::Collected), vec![&env, second]);
+    assert_eq!(client.get_wastes_by_status(&WasteStatus::Delivered), vec![&env, third]);
+    assert_eq!(client.get_wastes_by_status(&WasteStatus::Processed).len(), 0);
+
+    assert_eq!(
+        client.get_participant_wastes_by_status(&collector, &WasteStatus::Collected),
+        vec![&env, second]
+    );
+    assert_eq!(
+        client.get_participant_wastes_by_status(&recycler, &WasteStatus::Collected).len(),
+        0
+    );
+}
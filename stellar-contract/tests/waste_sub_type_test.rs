@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{
+    ParticipantRole, ScavengerContract, ScavengerContractClient, WasteSubType, WasteType,
+};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = Address::generate(env);
+    let collector = Address::generate(env);
+    let manufacturer = Address::generate(env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("rec"), &0, &0);
+    client.register_participant(&collector, &ParticipantRole::Collector, &symbol_short!("col"), &0, &0);
+    client.register_participant(&manufacturer, &ParticipantRole::Manufacturer, &symbol_short!("man"), &0, &0);
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_tag_waste_with_resin_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2_000, &recycler, &0, &0, &None);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().sub_type, WasteSubType::Unspecified);
+
+    let waste = client.set_waste_sub_type(&waste_id, &recycler, &WasteSubType::Hdpe);
+    assert_eq!(waste.sub_type, WasteSubType::Hdpe);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().sub_type, WasteSubType::Hdpe);
+}
+
+#[test]
+fn test_split_children_keep_sub_type() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &3_000, &recycler, &0, &0, &None);
+    client.set_waste_sub_type(&waste_id, &recycler, &WasteSubType::GreenGlass);
+
+    let children = client.split_waste(&waste_id, &recycler, &vec![&env, 1_000u128, 2_000u128]);
+    for child in children.iter() {
+        assert_eq!(client.get_waste_v2(&child).unwrap().sub_type, WasteSubType::GreenGlass);
+    }
+}
+
+#[test]
+fn test_incentive_targets_sub_type() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Plastic, &100, &1_000_000, &50_000, &manufacturer);
+    let incentive = client.set_incentive_sub_types(&incentive.id, &vec![&env, WasteSubType::Hdpe, WasteSubType::Pp]);
+    assert_eq!(incentive.sub_types, vec![&env, WasteSubType::Hdpe, WasteSubType::Pp]);
+    assert_eq!(client.get_incentives_by_sub_type(&WasteSubType::Pp).len(), 1);
+    assert_eq!(client.get_incentives_by_sub_type(&WasteSubType::Ldpe).len(), 0);
+
+    let untagged = client.recycle_waste(&WasteType::Plastic, &5_000, &recycler, &0, &0, &None);
+    let ldpe = client.recycle_waste(&WasteType::Plastic, &5_000, &recycler, &0, &0, &None);
+    let pp = client.recycle_waste(&WasteType::Plastic, &5_000, &recycler, &0, &0, &None);
+    client.set_waste_sub_type(&ldpe, &recycler, &WasteSubType::Ldpe);
+    client.set_waste_sub_type(&pp, &recycler, &WasteSubType::Pp);
+
+    assert_eq!(client.calculate_waste_incentive_reward(&incentive.id, &untagged), 0);
+    assert_eq!(client.calculate_waste_incentive_reward(&incentive.id, &ldpe), 0);
+    assert_eq!(client.calculate_waste_incentive_reward(&incentive.id, &pp), 500);
+}
+
+#[test]
+fn test_untargeted_incentive_accepts_any_sub_type() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::Metal, &100, &1_000_000, &50_000, &manufacturer);
+    let waste_id = client.recycle_waste(&WasteType::Metal, &5_000, &recycler, &0, &0, &None);
+    client.set_waste_sub_type(&waste_id, &recycler, &WasteSubType::Steel);
+
+    assert_eq!(client.calculate_waste_incentive_reward(&incentive.id, &waste_id), 500);
+}
+
+#[test]
+fn test_sub_type_stats_breakdown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let clear = client.recycle_waste(&WasteType::Glass, &1_000, &recycler, &0, &0, &None);
+    let brown_a = client.recycle_waste(&WasteType::Glass, &2_000, &recycler, &0, &0, &None);
+    let brown_b = client.recycle_waste(&WasteType::Glass, &3_000, &recycler, &0, &0, &None);
+    client.recycle_waste(&WasteType::Glass, &4_000, &recycler, &0, &0, &None);
+    client.set_waste_sub_type(&clear, &recycler, &WasteSubType::ClearGlass);
+    client.set_waste_sub_type(&brown_a, &recycler, &WasteSubType::BrownGlass);
+    client.set_waste_sub_type(&brown_b, &recycler, &WasteSubType::BrownGlass);
+
+    let stats = client.get_sub_type_stats(&WasteType::Glass);
+    assert_eq!(stats.len(), 3);
+
+    let clear_stats = stats.get(0).unwrap();
+    assert_eq!(clear_stats.sub_type, WasteSubType::ClearGlass);
+    assert_eq!(clear_stats.waste_count, 1);
+    assert_eq!(clear_stats.total_weight, 1_000);
+
+    assert_eq!(stats.get(1).unwrap().waste_count, 0);
+
+    let brown_stats = stats.get(2).unwrap();
+    assert_eq!(brown_stats.waste_count, 2);
+    assert_eq!(brown_stats.total_weight, 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Sub-type does not match waste type")]
+fn test_mismatched_sub_type_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, _collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &2_000, &recycler, &0, &0, &None);
+    client.set_waste_sub_type(&waste_id, &recycler, &WasteSubType::Aluminium);
+}
+
+#[test]
+#[should_panic(expected = "Sub-type does not match waste type")]
+fn test_incentive_mismatched_sub_type_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _recycler, _collector, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&WasteType::PetPlastic, &100, &1_000_000, &50_000, &manufacturer);
+    client.set_incentive_sub_types(&incentive.id, &vec![&env, WasteSubType::Hdpe]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot change sub-type of confirmed waste")]
+fn test_sub_type_locked_after_confirmation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, recycler, collector, _manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2_000, &recycler, &0, &0, &None);
+    client.confirm_waste_details(&waste_id, &collector, &None);
+    client.set_waste_sub_type(&waste_id, &recycler, &WasteSubType::Pvc);
+}